@@ -2,13 +2,13 @@ use std::str::FromStr;
 
 use arena_tournament_module::state::EliminationType;
 use cosmwasm_std::{
-    entry_point, to_json_binary, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Reply,
-    Response, StdError, StdResult, SubMsgResult, Uint128, WasmMsg,
+    entry_point, to_json_binary, BankMsg, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
+    Reply, Response, StdError, StdResult, SubMsgResult, Uint128, WasmMsg,
 };
 use cw2::{ensure_from_older_version, set_contract_version};
 
 use crate::{
-    execute::{self, TRIGGER_COMPETITION_REPLY_ID},
+    execute::{self, CREATE_FROM_TEMPLATE_REPLY_ID, TRIGGER_COMPETITION_REPLY_ID},
     migrate,
     msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
     query,
@@ -59,10 +59,15 @@ pub fn execute(
             competition_info,
             competition_type,
             group_contract_info,
+            waitlist_size,
+            eligibility_requirements,
+            approval_required,
+            escrow_id,
+            trigger_bounty,
         } => execute::create_enrollment(
             deps,
             env,
-            info,
+            info.sender,
             min_members,
             max_members,
             entry_fee,
@@ -71,6 +76,12 @@ pub fn execute(
             competition_info,
             competition_type,
             group_contract_info,
+            waitlist_size,
+            eligibility_requirements,
+            approval_required,
+            escrow_id,
+            trigger_bounty,
+            None,
         ),
         ExecuteMsg::TriggerExpiration { id, escrow_id } => {
             execute::trigger_expiration(deps, env, info, id, escrow_id)
@@ -80,6 +91,25 @@ pub fn execute(
         ExecuteMsg::ForceWithdraw { id, members } => {
             execute::force_withdraw(deps, env, info, id, members)
         }
+        ExecuteMsg::ApproveApplicants { id, applicants } => {
+            execute::approve_applicants(deps, env, info, id, applicants)
+        }
+        ExecuteMsg::RejectApplicants { id, applicants } => {
+            execute::reject_applicants(deps, env, info, id, applicants)
+        }
+        ExecuteMsg::CreateTemplate { template } => {
+            execute::create_template(deps, env, info, template)
+        }
+        ExecuteMsg::UpdateTemplate {
+            template_id,
+            template,
+        } => execute::update_template(deps, env, info, template_id, template),
+        ExecuteMsg::RemoveTemplate { template_id } => {
+            execute::remove_template(deps, env, info, template_id)
+        }
+        ExecuteMsg::CreateFromTemplate { template_id, start } => {
+            execute::create_from_template(deps, env, info, template_id, start)
+        }
     }
 }
 
@@ -101,6 +131,30 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             addr,
             enrollment_id,
         } => to_json_binary(&query::is_member(deps, enrollment_id, addr)?),
+        QueryMsg::CheckEligibility { id, addr } => {
+            to_json_binary(&query::check_eligibility(deps, id, addr)?)
+        }
+        QueryMsg::Applications {
+            enrollment_id,
+            start_after,
+            limit,
+        } => to_json_binary(&query::applications(
+            deps,
+            enrollment_id,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::Template { template_id } => to_json_binary(&query::template(deps, template_id)?),
+        QueryMsg::Templates {
+            start_after,
+            limit,
+            host,
+        } => to_json_binary(&query::templates(deps, start_after, limit, host)?),
+        QueryMsg::Waitlist {
+            enrollment_id,
+            start_after,
+            limit,
+        } => to_json_binary(&query::waitlist(deps, enrollment_id, start_after, limit)?),
     }
 }
 
@@ -115,6 +169,10 @@ pub fn migrate(mut deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response,
         MigrateMsg::WithGroupId { group_id } => {
             migrate::from_v2_to_v2_1(deps.branch(), &env, group_id)?
         }
+        MigrateMsg::WithEscrowId { escrow_id } => {
+            migrate::backfill_escrow_id(deps.branch(), escrow_id)?;
+            vec![]
+        }
         MigrateMsg::RemoveThirdPlaceMatch { enrollment_id } => {
             enrollment_entries().update(
                 deps.storage,
@@ -156,7 +214,7 @@ pub fn migrate(mut deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response,
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
     match msg.id {
         TRIGGER_COMPETITION_REPLY_ID => {
             let enrollment_info = TEMP_ENROLLMENT_INFO.load(deps.storage)?;
@@ -201,9 +259,15 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
                                                     contract_addr: escrow_addr.to_string(),
                                                     msg: to_json_binary(&arena_interface::escrow::ExecuteMsg::ReceiveNative {
                                                  })?,
-                                                 funds: vec![enrollment_info.amount.unwrap()] }));
+                                                 funds: vec![enrollment_info.amount.clone().unwrap()] }));
                                             }
                                         }
+                                        if let Some(bounty) = enrollment_info.bounty.clone() {
+                                            msgs.push(CosmosMsg::Bank(BankMsg::Send {
+                                                to_address: enrollment_info.triggered_by.to_string(),
+                                                amount: vec![bounty],
+                                            }));
+                                        }
                                         Ok(enrollment_entry)
                                     }
                                     None => Err(StdError::generic_err(format!(
@@ -213,10 +277,19 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
                                 }
                             },
                         )?;
+                        // Only editions that created their competition schedule the next one
+                        let enrollment_entry = enrollment_entries()
+                            .load(deps.storage, enrollment_info.enrollment_id)?;
+
                         Ok(Response::new()
                             .add_attribute("reply", "reply_trigger_competition")
                             .add_attribute("result", "competition_created")
-                            .add_messages(msgs))
+                            .add_messages(msgs)
+                            .add_submessages(execute::schedule_next_edition(
+                                deps.as_ref(),
+                                &env,
+                                &enrollment_entry,
+                            )?))
                     } else {
                         Err(ContractError::StdError(StdError::generic_err(
                             "Missing competition_id",
@@ -248,6 +321,15 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
                 }
             }
         }
+        CREATE_FROM_TEMPLATE_REPLY_ID => {
+            // The next edition failing to be created should not block the trigger
+            match msg.result.into_result() {
+                Ok(_) => Ok(Response::new().add_attribute("reply", "reply_create_from_template")),
+                Err(error_message) => Ok(Response::new()
+                    .add_attribute("reply", "reply_create_from_template")
+                    .add_attribute("recurrence_error", error_message)),
+            }
+        }
         _ => Err(ContractError::UnknownReplyId { id: msg.id }),
     }
 }
@@ -1,6 +1,6 @@
 use cosmwasm_std::{
-    CheckedFromRatioError, DecimalRangeExceeded, Instantiate2AddressError, OverflowError, StdError,
-    Uint128, Uint64,
+    CheckedFromRatioError, CheckedMultiplyFractionError, DecimalRangeExceeded,
+    Instantiate2AddressError, OverflowError, StdError, Uint128, Uint64,
 };
 use cw_ownable::OwnershipError;
 use cw_utils::{Expiration, ParseReplyError, PaymentError};
@@ -26,6 +26,9 @@ pub enum ContractError {
     #[error("{0}")]
     CheckedFromRatioError(#[from] CheckedFromRatioError),
 
+    #[error("{0}")]
+    CheckedMultiplyFractionError(#[from] CheckedMultiplyFractionError),
+
     #[error("{0}")]
     PaymentError(#[from] PaymentError),
 
@@ -59,4 +62,13 @@ pub enum ContractError {
 
     #[error("Enrollment is at max members already")]
     EnrollmentMaxMembers {},
+
+    #[error("Enrollment waitlist is full")]
+    WaitlistFull {},
+
+    #[error("Does not meet the enrollment's eligibility requirements")]
+    NotEligible {},
+
+    #[error("No pending application for {addr}")]
+    ApplicationNotFound { addr: String },
 }
@@ -1,37 +1,42 @@
 use arena_interface::{
     competition::msg::EscrowInstantiateInfo,
-    core::{CompetitionModuleQuery, CompetitionModuleResponse},
+    core::{CategoryMetadata, CompetitionModuleQuery, CompetitionModuleResponse},
     group::{self, GroupContractInfo},
+    hosts::HostBan,
 };
 use arena_league_module::msg::LeagueInstantiateExt;
 use arena_tournament_module::{msg::TournamentInstantiateExt, state::EliminationType};
 use arena_wager_module::msg::WagerInstantiateExt;
 use cosmwasm_std::{
-    ensure, instantiate2_address, to_json_binary, Addr, Attribute, BankMsg, Coin, CosmosMsg,
-    DepsMut, Env, MessageInfo, Response, StdError, StdResult, SubMsg, Uint128, Uint64, WasmMsg,
+    ensure, instantiate2_address, to_json_binary, Addr, Api, Attribute, BankMsg, Coin, CosmosMsg,
+    Decimal, Deps, DepsMut, Empty, Env, MessageInfo, Order, Response, StdError, StdResult, Storage,
+    SubMsg, Uint128, Uint64, WasmMsg,
 };
 use cw_balance::{BalanceUnchecked, MemberBalanceUnchecked};
-use cw_utils::{must_pay, Expiration};
+use cw_utils::{must_pay, Duration, Expiration};
 use dao_interface::state::ModuleInstantiateInfo;
 use itertools::Itertools as _;
 use sha2::{Digest, Sha256};
 
 use crate::{
-    msg::CompetitionInfoMsg,
+    msg::{CompetitionInfoMsg, ExecuteMsg},
     state::{
-        enrollment_entries, CompetitionInfo, CompetitionType, EnrollmentEntry, EnrollmentInfo,
-        ENROLLMENT_COUNT, TEMP_ENROLLMENT_INFO,
+        enrollment_entries, enrollment_templates, CompetitionInfo, CompetitionType,
+        EligibilityRequirement, EnrollmentEntry, EnrollmentInfo, EnrollmentTemplate,
+        EnrollmentTemplateInfo, ENROLLMENT_COUNT, ENROLLMENT_WAITLIST, PENDING_APPLICATIONS,
+        TEMPLATE_COUNT, TEMP_ENROLLMENT_INFO, WAITLIST_POSITIONS, WAITLIST_SEQUENCE,
     },
     ContractError,
 };
 
 pub const TRIGGER_COMPETITION_REPLY_ID: u64 = 1;
+pub const CREATE_FROM_TEMPLATE_REPLY_ID: u64 = 2;
 
 #[allow(clippy::too_many_arguments)]
 pub fn create_enrollment(
     deps: DepsMut,
     env: Env,
-    info: MessageInfo,
+    host: Addr,
     min_members: Option<Uint64>,
     max_members: Uint64,
     entry_fee: Option<Coin>,
@@ -40,6 +45,12 @@ pub fn create_enrollment(
     competition_info: CompetitionInfoMsg,
     competition_type: CompetitionType,
     group_contract_info: ModuleInstantiateInfo,
+    waitlist_size: Option<Uint64>,
+    eligibility_requirements: Option<Vec<EligibilityRequirement<String>>>,
+    approval_required: Option<bool>,
+    escrow_id: Option<u64>,
+    trigger_bounty: Option<Decimal>,
+    template_id: Option<Uint128>,
 ) -> Result<Response, ContractError> {
     ensure!(
         !expiration.is_expired(&env.block),
@@ -77,11 +88,66 @@ pub fn create_enrollment(
             ))
         );
     }
+    if let Some(trigger_bounty) = trigger_bounty {
+        ensure!(
+            entry_fee.is_some() && escrow_id.is_some(),
+            ContractError::StdError(StdError::generic_err(
+                "A trigger bounty requires an entry fee and an escrow id"
+            ))
+        );
+        ensure!(
+            trigger_bounty < Decimal::one(),
+            ContractError::StdError(StdError::generic_err(
+                "Trigger bounty must be less than 100%"
+            ))
+        );
+    }
+    if let Some(waitlist_size) = waitlist_size {
+        ensure!(
+            !waitlist_size.is_zero(),
+            ContractError::StdError(StdError::generic_err(
+                "Waitlist size must be greater than zero"
+            ))
+        );
+    }
 
-    // Validate category
+    // Validate the host and category
     let ownership = cw_ownable::get_ownership(deps.storage)?;
     let competition_module = if let Some(owner) = ownership.owner {
+        let host_ban = deps.querier.query_wasm_smart::<Option<HostBan<Addr>>>(
+            &owner,
+            &arena_interface::core::QueryMsg::QueryExtension {
+                msg: arena_interface::core::QueryExt::HostBan {
+                    host: host.to_string(),
+                },
+            },
+        )?;
+        if let Some(host_ban) = host_ban {
+            return Err(ContractError::StdError(StdError::generic_err(format!(
+                "The host {} is banned: {}",
+                host, host_ban.reason
+            ))));
+        }
+
         if let Some(category_id) = category_id {
+            // The category can restrict the competition module and bound the entry fee
+            let metadata = deps.querier.query_wasm_smart::<Option<CategoryMetadata>>(
+                &owner,
+                &arena_interface::core::QueryMsg::QueryExtension {
+                    msg: arena_interface::core::QueryExt::CategoryMetadata { category_id },
+                },
+            )?;
+            if let Some(metadata) = metadata {
+                ensure!(
+                    metadata.allows_module(&competition_type.to_string()),
+                    ContractError::StdError(StdError::generic_err(format!(
+                        "The category does not allow {} competitions",
+                        competition_type
+                    )))
+                );
+                metadata.check_stake(entry_fee.as_ref())?;
+            }
+
             if let Some(rulesets) = &competition_info.rulesets {
                 ensure!(
                     deps.querier.query_wasm_smart::<bool>(
@@ -131,6 +197,16 @@ pub fn create_enrollment(
         ))
     }?;
 
+    // Validate eligibility requirements before saving
+    let eligibility_requirements = eligibility_requirements
+        .map(|requirements| {
+            requirements
+                .iter()
+                .map(|x| x.into_checked(deps.as_ref()))
+                .collect::<StdResult<Vec<_>>>()
+        })
+        .transpose()?;
+
     // Validate additional layered fees before saving
     if let Some(additional_layered_fees) = &competition_info.additional_layered_fees {
         additional_layered_fees
@@ -144,7 +220,7 @@ pub fn create_enrollment(
     })?;
 
     // Generate the group contract
-    let binding = format!("{}{}{}", info.sender, env.block.height, competition_id);
+    let binding = format!("{}{}{}", host, env.block.height, competition_id);
     let salt: [u8; 32] = Sha256::digest(binding.as_bytes()).into();
     let canonical_creator = deps.api.addr_canonicalize(env.contract.address.as_str())?;
     let code_info = deps
@@ -182,10 +258,16 @@ pub fn create_enrollment(
                 additional_layered_fees: competition_info.additional_layered_fees,
             },
             competition_type,
-            host: info.sender,
+            host,
             category_id,
             competition_module,
             group_contract,
+            waitlist_size,
+            eligibility_requirements,
+            approval_required,
+            escrow_id,
+            trigger_bounty,
+            template_id,
         },
     )?;
 
@@ -204,7 +286,6 @@ pub fn trigger_expiration(
 ) -> Result<Response, ContractError> {
     let entry = enrollment_entries().load(deps.storage, id.u128())?;
 
-    ensure!(entry.host == info.sender, ContractError::Unauthorized {});
     ensure!(
         !entry.has_triggered_expiration,
         ContractError::StdError(StdError::generic_err(
@@ -230,8 +311,41 @@ pub fn trigger_expiration(
         };
         enrollment_entries().replace(deps.storage, id.u128(), Some(&new_data), Some(&entry))?;
 
+        let mut refund_msgs = clear_waitlist(deps.storage, &entry, id)?;
+        refund_msgs.extend(clear_applications(deps.storage, &entry, id)?);
+
+        // Automatically refund and remove the enrolled members
+        let members: Vec<group::MemberMsg<Addr>> = deps.querier.query_wasm_smart(
+            entry.group_contract.to_string(),
+            &group::QueryMsg::Members {
+                start_after: None,
+                limit: None,
+            },
+        )?;
+        if !members.is_empty() {
+            refund_msgs.push(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: entry.group_contract.to_string(),
+                msg: to_json_binary(&group::ExecuteMsg::UpdateMembers {
+                    to_add: None,
+                    to_update: None,
+                    to_remove: Some(members.iter().map(|x| x.addr.to_string()).collect()),
+                })?,
+                funds: vec![],
+            }));
+
+            if let Some(entry_fee) = &entry.entry_fee {
+                refund_msgs.extend(members.iter().map(|member| {
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: member.addr.to_string(),
+                        amount: vec![entry_fee.clone()],
+                    })
+                }));
+            }
+        }
+
         // Return a response indicating the enrollment was expired due to insufficient members
         return Ok(Response::new()
+            .add_messages(refund_msgs)
             .add_attribute("action", "trigger_expiration")
             .add_attribute("result", "expired_insufficient_members")
             .add_attribute("id", id.to_string())
@@ -248,10 +362,21 @@ pub fn trigger_expiration(
         }
     );
 
+    // Anyone can trigger the enrollment, but only the host can choose a different escrow
+    let is_host = entry.host == info.sender;
+    if !is_host && entry.entry_fee.is_some() {
+        ensure!(
+            entry.escrow_id == Some(escrow_id),
+            ContractError::Unauthorized {}
+        );
+    }
+
     let mut enrollment_info = EnrollmentInfo {
         enrollment_id: id.u128(),
         module_addr: entry.competition_module.clone(),
         amount: None,
+        bounty: None,
+        triggered_by: info.sender,
     };
 
     let creation_msg = match entry.competition_info.clone() {
@@ -269,11 +394,24 @@ pub fn trigger_expiration(
                     entry.group_contract.to_string(),
                     &group::QueryMsg::MembersCount {},
                 )?;
-                let total = Coin {
+                let mut total = Coin {
                     denom: entry_fee.denom.clone(),
                     amount: entry_fee.amount.checked_mul(members_count.into())?,
                 };
 
+                // The bounty is taken from the entry fees when triggered by someone other than the host
+                if let Some(trigger_bounty) = entry.trigger_bounty.filter(|_| !is_host) {
+                    let bounty = total.amount.checked_mul_floor(trigger_bounty)?;
+
+                    if !bounty.is_zero() {
+                        total.amount = total.amount.checked_sub(bounty)?;
+                        enrollment_info.bounty = Some(Coin {
+                            denom: entry_fee.denom.clone(),
+                            amount: bounty,
+                        });
+                    }
+                }
+
                 enrollment_info.amount = Some(total.clone());
 
                 Some(EscrowInstantiateInfo {
@@ -306,6 +444,7 @@ pub fn trigger_expiration(
                         expiration,
                         rules,
                         rulesets,
+                        ruleset_versions: None,
                         banner,
                         instantiate_extension: WagerInstantiateExt {},
                         group_contract: GroupContractInfo::Existing {
@@ -327,6 +466,7 @@ pub fn trigger_expiration(
                     expiration,
                     rules,
                     rulesets,
+                    ruleset_versions: None,
                     banner,
                     instantiate_extension: LeagueInstantiateExt {
                         match_win_points,
@@ -351,6 +491,7 @@ pub fn trigger_expiration(
                         expiration,
                         rules,
                         rulesets,
+                        ruleset_versions: None,
                         banner,
                         instantiate_extension: TournamentInstantiateExt {
                             elimination_type,
@@ -377,7 +518,12 @@ pub fn trigger_expiration(
 
     TEMP_ENROLLMENT_INFO.save(deps.storage, &enrollment_info)?;
 
+    // Anyone still waiting for a slot or an approval is refunded
+    let mut refund_msgs = clear_waitlist(deps.storage, &entry, id)?;
+    refund_msgs.extend(clear_applications(deps.storage, &entry, id)?);
+
     Ok(Response::new()
+        .add_messages(refund_msgs)
         .add_attribute("action", "trigger_expiration")
         .add_attribute("competition_module", enrollment_info.module_addr)
         .add_attribute("id", id.to_string())
@@ -388,9 +534,38 @@ pub fn trigger_expiration(
                 .map(|x| x.to_string())
                 .unwrap_or("None".to_owned()),
         )
+        .add_attribute("triggered_by", enrollment_info.triggered_by)
         .add_submessage(sub_msg))
 }
 
+/// Creates the next edition of a recurring template's enrollment
+/// This is dispatched as a submessage so a failure does not block the trigger
+pub fn schedule_next_edition(
+    deps: Deps,
+    env: &Env,
+    entry: &EnrollmentEntry,
+) -> StdResult<Vec<SubMsg>> {
+    if let Some(template_id) = entry.template_id {
+        if let Some(template) = enrollment_templates().may_load(deps.storage, template_id.u128())? {
+            if template.info.is_recurring {
+                return Ok(vec![SubMsg::reply_on_error(
+                    CosmosMsg::Wasm(WasmMsg::Execute {
+                        contract_addr: env.contract.address.to_string(),
+                        msg: to_json_binary(&ExecuteMsg::CreateFromTemplate {
+                            template_id,
+                            start: None,
+                        })?,
+                        funds: vec![],
+                    }),
+                    CREATE_FROM_TEMPLATE_REPLY_ID,
+                )]);
+            }
+        }
+    }
+
+    Ok(vec![])
+}
+
 pub fn enroll(
     deps: DepsMut,
     env: Env,
@@ -413,16 +588,80 @@ pub fn enroll(
             }
         );
     }
+    ensure!(
+        !WAITLIST_POSITIONS.has(deps.storage, (id.u128(), &info.sender)),
+        ContractError::AlreadyEnrolled {}
+    );
+    ensure!(
+        entry
+            .unmet_requirements(deps.as_ref(), &info.sender)?
+            .is_empty(),
+        ContractError::NotEligible {}
+    );
+
+    if entry.approval_required.unwrap_or_default() {
+        ensure!(
+            !PENDING_APPLICATIONS.has(deps.storage, (id.u128(), &info.sender)),
+            ContractError::AlreadyEnrolled {}
+        );
+        ensure!(
+            !deps.querier.query_wasm_smart::<bool>(
+                entry.group_contract.to_string(),
+                &group::QueryMsg::IsMember {
+                    addr: info.sender.to_string(),
+                },
+            )?,
+            ContractError::AlreadyEnrolled {}
+        );
+
+        PENDING_APPLICATIONS.save(deps.storage, (id.u128(), &info.sender), &Empty {})?;
+
+        return Ok(Response::new()
+            .add_attribute("action", "enroll")
+            .add_attribute("result", "pending_approval"));
+    }
 
     let member_count: Uint64 = deps.querier.query_wasm_smart(
         entry.group_contract.to_string(),
         &group::QueryMsg::MembersCount {},
     )?;
 
-    ensure!(
-        member_count < entry.max_members,
-        ContractError::EnrollmentMaxMembers {}
-    );
+    if member_count >= entry.max_members {
+        let waitlist_size = entry
+            .waitlist_size
+            .ok_or(ContractError::EnrollmentMaxMembers {})?;
+
+        ensure!(
+            !deps.querier.query_wasm_smart::<bool>(
+                entry.group_contract.to_string(),
+                &group::QueryMsg::IsMember {
+                    addr: info.sender.to_string(),
+                },
+            )?,
+            ContractError::AlreadyEnrolled {}
+        );
+
+        let waitlist_count = ENROLLMENT_WAITLIST
+            .prefix(id.u128())
+            .keys_raw(deps.storage, None, None, Order::Ascending)
+            .count() as u64;
+        ensure!(
+            waitlist_count < waitlist_size.u64(),
+            ContractError::WaitlistFull {}
+        );
+
+        let position = WAITLIST_SEQUENCE
+            .may_load(deps.storage, id.u128())?
+            .unwrap_or_default();
+        ENROLLMENT_WAITLIST.save(deps.storage, (id.u128(), position), &info.sender)?;
+        WAITLIST_POSITIONS.save(deps.storage, (id.u128(), &info.sender), &position)?;
+        WAITLIST_SEQUENCE.save(deps.storage, id.u128(), &(position + 1))?;
+
+        return Ok(Response::new()
+            .add_attribute("action", "enroll")
+            .add_attribute("result", "waitlisted")
+            .add_attribute("position", (waitlist_count + 1).to_string()));
+    }
 
     let msg = CosmosMsg::Wasm(WasmMsg::Execute {
         contract_addr: entry.group_contract.to_string(),
@@ -451,7 +690,7 @@ pub fn withdraw(
     // Load the enrollment entry
     let entry = enrollment_entries().load(deps.storage, id.u128())?;
 
-    Ok(_withdraw(entry, vec![info.sender], id)?.add_attribute("action", "withdraw"))
+    Ok(_withdraw(deps, entry, vec![info.sender], id)?.add_attribute("action", "withdraw"))
 }
 
 pub fn force_withdraw(
@@ -479,10 +718,11 @@ pub fn force_withdraw(
         ))
     );
 
-    Ok(_withdraw(entry, members, id)?.add_attribute("action", "force_withdraw"))
+    Ok(_withdraw(deps, entry, members, id)?.add_attribute("action", "force_withdraw"))
 }
 
 pub fn _withdraw(
+    deps: DepsMut,
     entry: EnrollmentEntry,
     members: Vec<Addr>,
     id: Uint128,
@@ -511,16 +751,75 @@ pub fn _withdraw(
         vec![]
     };
 
-    // Create group update message to remove all members
-    let group_msg = CosmosMsg::Wasm(WasmMsg::Execute {
-        contract_addr: entry.group_contract.to_string(),
-        msg: to_json_binary(&group::ExecuteMsg::UpdateMembers {
-            to_add: None,
-            to_update: None,
-            to_remove: Some(members.iter().map(|m| m.to_string()).collect()),
-        })?,
-        funds: vec![],
-    });
+    // Waitlisted and pending applicants are only removed from the queue
+    let mut group_members = vec![];
+    for member in members.iter() {
+        if PENDING_APPLICATIONS.has(deps.storage, (id.u128(), member)) {
+            PENDING_APPLICATIONS.remove(deps.storage, (id.u128(), member));
+        } else if let Some(position) =
+            WAITLIST_POSITIONS.may_load(deps.storage, (id.u128(), member))?
+        {
+            ENROLLMENT_WAITLIST.remove(deps.storage, (id.u128(), position));
+            WAITLIST_POSITIONS.remove(deps.storage, (id.u128(), member));
+        } else {
+            group_members.push(member.to_string());
+        }
+    }
+
+    let mut msgs = vec![];
+    let mut promoted_attributes = vec![];
+    if !group_members.is_empty() {
+        // Promote waitlisted applicants into the freed slots in FIFO order
+        let promoted = if entry.has_triggered_expiration {
+            vec![]
+        } else {
+            ENROLLMENT_WAITLIST
+                .prefix(id.u128())
+                .range(deps.storage, None, None, Order::Ascending)
+                .take(group_members.len())
+                .collect::<StdResult<Vec<_>>>()?
+        };
+        for (position, addr) in promoted.iter() {
+            ENROLLMENT_WAITLIST.remove(deps.storage, (id.u128(), *position));
+            WAITLIST_POSITIONS.remove(deps.storage, (id.u128(), addr));
+        }
+
+        // Create group update message to remove all members
+        msgs.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: entry.group_contract.to_string(),
+            msg: to_json_binary(&group::ExecuteMsg::UpdateMembers {
+                to_add: None,
+                to_update: None,
+                to_remove: Some(group_members),
+            })?,
+            funds: vec![],
+        }));
+
+        if !promoted.is_empty() {
+            msgs.push(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: entry.group_contract.to_string(),
+                msg: to_json_binary(&group::ExecuteMsg::UpdateMembers {
+                    to_add: Some(
+                        promoted
+                            .iter()
+                            .map(|(_, addr)| group::AddMemberMsg {
+                                addr: addr.to_string(),
+                                seed: None,
+                            })
+                            .collect(),
+                    ),
+                    to_update: None,
+                    to_remove: None,
+                })?,
+                funds: vec![],
+            }));
+        }
+
+        promoted_attributes.extend(promoted.into_iter().map(|(_, addr)| Attribute {
+            key: "promoted_member".to_string(),
+            value: addr.to_string(),
+        }));
+    }
 
     // Create attributes for each withdrawn member
     let member_attributes: Vec<Attribute> = members
@@ -532,10 +831,319 @@ pub fn _withdraw(
         .collect();
 
     Ok(Response::new()
-        .add_message(group_msg)
+        .add_messages(msgs)
         .add_messages(refund_msgs)
         .add_attribute("id", id.to_string())
-        .add_attributes(member_attributes))
+        .add_attributes(member_attributes)
+        .add_attributes(promoted_attributes))
+}
+
+pub fn approve_applicants(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    id: Uint128,
+    applicants: Vec<String>,
+) -> Result<Response, ContractError> {
+    let entry = enrollment_entries().load(deps.storage, id.u128())?;
+
+    ensure!(entry.host == info.sender, ContractError::Unauthorized {});
+    ensure!(
+        !entry.has_triggered_expiration,
+        ContractError::AlreadyExpired {}
+    );
+
+    let applicants = take_applications(deps.storage, deps.api, id, applicants)?;
+
+    let member_count: Uint64 = deps.querier.query_wasm_smart(
+        entry.group_contract.to_string(),
+        &group::QueryMsg::MembersCount {},
+    )?;
+    ensure!(
+        member_count.checked_add(Uint64::new(applicants.len() as u64))? <= entry.max_members,
+        ContractError::EnrollmentMaxMembers {}
+    );
+
+    let msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: entry.group_contract.to_string(),
+        msg: to_json_binary(&group::ExecuteMsg::UpdateMembers {
+            to_add: Some(
+                applicants
+                    .iter()
+                    .map(|x| group::AddMemberMsg {
+                        addr: x.to_string(),
+                        seed: None,
+                    })
+                    .collect(),
+            ),
+            to_remove: None,
+            to_update: None,
+        })?,
+        funds: vec![],
+    });
+
+    Ok(Response::new()
+        .add_attribute("action", "approve_applicants")
+        .add_attribute("id", id.to_string())
+        .add_attributes(applicants.into_iter().map(|x| ("approved_applicant", x)))
+        .add_message(msg))
+}
+
+pub fn reject_applicants(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    id: Uint128,
+    applicants: Vec<String>,
+) -> Result<Response, ContractError> {
+    let entry = enrollment_entries().load(deps.storage, id.u128())?;
+
+    ensure!(entry.host == info.sender, ContractError::Unauthorized {});
+
+    let applicants = take_applications(deps.storage, deps.api, id, applicants)?;
+
+    // Refund the entry fees held for the rejected applications
+    let refund_msgs = if let Some(entry_fee) = &entry.entry_fee {
+        applicants
+            .iter()
+            .map(|applicant| {
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: applicant.to_string(),
+                    amount: vec![entry_fee.clone()],
+                })
+            })
+            .collect::<Vec<_>>()
+    } else {
+        vec![]
+    };
+
+    Ok(Response::new()
+        .add_attribute("action", "reject_applicants")
+        .add_attribute("id", id.to_string())
+        .add_attributes(applicants.into_iter().map(|x| ("rejected_applicant", x)))
+        .add_messages(refund_msgs))
+}
+
+pub fn create_template(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    template: EnrollmentTemplateInfo,
+) -> Result<Response, ContractError> {
+    validate_template(&template)?;
+
+    let template_id = TEMPLATE_COUNT
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .checked_add(Uint128::one())?;
+    TEMPLATE_COUNT.save(deps.storage, &template_id)?;
+
+    enrollment_templates().save(
+        deps.storage,
+        template_id.u128(),
+        &EnrollmentTemplate {
+            host: info.sender,
+            info: template,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "create_template")
+        .add_attribute("template_id", template_id))
+}
+
+pub fn update_template(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    template_id: Uint128,
+    template: EnrollmentTemplateInfo,
+) -> Result<Response, ContractError> {
+    let prev = enrollment_templates().load(deps.storage, template_id.u128())?;
+
+    ensure!(prev.host == info.sender, ContractError::Unauthorized {});
+    validate_template(&template)?;
+
+    let new = EnrollmentTemplate {
+        host: prev.host.clone(),
+        info: template,
+    };
+    enrollment_templates().replace(deps.storage, template_id.u128(), Some(&new), Some(&prev))?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_template")
+        .add_attribute("template_id", template_id))
+}
+
+pub fn remove_template(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    template_id: Uint128,
+) -> Result<Response, ContractError> {
+    let template = enrollment_templates().load(deps.storage, template_id.u128())?;
+
+    ensure!(template.host == info.sender, ContractError::Unauthorized {});
+
+    enrollment_templates().remove(deps.storage, template_id.u128())?;
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_template")
+        .add_attribute("template_id", template_id))
+}
+
+pub fn create_from_template(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    template_id: Uint128,
+    start: Option<Expiration>,
+) -> Result<Response, ContractError> {
+    let template = enrollment_templates().load(deps.storage, template_id.u128())?;
+
+    // Recurring editions are created by the contract itself
+    ensure!(
+        template.host == info.sender || env.contract.address == info.sender,
+        ContractError::Unauthorized {}
+    );
+
+    let expiration = match start {
+        Some(start) => (start + template.info.enrollment_duration)?,
+        None => template.info.enrollment_duration.after(&env.block),
+    };
+    let competition_expiration = (expiration + template.info.competition_duration)?;
+
+    let template_info = template.info;
+    Ok(create_enrollment(
+        deps,
+        env,
+        template.host,
+        template_info.min_members,
+        template_info.max_members,
+        template_info.entry_fee,
+        expiration,
+        template_info.category_id,
+        CompetitionInfoMsg {
+            name: template_info.name,
+            description: template_info.description,
+            expiration: competition_expiration,
+            rules: template_info.rules,
+            rulesets: template_info.rulesets,
+            banner: template_info.banner,
+            additional_layered_fees: template_info.additional_layered_fees,
+        },
+        template_info.competition_type,
+        template_info.group_contract_info,
+        template_info.waitlist_size,
+        template_info.eligibility_requirements,
+        template_info.approval_required,
+        template_info.escrow_id,
+        template_info.trigger_bounty,
+        Some(template_id),
+    )?
+    .add_attribute("template_id", template_id))
+}
+
+fn validate_template(template: &EnrollmentTemplateInfo) -> Result<(), ContractError> {
+    ensure!(
+        matches!(
+            (
+                &template.enrollment_duration,
+                &template.competition_duration
+            ),
+            (Duration::Height(_), Duration::Height(_)) | (Duration::Time(_), Duration::Time(_))
+        ),
+        ContractError::StdError(StdError::generic_err(
+            "Template durations must both be in heights or both in time"
+        ))
+    );
+
+    Ok(())
+}
+
+/// Validates and removes the pending applications, failing if any of them do not exist
+fn take_applications(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    id: Uint128,
+    applicants: Vec<String>,
+) -> Result<Vec<Addr>, ContractError> {
+    let applicants = applicants
+        .into_iter()
+        .unique()
+        .map(|x| api.addr_validate(&x))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    ensure!(
+        !applicants.is_empty(),
+        ContractError::StdError(StdError::generic_err("No applicants provided"))
+    );
+
+    for applicant in applicants.iter() {
+        ensure!(
+            PENDING_APPLICATIONS.has(storage, (id.u128(), applicant)),
+            ContractError::ApplicationNotFound {
+                addr: applicant.to_string()
+            }
+        );
+
+        PENDING_APPLICATIONS.remove(storage, (id.u128(), applicant));
+    }
+
+    Ok(applicants)
+}
+
+/// Removes every pending application of an enrollment and refunds their entry fees
+fn clear_applications(
+    storage: &mut dyn Storage,
+    entry: &EnrollmentEntry,
+    id: Uint128,
+) -> StdResult<Vec<CosmosMsg>> {
+    let applicants = PENDING_APPLICATIONS
+        .prefix(id.u128())
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut msgs = vec![];
+    for applicant in applicants {
+        PENDING_APPLICATIONS.remove(storage, (id.u128(), &applicant));
+
+        if let Some(entry_fee) = &entry.entry_fee {
+            msgs.push(CosmosMsg::Bank(BankMsg::Send {
+                to_address: applicant.to_string(),
+                amount: vec![entry_fee.clone()],
+            }));
+        }
+    }
+
+    Ok(msgs)
+}
+
+/// Removes every applicant from an enrollment's waitlist and refunds their entry fees
+fn clear_waitlist(
+    storage: &mut dyn Storage,
+    entry: &EnrollmentEntry,
+    id: Uint128,
+) -> StdResult<Vec<CosmosMsg>> {
+    let waitlist = ENROLLMENT_WAITLIST
+        .prefix(id.u128())
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut msgs = vec![];
+    for (position, addr) in waitlist {
+        ENROLLMENT_WAITLIST.remove(storage, (id.u128(), position));
+        WAITLIST_POSITIONS.remove(storage, (id.u128(), &addr));
+
+        if let Some(entry_fee) = &entry.entry_fee {
+            msgs.push(CosmosMsg::Bank(BankMsg::Send {
+                to_address: addr.to_string(),
+                amount: vec![entry_fee.clone()],
+            }));
+        }
+    }
+
+    Ok(msgs)
 }
 
 fn get_min_min_members(competition_type: &CompetitionType) -> Uint64 {
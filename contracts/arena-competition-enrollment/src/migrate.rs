@@ -57,6 +57,12 @@ pub fn from_v2_to_v2_1(deps: DepsMut, env: &Env, group_id: u64) -> StdResult<Vec
             category_id: enrollment.category_id,
             competition_module: enrollment.competition_module,
             group_contract,
+            waitlist_size: None,
+            eligibility_requirements: None,
+            approval_required: None,
+            escrow_id: None,
+            trigger_bounty: None,
+            template_id: None,
         };
 
         enrollment_entries().replace(
@@ -71,3 +77,23 @@ pub fn from_v2_to_v2_1(deps: DepsMut, env: &Env, group_id: u64) -> StdResult<Vec
 
     Ok(msgs)
 }
+
+/// Enrollments created before the escrow was stored can only be triggered by their host
+pub fn backfill_escrow_id(deps: DepsMut, escrow_id: u64) -> StdResult<()> {
+    for (enrollment_id, mut enrollment) in enrollment_entries()
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?
+    {
+        if enrollment.escrow_id.is_some()
+            || enrollment.entry_fee.is_none()
+            || enrollment.has_triggered_expiration
+        {
+            continue;
+        }
+
+        enrollment.escrow_id = Some(escrow_id);
+        enrollment_entries().save(deps.storage, enrollment_id, &enrollment)?;
+    }
+
+    Ok(())
+}
@@ -1,10 +1,13 @@
 use arena_interface::fees::FeeInformation;
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Coin, Uint128, Uint64};
+use cosmwasm_std::{Coin, Decimal, Uint128, Uint64};
 use cw_utils::Expiration;
 use dao_interface::state::ModuleInstantiateInfo;
 
-use crate::state::{CompetitionType, EnrollmentEntryResponse};
+use crate::state::{
+    CompetitionType, EligibilityRequirement, EligibilityResponse, EnrollmentEntryResponse,
+    EnrollmentTemplateInfo, EnrollmentTemplateResponse,
+};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -28,6 +31,15 @@ pub enum ExecuteMsg {
         competition_info: CompetitionInfoMsg,
         competition_type: CompetitionType,
         group_contract_info: ModuleInstantiateInfo,
+        /// Allows applicants to queue for a slot once the enrollment is full
+        waitlist_size: Option<Uint64>,
+        eligibility_requirements: Option<Vec<EligibilityRequirement<String>>>,
+        /// Enrollments are held as pending applications until the host approves them
+        approval_required: Option<bool>,
+        /// The escrow code id used when anyone other than the host triggers the enrollment
+        escrow_id: Option<u64>,
+        /// The percentage of the entry fees paid to a non-host caller of TriggerExpiration
+        trigger_bounty: Option<Decimal>,
     },
     TriggerExpiration {
         id: Uint128,
@@ -44,6 +56,30 @@ pub enum ExecuteMsg {
         id: Uint128,
         members: Vec<String>,
     },
+    ApproveApplicants {
+        id: Uint128,
+        applicants: Vec<String>,
+    },
+    RejectApplicants {
+        id: Uint128,
+        applicants: Vec<String>,
+    },
+    CreateTemplate {
+        template: EnrollmentTemplateInfo,
+    },
+    UpdateTemplate {
+        template_id: Uint128,
+        template: EnrollmentTemplateInfo,
+    },
+    RemoveTemplate {
+        template_id: Uint128,
+    },
+    CreateFromTemplate {
+        template_id: Uint128,
+        /// The point the template's durations are measured from
+        /// Defaults to the current block
+        start: Option<Expiration>,
+    },
 }
 
 #[cw_serde]
@@ -82,6 +118,28 @@ pub enum QueryMsg {
         enrollment_id: Uint128,
         addr: String,
     },
+    #[returns(EligibilityResponse)]
+    CheckEligibility { id: Uint128, addr: String },
+    #[returns(Vec<cosmwasm_std::Addr>)]
+    Applications {
+        enrollment_id: Uint128,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(EnrollmentTemplateResponse)]
+    Template { template_id: Uint128 },
+    #[returns(Vec<EnrollmentTemplateResponse>)]
+    Templates {
+        start_after: Option<Uint128>,
+        limit: Option<u32>,
+        host: Option<String>,
+    },
+    #[returns(Vec<cosmwasm_std::Addr>)]
+    Waitlist {
+        enrollment_id: Uint128,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
 }
 
 #[cw_serde]
@@ -89,6 +147,7 @@ pub enum MigrateMsg {
     FromCompatible {},
     WithGroupId { group_id: u64 },
     RemoveThirdPlaceMatch { enrollment_id: Uint128 },
+    WithEscrowId { escrow_id: u64 },
 }
 
 #[cw_serde]
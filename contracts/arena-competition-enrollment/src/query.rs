@@ -1,10 +1,13 @@
 use arena_interface::group;
-use cosmwasm_std::{Deps, Env, Order, StdResult, Uint128};
+use cosmwasm_std::{Addr, Deps, Env, Order, StdResult, Uint128};
 use cw_storage_plus::Bound;
 
 use crate::{
     msg::EnrollmentFilter,
-    state::{enrollment_entries, EnrollmentEntryResponse, ENROLLMENT_COUNT},
+    state::{
+        enrollment_entries, enrollment_templates, EligibilityResponse, EnrollmentEntryResponse,
+        EnrollmentTemplateResponse, ENROLLMENT_COUNT, ENROLLMENT_WAITLIST, PENDING_APPLICATIONS,
+    },
 };
 
 pub fn enrollments(
@@ -58,3 +61,94 @@ pub fn is_member(deps: Deps, enrollment_id: Uint128, addr: String) -> StdResult<
         &group::QueryMsg::IsMember { addr },
     )
 }
+
+pub fn check_eligibility(deps: Deps, id: Uint128, addr: String) -> StdResult<EligibilityResponse> {
+    let enrollment = enrollment_entries().load(deps.storage, id.u128())?;
+    let addr = deps.api.addr_validate(&addr)?;
+
+    let unmet_requirements = enrollment.unmet_requirements(deps, &addr)?;
+
+    Ok(EligibilityResponse {
+        is_eligible: unmet_requirements.is_empty(),
+        unmet_requirements,
+    })
+}
+
+pub fn applications(
+    deps: Deps,
+    enrollment_id: Uint128,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<Addr>> {
+    let start_after = start_after
+        .map(|x| deps.api.addr_validate(&x))
+        .transpose()?;
+    let start_after_bound = start_after.as_ref().map(Bound::exclusive);
+    let limit = limit.unwrap_or(30).min(30);
+
+    PENDING_APPLICATIONS
+        .prefix(enrollment_id.u128())
+        .keys(deps.storage, start_after_bound, None, Order::Ascending)
+        .take(limit as usize)
+        .collect::<StdResult<Vec<_>>>()
+}
+
+pub fn template(deps: Deps, template_id: Uint128) -> StdResult<EnrollmentTemplateResponse> {
+    let template = enrollment_templates().load(deps.storage, template_id.u128())?;
+
+    Ok(EnrollmentTemplateResponse {
+        id: template_id,
+        host: template.host,
+        info: template.info,
+    })
+}
+
+pub fn templates(
+    deps: Deps,
+    start_after: Option<Uint128>,
+    limit: Option<u32>,
+    host: Option<String>,
+) -> StdResult<Vec<EnrollmentTemplateResponse>> {
+    // Templates are listed newest first, so start_after is the upper bound
+    let start_after_bound = start_after.map(|x| x.u128()).map(Bound::exclusive);
+    let limit = limit.unwrap_or(30).min(30);
+
+    let iter = match host {
+        Some(host) => enrollment_templates().idx.host.prefix(host).range(
+            deps.storage,
+            None,
+            start_after_bound,
+            Order::Descending,
+        ),
+        None => {
+            enrollment_templates().range(deps.storage, None, start_after_bound, Order::Descending)
+        }
+    };
+
+    iter.map(|x| {
+        x.map(|(id, template)| EnrollmentTemplateResponse {
+            id: Uint128::new(id),
+            host: template.host,
+            info: template.info,
+        })
+    })
+    .take(limit as usize)
+    .collect::<StdResult<Vec<_>>>()
+}
+
+pub fn waitlist(
+    deps: Deps,
+    enrollment_id: Uint128,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<Addr>> {
+    let start_after_bound = start_after.map(Bound::exclusive);
+    let limit = limit.unwrap_or(30).min(30);
+
+    ENROLLMENT_WAITLIST
+        .prefix(enrollment_id.u128())
+        .range(deps.storage, start_after_bound, None, Order::Ascending)
+        .map(|x| x.map(|y| y.1))
+        .take(limit as usize)
+        .collect::<StdResult<Vec<_>>>()
+}
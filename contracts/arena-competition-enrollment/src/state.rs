@@ -1,11 +1,21 @@
 use std::fmt;
 
-use arena_interface::{competition::state::CompetitionResponse, fees::FeeInformation, group};
+use arena_discord_identity::msg::DiscordProfile;
+use arena_interface::{
+    competition::state::CompetitionResponse, core::CompetitionCategory, fees::FeeInformation,
+    group, ratings::Rating,
+};
 use arena_tournament_module::state::EliminationType;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, BlockInfo, Coin, Decimal, Deps, Empty, StdResult, Uint128, Uint64};
+use cosmwasm_std::{
+    ensure, Addr, BlockInfo, Coin, Decimal, Deps, Empty, Order, SignedDecimal, StdError, StdResult,
+    Uint128, Uint64,
+};
+use cw_address_like::AddressLike;
 use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
-use cw_utils::Expiration;
+use cw_utils::{Duration, Expiration};
+use dao_interface::state::ModuleInstantiateInfo;
+use itertools::Itertools as _;
 
 #[cw_serde]
 pub struct EnrollmentEntry {
@@ -20,6 +30,18 @@ pub struct EnrollmentEntry {
     pub category_id: Option<Uint128>,
     pub competition_module: Addr,
     pub group_contract: Addr,
+    /// The maximum number of applicants that can wait for a slot once the enrollment is full
+    pub waitlist_size: Option<Uint64>,
+    /// Every requirement must be met by an applicant to enroll
+    pub eligibility_requirements: Option<Vec<EligibilityRequirement<Addr>>>,
+    /// Applicants must be approved by the host before they are added to the group
+    pub approval_required: Option<bool>,
+    /// The escrow code id required when the enrollment is triggered by someone other than the host
+    pub escrow_id: Option<u64>,
+    /// The percentage of the entry fees paid to whoever triggers the enrollment in place of the host
+    pub trigger_bounty: Option<Decimal>,
+    /// The template this enrollment was created from
+    pub template_id: Option<Uint128>,
 }
 
 #[cw_serde]
@@ -52,6 +74,13 @@ pub struct EnrollmentEntryResponse {
     pub is_expired: bool,
     pub competition_module: Addr,
     pub group_contract: Addr,
+    pub waitlist_size: Option<Uint64>,
+    pub waitlist_count: Uint64,
+    pub eligibility_requirements: Option<Vec<EligibilityRequirement<Addr>>>,
+    pub approval_required: bool,
+    pub escrow_id: Option<u64>,
+    pub trigger_bounty: Option<Decimal>,
+    pub template_id: Option<Uint128>,
 }
 
 #[cw_serde]
@@ -78,6 +107,12 @@ impl EnrollmentEntry {
             &group::QueryMsg::MembersCount {},
         )?;
         let is_expired = self.expiration.is_expired(block);
+        let waitlist_count = Uint64::new(
+            ENROLLMENT_WAITLIST
+                .prefix(id.u128())
+                .keys_raw(deps.storage, None, None, Order::Ascending)
+                .count() as u64,
+        );
 
         Ok(EnrollmentEntryResponse {
             category_id: self.category_id,
@@ -96,8 +131,210 @@ impl EnrollmentEntry {
             is_expired,
             competition_module: self.competition_module,
             group_contract: self.group_contract,
+            waitlist_size: self.waitlist_size,
+            waitlist_count,
+            eligibility_requirements: self.eligibility_requirements,
+            approval_required: self.approval_required.unwrap_or_default(),
+            escrow_id: self.escrow_id,
+            trigger_bounty: self.trigger_bounty,
+            template_id: self.template_id,
         })
     }
+
+    /// Returns the eligibility requirements the address does not meet
+    pub fn unmet_requirements(
+        &self,
+        deps: Deps,
+        addr: &Addr,
+    ) -> StdResult<Vec<EligibilityRequirement<Addr>>> {
+        let mut unmet = vec![];
+
+        if let Some(requirements) = &self.eligibility_requirements {
+            for requirement in requirements {
+                if !requirement.is_met(deps, addr)? {
+                    unmet.push(requirement.clone());
+                }
+            }
+        }
+
+        Ok(unmet)
+    }
+}
+
+#[cw_serde]
+pub enum EligibilityRequirement<T: AddressLike> {
+    /// The applicant's rating in the category must be within the bounds
+    /// Unrated applicants are evaluated with the category's initial rating
+    Rating {
+        category_id: Uint128,
+        min: Option<SignedDecimal>,
+        max: Option<SignedDecimal>,
+    },
+    /// The applicant must hold at least one token of the cw721 collection
+    Cw721 {
+        addr: T,
+    },
+    /// The applicant must hold at least the amount of the cw20 token
+    Cw20 {
+        addr: T,
+        amount: Uint128,
+    },
+    /// The applicant must have voting power in the DAO
+    DaoMember {
+        addr: T,
+    },
+    /// The applicant must have a Discord profile set in the identity contract
+    DiscordVerified {
+        addr: T,
+    },
+    Allowlist {
+        addrs: Vec<T>,
+    },
+}
+
+impl EligibilityRequirement<String> {
+    pub fn into_checked(&self, deps: Deps) -> StdResult<EligibilityRequirement<Addr>> {
+        Ok(match self {
+            EligibilityRequirement::Rating {
+                category_id,
+                min,
+                max,
+            } => {
+                if let (Some(min), Some(max)) = (min, max) {
+                    ensure!(
+                        min <= max,
+                        StdError::generic_err("Minimum rating cannot be larger than the maximum")
+                    );
+                }
+
+                EligibilityRequirement::Rating {
+                    category_id: *category_id,
+                    min: *min,
+                    max: *max,
+                }
+            }
+            EligibilityRequirement::Cw721 { addr } => EligibilityRequirement::Cw721 {
+                addr: deps.api.addr_validate(addr)?,
+            },
+            EligibilityRequirement::Cw20 { addr, amount } => EligibilityRequirement::Cw20 {
+                addr: deps.api.addr_validate(addr)?,
+                amount: *amount,
+            },
+            EligibilityRequirement::DaoMember { addr } => EligibilityRequirement::DaoMember {
+                addr: deps.api.addr_validate(addr)?,
+            },
+            EligibilityRequirement::DiscordVerified { addr } => {
+                EligibilityRequirement::DiscordVerified {
+                    addr: deps.api.addr_validate(addr)?,
+                }
+            }
+            EligibilityRequirement::Allowlist { addrs } => EligibilityRequirement::Allowlist {
+                addrs: addrs
+                    .iter()
+                    .unique()
+                    .map(|x| deps.api.addr_validate(x))
+                    .collect::<StdResult<Vec<_>>>()?,
+            },
+        })
+    }
+}
+
+impl EligibilityRequirement<Addr> {
+    pub fn is_met(&self, deps: Deps, addr: &Addr) -> StdResult<bool> {
+        Ok(match self {
+            EligibilityRequirement::Rating {
+                category_id,
+                min,
+                max,
+            } => {
+                let arena_core = cw_ownable::get_ownership(deps.storage)?
+                    .owner
+                    .ok_or(StdError::generic_err("Missing the Arena Core owner"))?;
+                let rating = match deps.querier.query_wasm_smart::<Option<Rating>>(
+                    &arena_core,
+                    &arena_interface::core::QueryMsg::QueryExtension {
+                        msg: arena_interface::core::QueryExt::Rating {
+                            category_id: *category_id,
+                            addr: addr.to_string(),
+                            height: None,
+                        },
+                    },
+                )? {
+                    Some(rating) => rating,
+                    None => deps
+                        .querier
+                        .query_wasm_smart::<Option<CompetitionCategory>>(
+                            &arena_core,
+                            &arena_interface::core::QueryMsg::QueryExtension {
+                                msg: arena_interface::core::QueryExt::Category { id: *category_id },
+                            },
+                        )?
+                        .ok_or(StdError::generic_err("Could not find the category"))?
+                        .rating_config
+                        .unwrap_or_default()
+                        .initial_rating(),
+                };
+
+                min.is_none_or(|min| rating.value >= min)
+                    && max.is_none_or(|max| rating.value <= max)
+            }
+            EligibilityRequirement::Cw721 { addr: collection } => {
+                let response: cw721::TokensResponse = deps.querier.query_wasm_smart(
+                    collection,
+                    &cw721::Cw721QueryMsg::Tokens {
+                        owner: addr.to_string(),
+                        start_after: None,
+                        limit: Some(1),
+                    },
+                )?;
+
+                !response.tokens.is_empty()
+            }
+            EligibilityRequirement::Cw20 {
+                addr: token,
+                amount,
+            } => {
+                let response: cw20::BalanceResponse = deps.querier.query_wasm_smart(
+                    token,
+                    &cw20::Cw20QueryMsg::Balance {
+                        address: addr.to_string(),
+                    },
+                )?;
+
+                response.balance >= *amount
+            }
+            EligibilityRequirement::DaoMember { addr: dao } => {
+                let response: dao_interface::voting::VotingPowerAtHeightResponse =
+                    deps.querier.query_wasm_smart(
+                        dao,
+                        &dao_interface::msg::QueryMsg::VotingPowerAtHeight {
+                            address: addr.to_string(),
+                            height: None,
+                        },
+                    )?;
+
+                !response.power.is_zero()
+            }
+            EligibilityRequirement::DiscordVerified {
+                addr: identity_contract,
+            } => deps
+                .querier
+                .query_wasm_smart::<Option<DiscordProfile>>(
+                    identity_contract,
+                    &arena_discord_identity::msg::QueryMsg::DiscordProfile {
+                        addr: addr.to_string(),
+                    },
+                )?
+                .is_some(),
+            EligibilityRequirement::Allowlist { addrs } => addrs.contains(addr),
+        })
+    }
+}
+
+#[cw_serde]
+pub struct EligibilityResponse {
+    pub is_eligible: bool,
+    pub unmet_requirements: Vec<EligibilityRequirement<Addr>>,
 }
 
 #[cw_serde]
@@ -250,15 +487,90 @@ pub fn enrollment_entriesv2<'a>(
     IndexedMap::new("enrollment_entries", indexes)
 }
 
+#[cw_serde]
+pub struct EnrollmentTemplateInfo {
+    pub min_members: Option<Uint64>,
+    pub max_members: Uint64,
+    pub entry_fee: Option<Coin>,
+    /// How long each edition's enrollment stays open from its start
+    pub enrollment_duration: Duration,
+    /// How long each edition's competition runs after its enrollment expires
+    pub competition_duration: Duration,
+    pub category_id: Option<Uint128>,
+    pub name: String,
+    pub description: String,
+    pub rules: Option<Vec<String>>,
+    pub rulesets: Option<Vec<Uint128>>,
+    pub banner: Option<String>,
+    pub additional_layered_fees: Option<Vec<FeeInformation<String>>>,
+    pub competition_type: CompetitionType,
+    pub group_contract_info: ModuleInstantiateInfo,
+    pub waitlist_size: Option<Uint64>,
+    pub eligibility_requirements: Option<Vec<EligibilityRequirement<String>>>,
+    pub approval_required: Option<bool>,
+    pub escrow_id: Option<u64>,
+    pub trigger_bounty: Option<Decimal>,
+    /// Triggering an edition into a competition automatically creates the next one
+    /// Editions that expire without enough members end the recurrence
+    pub is_recurring: bool,
+}
+
+#[cw_serde]
+pub struct EnrollmentTemplate {
+    pub host: Addr,
+    pub info: EnrollmentTemplateInfo,
+}
+
+#[cw_serde]
+pub struct EnrollmentTemplateResponse {
+    pub id: Uint128,
+    pub host: Addr,
+    pub info: EnrollmentTemplateInfo,
+}
+
+pub struct EnrollmentTemplateIndexes<'a> {
+    pub host: MultiIndex<'a, String, EnrollmentTemplate, u128>,
+}
+
+impl IndexList<EnrollmentTemplate> for EnrollmentTemplateIndexes<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<EnrollmentTemplate>> + '_> {
+        let v: Vec<&dyn Index<EnrollmentTemplate>> = vec![&self.host];
+        Box::new(v.into_iter())
+    }
+}
+
+pub fn enrollment_templates<'a>(
+) -> IndexedMap<'a, u128, EnrollmentTemplate, EnrollmentTemplateIndexes<'a>> {
+    let indexes = EnrollmentTemplateIndexes {
+        host: MultiIndex::new(
+            |_x, d: &EnrollmentTemplate| d.host.to_string(),
+            "enrollment_templates",
+            "enrollment_templates__host",
+        ),
+    };
+    IndexedMap::new("enrollment_templates", indexes)
+}
+
 pub const ENROLLMENT_COUNT: Item<Uint128> = Item::new("enrollment_count");
+pub const TEMPLATE_COUNT: Item<Uint128> = Item::new("template_count");
 /// Stores the module address and enrollment id to process in a reply
 pub const TEMP_ENROLLMENT_INFO: Item<EnrollmentInfo> = Item::new("temp_enrollment_info");
 // Store this for migration - deleted after migration
 pub const ENROLLMENT_MEMBERS: Map<(u128, &Addr), Empty> = Map::new("enrollment_members");
+/// Stores the waitlisted applicants of an enrollment in FIFO order by their queue position
+pub const ENROLLMENT_WAITLIST: Map<(u128, u64), Addr> = Map::new("enrollment_waitlist");
+/// Stores the queue position of each waitlisted applicant
+pub const WAITLIST_POSITIONS: Map<(u128, &Addr), u64> = Map::new("waitlist_positions");
+/// Stores the next queue position to assign for an enrollment's waitlist
+pub const WAITLIST_SEQUENCE: Map<u128, u64> = Map::new("waitlist_sequence");
+/// Stores the applications awaiting the host's approval
+pub const PENDING_APPLICATIONS: Map<(u128, &Addr), Empty> = Map::new("pending_applications");
 
 #[cw_serde]
 pub struct EnrollmentInfo {
     pub module_addr: Addr,
     pub enrollment_id: u128,
     pub amount: Option<Coin>,
+    pub bounty: Option<Coin>,
+    pub triggered_by: Addr,
 }
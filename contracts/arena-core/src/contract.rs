@@ -1,9 +1,10 @@
 use crate::{
-    execute::{self, COMPETITION_MODULE_REPLY_ID},
+    execute::{self, COMPETITION_MODULE_REPLY_ID, JAIL_BOND_REPLY_ID, MODULE_UPGRADE_REPLY_ID},
     migrate, query,
     state::{
-        competition_modules, rulesets, CompetitionModule, ARENA_TAX_CONFIG,
-        COMPETITION_CATEGORIES_COUNT, KEYS, PAYMENT_REGISTRY, RATING_PERIOD, RULESETS_COUNT,
+        competition_modules, rulesets, CompetitionModule, RatingsMigration, ARENA_TAX_CONFIG,
+        COMPETITION_CATEGORIES_COUNT, FEE_CEILING, JAIL_PROPOSALS, KEYS, PAYMENT_REGISTRY,
+        RATING_PERIOD, RATING_PERIOD_ENDS, RULESETS_COUNT, SEASONS,
     },
     ContractError,
 };
@@ -15,7 +16,7 @@ use arena_interface::core::{
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
     from_json, to_json_binary, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Reply, Response,
-    StdError, Uint128, WasmMsg,
+    StdError, SubMsgResult, Uint128, WasmMsg,
 };
 use cw2::{ensure_from_older_version, set_contract_version};
 use cw_utils::parse_reply_instantiate_data;
@@ -82,6 +83,7 @@ pub fn instantiate_extension(
                 msg: ExecuteExt::UpdateRulesets {
                     to_add: Some(rulesets),
                     to_disable: None,
+                    to_edit: None,
                 },
             })?,
             funds: vec![],
@@ -155,9 +157,20 @@ pub fn execute(
     match msg {
         ExecuteMsg::Propose { msg } => Ok(execute::propose(deps, env, info, msg)?),
         ExecuteMsg::Extension { msg } => {
-            // Check authorization for all Extension messages except AdjustRatings
-            if !matches!(msg, ExecuteExt::AdjustRatings { .. })
-                && env.contract.address != info.sender
+            // Check authorization for all Extension messages except rating adjustments, CloseRatingPeriod, continued reverts, soft resets, escrow and ratings migrations, host activity, and community proposals
+            if !matches!(
+                msg,
+                ExecuteExt::AdjustRatings { .. }
+                    | ExecuteExt::AdjustTeamRatings { .. }
+                    | ExecuteExt::CloseRatingPeriod { .. }
+                    | ExecuteExt::RevertRatingAdjustments { .. }
+                    | ExecuteExt::ContinueSoftReset { .. }
+                    | ExecuteExt::ContinueEscrowMigration { .. }
+                    | ExecuteExt::ContinueRatingsMigration { .. }
+                    | ExecuteExt::RecordHostActivity { .. }
+                    | ExecuteExt::ProposeRuleset { .. }
+                    | ExecuteExt::ProposeCategory { .. }
+            ) && env.contract.address != info.sender
             {
                 let dao = PrePropose::default().dao.load(deps.storage)?;
                 if dao != info.sender {
@@ -169,35 +182,150 @@ pub fn execute(
                 ExecuteExt::UpdateCompetitionModules { to_add, to_disable } => {
                     execute::update_competition_modules(deps, info.sender, to_add, to_disable)
                 }
-                ExecuteExt::UpdateRulesets { to_add, to_disable } => {
-                    execute::update_rulesets(deps, to_add, to_disable)
-                }
+                ExecuteExt::UpgradeCompetitionModule {
+                    key,
+                    code_id,
+                    migrate_msg,
+                    escrow_migration,
+                } => execute::upgrade_competition_module(
+                    deps,
+                    &env,
+                    key,
+                    code_id,
+                    migrate_msg,
+                    escrow_migration,
+                ),
+                ExecuteExt::UpdateRulesets {
+                    to_add,
+                    to_disable,
+                    to_edit,
+                } => execute::update_rulesets(deps, to_add, to_disable, to_edit),
                 ExecuteExt::UpdateTax { tax } => execute::update_tax(deps, &env, tax),
+                ExecuteExt::UpdateTaxSchedule {
+                    category_taxes,
+                    module_taxes,
+                    volume_tiers,
+                } => execute::update_tax_schedule(
+                    deps,
+                    &env,
+                    category_taxes,
+                    module_taxes,
+                    volume_tiers,
+                ),
+                ExecuteExt::UpdateFeeCeiling { fee_ceiling } => {
+                    execute::update_fee_ceiling(deps, fee_ceiling)
+                }
                 ExecuteExt::UpdateCategories { to_add, to_edit } => {
                     execute::update_categories(deps, to_add, to_edit)
                 }
                 ExecuteExt::AdjustRatings {
                     category_id,
                     member_results,
-                } => execute::adjust_ratings(deps, env, info, category_id, member_results),
+                    competition_id,
+                    match_numbers,
+                } => execute::adjust_ratings(
+                    deps,
+                    env,
+                    info,
+                    category_id,
+                    member_results,
+                    competition_id,
+                    match_numbers,
+                ),
+                ExecuteExt::AdjustTeamRatings {
+                    category_id,
+                    matches,
+                    competition_id,
+                } => execute::adjust_team_ratings(
+                    deps,
+                    env,
+                    info,
+                    category_id,
+                    matches,
+                    competition_id,
+                ),
+                ExecuteExt::RevertRatingAdjustments {
+                    module,
+                    competition_id,
+                    limit,
+                } => execute::revert_rating_adjustments(
+                    deps,
+                    env,
+                    info,
+                    module,
+                    competition_id,
+                    limit,
+                ),
                 ExecuteExt::UpdateRatingPeriod { period } => {
                     execute::update_rating_period(deps, period)
                 }
+                ExecuteExt::CloseRatingPeriod {
+                    category_id,
+                    start_after,
+                    limit,
+                } => execute::close_rating_period(deps, env, category_id, start_after, limit),
+                ExecuteExt::StartSeason {
+                    category_id,
+                    soft_reset,
+                    reward,
+                } => execute::start_season(deps, env, info, category_id, soft_reset, reward),
+                ExecuteExt::ContinueSoftReset { category_id, limit } => {
+                    execute::continue_soft_reset(deps, env, category_id, limit)
+                }
+                ExecuteExt::ContinueEscrowMigration { key, limit } => {
+                    execute::continue_escrow_migration(deps, key, limit)
+                }
+                ExecuteExt::ContinueRatingsMigration { limit } => {
+                    execute::continue_ratings_migration(deps, limit)
+                }
                 ExecuteExt::UpdateEnrollmentModules { to_add, to_remove } => {
                     execute::update_enrollment_modules(deps, to_add, to_remove)
                 }
                 ExecuteExt::SetPaymentRegistry { addr } => {
                     execute::set_payment_registry(deps, addr)
                 }
+                ExecuteExt::UpdateHostDenylist { to_add, to_remove } => {
+                    execute::update_host_denylist(deps, &env, to_add, to_remove)
+                }
+                ExecuteExt::RecordHostActivity { host, activity } => {
+                    execute::record_host_activity(deps, info, host, activity)
+                }
+                ExecuteExt::ProposeRuleset {
+                    title,
+                    description,
+                    ruleset,
+                } => execute::propose_ruleset(deps, env, info, title, description, ruleset),
+                ExecuteExt::ProposeCategory {
+                    title,
+                    description,
+                    category,
+                } => execute::propose_category(deps, env, info, title, description, category),
             }
         }
+        ExecuteMsg::ProposalCompletedHook {
+            proposal_id,
+            new_status,
+        } => {
+            let msgs = execute::release_jail_bond(deps.as_ref(), proposal_id, &new_status)?;
+            let response = PrePropose::default().execute(
+                deps,
+                env,
+                info,
+                ExecuteMsg::ProposalCompletedHook {
+                    proposal_id,
+                    new_status,
+                },
+            )?;
+
+            Ok(response.add_submessages(msgs))
+        }
         // Default pre-propose-base behavior for all other messages
         _ => Ok(PrePropose::default().execute(deps, env, info, msg)?),
     }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+pub fn reply(mut deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
     match msg.id {
         COMPETITION_MODULE_REPLY_ID => {
             let res = parse_reply_instantiate_data(msg.clone())?;
@@ -228,6 +356,7 @@ pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractEr
 
             competition_modules().save(deps.storage, &module_addr, &competition_module)?;
             KEYS.save(deps.storage, key.clone(), &module_addr, env.block.height)?;
+            execute::save_module_version(deps.branch(), &env, &key, module_addr)?;
 
             // Check for module instantiation callbacks
             let callback_msgs = match res.data {
@@ -243,6 +372,16 @@ pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractEr
                 .add_attribute("competition_module".to_string(), res.contract_address)
                 .add_messages(callback_msgs))
         }
+        MODULE_UPGRADE_REPLY_ID => execute::reply_upgrade_competition_module(deps, env),
+        JAIL_BOND_REPLY_ID => {
+            // A module that cannot release the bond must not fail the proposal's completion
+            let mut response = Response::new().add_attribute("reply", "reply_release_jail_bond");
+            if let SubMsgResult::Err(error) = msg.result {
+                response = response.add_attribute("error", error);
+            }
+
+            Ok(response)
+        }
         _ => Err(ContractError::UnknownReplyId { id: msg.id }),
     }
 }
@@ -274,6 +413,14 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractErro
                 include_disabled,
             )?),
             QueryExt::Ruleset { id } => to_json_binary(&query::ruleset(deps, id)?),
+            QueryExt::RulesetVersion { id, version } => {
+                to_json_binary(&query::ruleset_version(deps, id, version)?)
+            }
+            QueryExt::RulesetHistory {
+                id,
+                start_after,
+                limit,
+            } => to_json_binary(&query::ruleset_history(deps, id, start_after, limit)?),
             QueryExt::Categories {
                 start_after,
                 limit,
@@ -289,7 +436,28 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractErro
             QueryExt::CompetitionModule { query } => {
                 to_json_binary(&query::competition_module(deps, env, query)?)
             }
+            QueryExt::ModuleVersions {
+                key,
+                start_after,
+                limit,
+            } => to_json_binary(&query::module_versions(deps, key, start_after, limit)?),
             QueryExt::DumpState {} => to_json_binary(&query::dump_state(deps, env)?),
+            QueryExt::Subcategories {
+                category_id,
+                start_after,
+                limit,
+            } => to_json_binary(&query::subcategories(
+                deps,
+                category_id,
+                start_after,
+                limit,
+            )?),
+            QueryExt::CategoryPath { category_id } => {
+                to_json_binary(&query::category_path(deps, category_id)?)
+            }
+            QueryExt::CategoryMetadata { category_id } => {
+                to_json_binary(&query::category_metadata(deps, category_id)?)
+            }
             QueryExt::IsValidCategoryAndRulesets {
                 category_id,
                 rulesets,
@@ -298,12 +466,29 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractErro
                 category_id,
                 rulesets,
             )),
-            QueryExt::TaxConfig { height } => {
-                to_json_binary(&query::arena_fee_config(deps, height)?)
-            }
-            QueryExt::Rating { category_id, addr } => {
-                to_json_binary(&query::rating(deps, category_id, addr)?)
-            }
+            QueryExt::FeeCeiling {} => to_json_binary(&FEE_CEILING.may_load(deps.storage)?),
+            QueryExt::TaxConfig {
+                height,
+                category_id,
+                module,
+            } => to_json_binary(&query::arena_fee_config(deps, height, category_id, module)?),
+            QueryExt::Rating {
+                category_id,
+                addr,
+                height,
+            } => to_json_binary(&query::rating(deps, category_id, addr, height)?),
+            QueryExt::RatingHistory {
+                category_id,
+                addr,
+                start_after,
+                limit,
+            } => to_json_binary(&query::rating_history(
+                deps,
+                category_id,
+                addr,
+                start_after,
+                limit,
+            )?),
             QueryExt::RatingLeaderboard {
                 category_id,
                 start_after,
@@ -318,9 +503,57 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractErro
                 to_json_binary(&query::is_valid_enrollment_module(deps, addr)?)
             }
             QueryExt::RatingPeriod {} => to_json_binary(&RATING_PERIOD.may_load(deps.storage)?),
+            QueryExt::RatingPeriodEnd { category_id } => {
+                to_json_binary(&RATING_PERIOD_ENDS.may_load(deps.storage, category_id.u128())?)
+            }
+            QueryExt::PendingRatingResults { category_id, addr } => {
+                to_json_binary(&query::pending_rating_results(deps, category_id, addr)?)
+            }
             QueryExt::PaymentRegistry {} => {
                 to_json_binary(&PAYMENT_REGISTRY.may_load(deps.storage)?)
             }
+            QueryExt::Season {
+                category_id,
+                season_id,
+            } => to_json_binary(&SEASONS.may_load(deps.storage, (category_id.u128(), season_id))?),
+            QueryExt::Seasons {
+                category_id,
+                start_after,
+                limit,
+            } => to_json_binary(&query::seasons(deps, category_id, start_after, limit)?),
+            QueryExt::SeasonLeaderboard {
+                category_id,
+                season_id,
+                start_after,
+                limit,
+            } => to_json_binary(&query::season_leaderboard(
+                deps,
+                category_id,
+                season_id,
+                start_after,
+                limit,
+            )?),
+            QueryExt::FlaggedPairs {
+                category_id,
+                start_after,
+                limit,
+            } => to_json_binary(&query::flagged_pairs(
+                deps,
+                category_id,
+                start_after,
+                limit,
+            )?),
+            QueryExt::HostStats { host } => to_json_binary(&query::host_stats(deps, host)?),
+            QueryExt::HostStatsList { start_after, limit } => {
+                to_json_binary(&query::host_stats_list(deps, start_after, limit)?)
+            }
+            QueryExt::HostBan { host } => to_json_binary(&query::host_ban(deps, env, host)?),
+            QueryExt::HostDenylist { start_after, limit } => {
+                to_json_binary(&query::host_denylist(deps, env, start_after, limit)?)
+            }
+            QueryExt::JailProposal { proposal_id } => {
+                to_json_binary(&JAIL_PROPOSALS.may_load(deps.storage, proposal_id)?)
+            }
         },
         _ => PrePropose::default().query(deps, env, msg),
     };
@@ -352,6 +585,20 @@ pub fn migrate(mut deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response
                 if patch.as_str() == "v1.4" {
                     migrate::from_v1_3_to_v1_4(deps.branch())?;
                 }
+
+                if patch.as_str() == "tagged_ratings" {
+                    migrate::start_ratings_migration(
+                        deps.branch(),
+                        RatingsMigration::TaggedRatings {},
+                    )?;
+                }
+
+                if patch.as_str() == "signed_ratings" {
+                    migrate::start_ratings_migration(
+                        deps.branch(),
+                        RatingsMigration::SignedRatings {},
+                    )?;
+                }
             }
         },
         MigrateMsg::FromUnderV250 { policy: _ } => {
@@ -1,7 +1,8 @@
 use std::num::ParseIntError;
 
 use cosmwasm_std::{
-    Addr, CheckedFromRatioError, DecimalRangeExceeded, OverflowError, StdError, Uint128,
+    Addr, CheckedFromRatioError, CheckedMultiplyFractionError, DecimalRangeExceeded, OverflowError,
+    StdError, Uint128,
 };
 use cw_utils::ParseReplyError;
 use dao_pre_propose_base::error::PreProposeError;
@@ -27,6 +28,9 @@ pub enum ContractError {
     #[error("{0}")]
     CheckedFromRatioError(#[from] CheckedFromRatioError),
 
+    #[error("{0}")]
+    CheckedMultiplyFractionError(#[from] CheckedMultiplyFractionError),
+
     #[error("UnknownReplyId")]
     UnknownReplyId { id: u64 },
 
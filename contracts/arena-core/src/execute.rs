@@ -1,30 +1,54 @@
-use std::collections::HashMap;
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
 
 use arena_interface::{
+    competition::{msg::ExecuteBase, state::CompetitionResponse},
     core::{
-        CompetitionCategory, EditCompetitionCategory, NewCompetitionCategory, NewRuleset,
-        PrePropose, ProposeMessage, ProposeMessages, Ruleset,
+        CategoryMetadata, CompetitionCategory, EditCompetitionCategory, EditRuleset,
+        EscrowMigration, ExecuteExt, ExecuteMsg, JailProposal, ModuleVersion,
+        NewCompetitionCategory, NewRuleset, PrePropose, ProposeMessage, ProposeMessages, Ruleset,
+    },
+    fees::TaxTier,
+    hosts::{HostActivity, HostBan},
+    ratings::{
+        FlaggedPair, IntegrityConfig, MemberResult, PendingRatingResult, RatedMatch, Rating,
+        RatingSnapshot, RatingSource, RatingState, Season, SeasonReward, SeasonStanding, SoftReset,
     },
-    ratings::MemberResult,
 };
+use arena_rating_systems::rating_calculator;
 use cosmwasm_std::{
-    ensure, ensure_eq, ensure_ne, to_json_binary, Addr, Attribute, CosmosMsg, Decimal, Deps,
-    DepsMut, Empty, Env, MessageInfo, Response, StdError, StdResult, SubMsg, Uint128, WasmMsg,
+    ensure, ensure_eq, ensure_ne, to_json_binary, Addr, Attribute, BankMsg, Binary, Coin,
+    CosmosMsg, Decimal, Deps, DepsMut, Empty, Env, MessageInfo, Order, Response, SignedDecimal,
+    StdError, StdResult, Storage, SubMsg, Uint128, WasmMsg,
 };
+use cw_balance::MemberBalanceChecked;
+use cw_storage_plus::Bound;
 use cw_utils::Duration;
 use dao_interface::state::ModuleInstantiateInfo;
 use dao_pre_propose_base::error::PreProposeError;
 use dao_voting::{
+    deposit::CheckedDepositInfo,
     pre_propose::PreProposeSubmissionPolicy,
     proposal::SingleChoiceProposeMsg,
+    status::Status,
     voting::{SingleChoiceAutoVote, Vote},
 };
 
 use crate::{
+    migrate::migrate_ratings_page,
+    query,
     state::{
-        competition_categories, competition_modules, ratings, rulesets,
-        COMPETITION_CATEGORIES_COUNT, ENROLLMENT_MODULES, PAYMENT_REGISTRY, RATING_PERIOD,
-        RULESETS_COUNT, TAX,
+        category_lineage, competition_categories, competition_modules,
+        oldest_pending_rating_period, ratings, rulesets, ModuleUpgrade, CATEGORY_CHILDREN,
+        CATEGORY_TAXES, COMPETITION_CATEGORIES_COUNT, COMPETITION_PENDING_RESULTS,
+        ENROLLMENT_MODULES, ESCROW_MIGRATIONS, FEE_CEILING, FLAGGED_PAIRS, HOST_DENYLIST,
+        HOST_STATS, JAIL_PROPOSALS, KEYS, MODULE_TAXES, MODULE_VERSIONS, PAIRINGS,
+        PAYMENT_REGISTRY, PENDING_RATING_RESULTS, PERIOD_START_RATINGS, RATINGS_MIGRATION,
+        RATING_ADJUSTMENTS, RATING_HISTORY, RATING_PERIOD, RATING_PERIOD_ENDS, RATING_PERIOD_IDS,
+        RATING_REVERTS, RULESETS_COUNT, RULESET_VERSIONS, SEASONS, SEASON_STANDINGS, SEASON_STARTS,
+        TAX, TAX_TIERS, TEMP_MODULE_UPGRADE,
     },
     ContractError,
 };
@@ -33,6 +57,12 @@ pub const COMPETITION_MODULE_REPLY_ID: u64 = 1;
 pub const DAO_REPLY_ID: u64 = 2;
 pub const ESCROW_REPLY_ID: u64 = 3;
 pub const COMPETITION_REPLY_ID: u64 = 5;
+pub const MODULE_UPGRADE_REPLY_ID: u64 = 6;
+pub const JAIL_BOND_REPLY_ID: u64 = 7;
+/// Categories can be nested as game, mode, and region
+pub const MAX_CATEGORY_DEPTH: usize = 3;
+/// The number of ranks archived for a season, unless its reward distribution pays more
+pub const MIN_SEASON_STANDINGS: usize = 30;
 
 pub fn update_competition_modules(
     deps: DepsMut,
@@ -75,6 +105,217 @@ pub fn update_competition_modules(
         .add_submessages(competition_module_msgs))
 }
 
+/// Saves the code id and contract version of the competition module registered under the key
+pub fn save_module_version(
+    deps: DepsMut,
+    env: &Env,
+    key: &str,
+    addr: Addr,
+) -> StdResult<ModuleVersion> {
+    let code_id = deps.querier.query_wasm_contract_info(&addr)?.code_id;
+    let contract_version = cw2::query_contract_info(&deps.querier, &addr)?;
+
+    let module_version = ModuleVersion {
+        addr,
+        code_id,
+        contract: contract_version.contract,
+        version: contract_version.version,
+        height: env.block.height,
+    };
+    MODULE_VERSIONS.save(deps.storage, (key, env.block.height), &module_version)?;
+
+    Ok(module_version)
+}
+
+pub fn upgrade_competition_module(
+    deps: DepsMut,
+    env: &Env,
+    key: String,
+    code_id: u64,
+    migrate_msg: Binary,
+    escrow_migration: Option<EscrowMigration>,
+) -> Result<Response, ContractError> {
+    let addr = KEYS
+        .may_load(deps.storage, key.clone())?
+        .ok_or(ContractError::StdError(StdError::generic_err(format!(
+            "Could not find a competition module for the key {}",
+            key
+        ))))?;
+
+    // Modules added by a proposal are administered by the DAO, which must first hand the admin to the arena core
+    let contract_info = deps.querier.query_wasm_contract_info(&addr)?;
+    ensure!(
+        contract_info.admin.as_deref() == Some(env.contract.address.as_str()),
+        ContractError::StdError(StdError::generic_err(
+            "The arena core must be the competition module's admin"
+        ))
+    );
+    ensure!(
+        contract_info.code_id != code_id,
+        ContractError::StdError(StdError::generic_err(
+            "The competition module already uses this code id"
+        ))
+    );
+    ensure!(
+        !ESCROW_MIGRATIONS.has(deps.storage, &key),
+        ContractError::StdError(StdError::generic_err(
+            "The competition module's escrow migration must be completed first"
+        ))
+    );
+
+    TEMP_MODULE_UPGRADE.save(
+        deps.storage,
+        &ModuleUpgrade {
+            key: key.clone(),
+            escrow_migration,
+        },
+    )?;
+
+    let msg = WasmMsg::Migrate {
+        contract_addr: addr.to_string(),
+        new_code_id: code_id,
+        msg: migrate_msg,
+    };
+
+    Ok(Response::new()
+        .add_attribute("action", "upgrade_competition_module")
+        .add_attribute("key", key)
+        .add_attribute("code_id", code_id.to_string())
+        .add_submessage(SubMsg::reply_on_success(msg, MODULE_UPGRADE_REPLY_ID)))
+}
+
+pub fn reply_upgrade_competition_module(
+    mut deps: DepsMut,
+    env: Env,
+) -> Result<Response, ContractError> {
+    let upgrade = TEMP_MODULE_UPGRADE.load(deps.storage)?;
+    TEMP_MODULE_UPGRADE.remove(deps.storage);
+
+    let addr = KEYS.load(deps.storage, upgrade.key.clone())?;
+    let previous_version = MODULE_VERSIONS
+        .prefix(&upgrade.key)
+        .range(deps.storage, None, None, Order::Descending)
+        .next()
+        .transpose()?
+        .map(|(_, module_version)| module_version);
+    let module_version = save_module_version(deps.branch(), &env, &upgrade.key, addr.clone())?;
+
+    // Modules registered before versions were recorded have nothing to compare against
+    if let Some(previous_version) = previous_version {
+        ensure!(
+            previous_version.contract == module_version.contract,
+            ContractError::StdError(StdError::generic_err(format!(
+                "Cannot upgrade {} to a different contract {}",
+                previous_version.contract, module_version.contract
+            )))
+        );
+
+        let parse_version = |version: &str| {
+            semver::Version::parse(version).map_err(|e| StdError::generic_err(e.to_string()))
+        };
+        ensure!(
+            parse_version(&module_version.version)? >= parse_version(&previous_version.version)?,
+            ContractError::StdError(StdError::generic_err(format!(
+                "Cannot downgrade the competition module from {} to {}",
+                previous_version.version, module_version.version
+            )))
+        );
+    }
+
+    // Escrows are migrated a page at a time, and the rest through ContinueEscrowMigration
+    let (msgs, attrs) = match upgrade.escrow_migration {
+        Some(escrow_migration) => {
+            ESCROW_MIGRATIONS.save(deps.storage, &upgrade.key, &(escrow_migration, None))?;
+            migrate_escrows_page(deps, &upgrade.key, &addr, None)?
+        }
+        None => (vec![], vec![]),
+    };
+
+    Ok(Response::new()
+        .add_attribute("action", "reply_upgrade_competition_module")
+        .add_attribute("key", upgrade.key)
+        .add_attribute("code_id", module_version.code_id.to_string())
+        .add_attribute("version", module_version.version)
+        .add_attributes(attrs)
+        .add_messages(msgs))
+}
+
+pub fn continue_escrow_migration(
+    deps: DepsMut,
+    key: String,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let addr = KEYS
+        .may_load(deps.storage, key.clone())?
+        .ok_or(ContractError::StdError(StdError::generic_err(format!(
+            "Could not find a competition module for the key {}",
+            key
+        ))))?;
+    let (msgs, attrs) = migrate_escrows_page(deps, &key, &addr, limit)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "continue_escrow_migration")
+        .add_attribute("key", key)
+        .add_attributes(attrs)
+        .add_messages(msgs))
+}
+
+/// Has the competition module migrate the escrows of the page of competitions after the key's migration cursor
+fn migrate_escrows_page(
+    deps: DepsMut,
+    key: &str,
+    addr: &Addr,
+    limit: Option<u32>,
+) -> Result<(Vec<WasmMsg>, Vec<Attribute>), ContractError> {
+    let (escrow_migration, start_after) = ESCROW_MIGRATIONS
+        .may_load(deps.storage, key)?
+        .ok_or_else(|| {
+            ContractError::StdError(StdError::generic_err(
+                "There is no escrow migration in progress for this key",
+            ))
+        })?;
+    let limit = limit.unwrap_or(30).min(30);
+
+    // Competition ids are sequential, so the page ends a limit past the cursor
+    let competition_count: Uint128 = deps.querier.query_wasm_smart(
+        addr,
+        &arena_interface::competition::msg::QueryBase::<Empty, Empty, Empty>::CompetitionCount {},
+    )?;
+    let last = start_after
+        .unwrap_or_default()
+        .checked_add(Uint128::from(limit))?;
+    let is_complete = last >= competition_count;
+
+    let mut msgs = vec![];
+    if start_after.unwrap_or_default() < competition_count {
+        msgs.push(WasmMsg::Execute {
+            contract_addr: addr.to_string(),
+            msg: to_json_binary(&ExecuteBase::<Empty, Empty>::MigrateEscrows {
+                start_after,
+                limit: Some(limit),
+                filter: None,
+                escrow_code_id: escrow_migration.escrow_code_id,
+                escrow_migrate_msg: escrow_migration.escrow_migrate_msg.clone(),
+            })?,
+            funds: vec![],
+        });
+    }
+
+    if is_complete {
+        ESCROW_MIGRATIONS.remove(deps.storage, key);
+    } else {
+        ESCROW_MIGRATIONS.save(deps.storage, key, &(escrow_migration, Some(last)))?;
+    }
+
+    Ok((
+        msgs,
+        vec![Attribute {
+            key: "is_escrow_migration_complete".to_string(),
+            value: is_complete.to_string(),
+        }],
+    ))
+}
+
 pub fn update_tax(deps: DepsMut, env: &Env, tax: Decimal) -> Result<Response, ContractError> {
     if tax >= Decimal::one() {
         return Err(ContractError::StdError(StdError::GenericErr {
@@ -89,11 +330,136 @@ pub fn update_tax(deps: DepsMut, env: &Env, tax: Decimal) -> Result<Response, Co
         .add_attribute("tax", tax.to_string()))
 }
 
+pub fn update_tax_schedule(
+    deps: DepsMut,
+    env: &Env,
+    category_taxes: Option<Vec<(Uint128, Option<Decimal>)>>,
+    module_taxes: Option<Vec<(String, Option<Decimal>)>>,
+    volume_tiers: Option<Vec<TaxTier>>,
+) -> Result<Response, ContractError> {
+    let ensure_valid_tax = |tax: &Decimal| {
+        ensure!(
+            *tax < Decimal::one(),
+            ContractError::StdError(StdError::generic_err("The dao tax must be less than 100%."))
+        );
+
+        Ok::<_, ContractError>(())
+    };
+
+    for (category_id, tax) in category_taxes.into_iter().flatten() {
+        ensure!(
+            competition_categories().has(deps.storage, category_id.u128()),
+            ContractError::CompetitionCategoryDoesNotExist { id: category_id }
+        );
+
+        match tax {
+            Some(tax) => {
+                ensure_valid_tax(&tax)?;
+                CATEGORY_TAXES.save(deps.storage, category_id.u128(), &tax, env.block.height)?;
+            }
+            None => CATEGORY_TAXES.remove(deps.storage, category_id.u128(), env.block.height)?,
+        }
+    }
+
+    for (key, tax) in module_taxes.into_iter().flatten() {
+        ensure!(
+            KEYS.has(deps.storage, key.clone()),
+            ContractError::StdError(StdError::generic_err(format!(
+                "Could not find a competition module with the key {}",
+                key
+            )))
+        );
+
+        match tax {
+            Some(tax) => {
+                ensure_valid_tax(&tax)?;
+                MODULE_TAXES.save(deps.storage, key, &tax, env.block.height)?;
+            }
+            None => MODULE_TAXES.remove(deps.storage, key, env.block.height)?,
+        }
+    }
+
+    if let Some(volume_tiers) = volume_tiers {
+        for tier in &volume_tiers {
+            tier.validate()?;
+        }
+
+        TAX_TIERS.save(deps.storage, &volume_tiers, env.block.height)?;
+    }
+
+    Ok(Response::new().add_attribute("action", "update_tax_schedule"))
+}
+
+pub fn update_fee_ceiling(
+    deps: DepsMut,
+    fee_ceiling: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    match fee_ceiling {
+        Some(fee_ceiling) => {
+            ensure!(
+                fee_ceiling <= Decimal::one(),
+                ContractError::StdError(StdError::generic_err(
+                    "The fee ceiling cannot be more than 100%"
+                ))
+            );
+
+            FEE_CEILING.save(deps.storage, &fee_ceiling)?;
+        }
+        None => FEE_CEILING.remove(deps.storage),
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "update_fee_ceiling")
+        .add_attributes(fee_ceiling.map(|x| ("fee_ceiling", x.to_string()))))
+}
+
 pub fn update_rulesets(
     deps: DepsMut,
     to_add: Option<Vec<NewRuleset>>,
     to_disable: Option<Vec<Uint128>>,
+    to_edit: Option<Vec<EditRuleset>>,
 ) -> Result<Response, ContractError> {
+    // Edit specified rulesets, keeping their previous versions
+    let mut attrs = vec![];
+    if let Some(to_edit) = to_edit {
+        for edit in to_edit {
+            let previous =
+                rulesets()
+                    .may_load(deps.storage, edit.id.u128())?
+                    .ok_or(StdError::GenericErr {
+                        msg: format!("Could not find a ruleset with the id {}", edit.id),
+                    })?;
+            ensure!(
+                previous.is_enabled,
+                ContractError::StdError(StdError::generic_err("Cannot edit a disabled ruleset"))
+            );
+
+            let version = previous.current_version();
+            if !RULESET_VERSIONS.has(deps.storage, (edit.id.u128(), version)) {
+                RULESET_VERSIONS.save(deps.storage, (edit.id.u128(), version), &previous)?;
+            }
+
+            let ruleset = Ruleset {
+                rules: edit.rules,
+                description: edit.description,
+                version: Some(version + 1),
+                ..previous.clone()
+            };
+            rulesets().replace(
+                deps.storage,
+                edit.id.u128(),
+                Some(&ruleset),
+                Some(&previous),
+            )?;
+            RULESET_VERSIONS.save(deps.storage, (edit.id.u128(), version + 1), &ruleset)?;
+
+            attrs.push(Attribute::new(
+                ruleset.id,
+                format!("Version {} - {}", version + 1, ruleset.description),
+            ));
+        }
+    }
+
     // Disable specified rulesets
     if let Some(to_disable) = to_disable {
         for id in to_disable {
@@ -108,7 +474,6 @@ pub fn update_rulesets(
     }
 
     // Add new rulesets
-    let mut attrs = vec![];
     if let Some(to_add) = to_add {
         let mut current_id = RULESETS_COUNT.load(deps.storage)?;
         for ruleset in to_add {
@@ -126,8 +491,10 @@ pub fn update_rulesets(
                 rules: ruleset.rules,
                 description: ruleset.description,
                 is_enabled: true,
+                version: Some(1),
             };
             rulesets().save(deps.storage, current_id.u128(), &new_ruleset)?;
+            RULESET_VERSIONS.save(deps.storage, (current_id.u128(), 1), &new_ruleset)?;
 
             attrs.push(Attribute::new(
                 new_ruleset.id,
@@ -194,7 +561,7 @@ pub fn ensure_active_competition_module(deps: Deps, who: &Addr) -> Result<(), Pr
 }
 
 pub fn propose(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     msg: ProposeMessage,
@@ -203,8 +570,213 @@ pub fn propose(
     check_can_submit(deps.as_ref(), &info.sender, &config)?;
     let originator = deps.api.addr_validate(&msg.originator)?;
 
-    let deposit_messages = if let Some(ref deposit_info) = config.deposit_info {
-        deposit_info.check_native_deposit_paid(&info)?;
+    // Validate distribution
+    if let Some(distribution) = &msg.distribution {
+        distribution.into_checked(deps.as_ref())?;
+    }
+
+    let msgs =
+        vec![CosmosMsg::Wasm(
+            WasmMsg::Execute {
+                contract_addr: info.sender.to_string(),
+                msg: to_json_binary(&arena_interface::competition::msg::ExecuteBase::<
+                    Empty,
+                    Empty,
+                >::ProcessCompetition {
+                    competition_id: msg.competition_id,
+                    distribution: msg.distribution.clone(),
+                })?,
+                funds: vec![],
+            },
+        )];
+
+    let (response, proposal_id) = submit_proposal(
+        deps.branch(),
+        &env,
+        &info,
+        config.deposit_info,
+        originator.clone(),
+        msg.title,
+        msg.description,
+        msgs,
+    )?;
+
+    // Keep the competition's state for the DAO to review
+    JAIL_PROPOSALS.save(
+        deps.storage,
+        proposal_id,
+        &JailProposal {
+            proposal_id,
+            module: info.sender,
+            competition_id: msg.competition_id,
+            originator,
+            distribution: msg.distribution,
+            snapshot: msg.snapshot,
+            jail_bond: msg.jail_bond,
+        },
+    )?;
+
+    Ok(response.add_attribute("proposal_id", proposal_id.to_string()))
+}
+
+/// Settles the bond of a jail proposal that completed without being executed
+/// A rejected or closed proposal means the DAO ruled against the jail, while a failed execution means it never ruled
+pub fn release_jail_bond(
+    deps: Deps,
+    proposal_id: u64,
+    new_status: &Status,
+) -> StdResult<Vec<SubMsg>> {
+    if *new_status == Status::Executed {
+        return Ok(vec![]);
+    }
+
+    let jail_bond = JAIL_PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .and_then(|x| x.jail_bond.map(|amount| (x, amount)));
+    match jail_bond {
+        Some((jail_proposal, amount)) => Ok(vec![SubMsg::reply_on_error(
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: jail_proposal.module.to_string(),
+                msg: to_json_binary(&ExecuteBase::<Empty, Empty>::ReleaseJailBond {
+                    competition_id: jail_proposal.competition_id,
+                    originator: jail_proposal.originator.to_string(),
+                    amount,
+                    is_rejected: matches!(new_status, Status::Rejected | Status::Closed),
+                })?,
+                funds: vec![],
+            }),
+            JAIL_BOND_REPLY_ID,
+        )]),
+        None => Ok(vec![]),
+    }
+}
+
+pub fn propose_ruleset(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    title: String,
+    description: String,
+    ruleset: NewRuleset,
+) -> Result<Response, ContractError> {
+    let category = competition_categories()
+        .may_load(deps.storage, ruleset.category_id.u128())?
+        .ok_or(ContractError::CompetitionCategoryDoesNotExist {
+            id: ruleset.category_id,
+        })?;
+    ensure!(
+        category.is_enabled,
+        ContractError::StdError(StdError::generic_err(
+            "Cannot propose a ruleset for a disabled category"
+        ))
+    );
+
+    let update = ExecuteExt::UpdateRulesets {
+        to_add: Some(vec![ruleset]),
+        to_disable: None,
+        to_edit: None,
+    };
+
+    Ok(propose_update(deps, env, info, title, description, update)?
+        .add_attribute("action", "propose_ruleset"))
+}
+
+pub fn propose_category(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    title: String,
+    description: String,
+    category: NewCompetitionCategory,
+) -> Result<Response, ContractError> {
+    if let Some(rating_system) = &category.rating_system {
+        rating_system.validate()?;
+    }
+    if let Some(rating_config) = &category.rating_config {
+        rating_config.validate()?;
+    }
+    if let Some(metadata) = &category.metadata {
+        metadata.validate()?;
+    }
+    if let Some(parent_id) = category.parent_id {
+        ensure!(
+            category_lineage(deps.storage, parent_id.u128())?.len() < MAX_CATEGORY_DEPTH,
+            ContractError::StdError(StdError::generic_err(format!(
+                "Categories cannot be nested more than {} levels deep",
+                MAX_CATEGORY_DEPTH
+            )))
+        );
+    }
+
+    let update = ExecuteExt::UpdateCategories {
+        to_add: Some(vec![category]),
+        to_edit: None,
+    };
+
+    Ok(propose_update(deps, env, info, title, description, update)?
+        .add_attribute("action", "propose_category"))
+}
+
+/// Submits a DAO proposal to apply an update to the arena core on behalf of the sender
+/// Community proposals are only open while a proposal deposit is configured, which is returned under the deposit's refund policy
+fn propose_update(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    title: String,
+    description: String,
+    update: ExecuteExt,
+) -> Result<Response, ContractError> {
+    let config = PrePropose::default().config.load(deps.storage)?;
+    let denylist = match &config.submission_policy {
+        PreProposeSubmissionPolicy::Anyone { denylist } => denylist,
+        PreProposeSubmissionPolicy::Specific { denylist, .. } => denylist,
+    };
+    ensure!(
+        !denylist.contains(&info.sender),
+        ContractError::Unauthorized {}
+    );
+    ensure!(
+        config.deposit_info.is_some(),
+        ContractError::StdError(StdError::generic_err(
+            "Community proposals require a proposal deposit"
+        ))
+    );
+
+    let msgs = vec![CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_json_binary(&ExecuteMsg::Extension { msg: update })?,
+        funds: vec![],
+    })];
+
+    let (response, _) = submit_proposal(
+        deps,
+        &env,
+        &info,
+        config.deposit_info,
+        info.sender.clone(),
+        title,
+        description,
+        msgs,
+    )?;
+
+    Ok(response)
+}
+
+/// Takes the originator's deposit and submits the proposal to the DAO's proposal module, returning the proposal's id
+#[allow(clippy::too_many_arguments)]
+fn submit_proposal(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    deposit_info: Option<CheckedDepositInfo>,
+    originator: Addr,
+    title: String,
+    description: String,
+    msgs: Vec<CosmosMsg>,
+) -> Result<(Response, u64), PreProposeError> {
+    let deposit_messages = if let Some(ref deposit_info) = deposit_info {
+        deposit_info.check_native_deposit_paid(info)?;
         deposit_info.get_take_deposit_messages(&originator, &env.contract.address)?
     } else {
         vec![]
@@ -214,21 +786,16 @@ pub fn propose(
 
     // Snapshot the deposit using the ID of the proposal that we
     // will create.
-    let next_id = deps.querier.query_wasm_smart(
+    let next_id: u64 = deps.querier.query_wasm_smart(
         &proposal_module,
         &dao_interface::proposal::Query::NextProposalId {},
     )?;
     PrePropose::default().deposits.save(
         deps.storage,
         next_id,
-        &(config.deposit_info, originator.clone()),
+        &(deposit_info, originator.clone()),
     )?;
 
-    // Validate distribution
-    if let Some(distribution) = &msg.distribution {
-        distribution.into_checked(deps.as_ref())?;
-    }
-
     // Check voting power to auto vote
     let dao = PrePropose::default().dao.load(deps.storage)?;
     let voting_power: dao_interface::voting::VotingPowerAtHeightResponse =
@@ -241,31 +808,20 @@ pub fn propose(
         )?;
 
     // Construct message
-    let msg =
-        ProposeMessages::Propose(SingleChoiceProposeMsg {
-            title: msg.title,
-            description: msg.description,
-            vote: if voting_power.power.is_zero() {
-                None
-            } else {
-                Some(SingleChoiceAutoVote {
-                    vote: Vote::Yes,
-                    rationale: None,
-                })
-            },
-            msgs: vec![CosmosMsg::Wasm(WasmMsg::Execute {
-                contract_addr: info.sender.to_string(),
-                msg: to_json_binary(&arena_interface::competition::msg::ExecuteBase::<
-                    Empty,
-                    Empty,
-                >::ProcessCompetition {
-                    competition_id: msg.competition_id,
-                    distribution: msg.distribution,
-                })?,
-                funds: vec![],
-            })],
-            proposer: Some(originator.to_string()),
-        });
+    let msg = ProposeMessages::Propose(SingleChoiceProposeMsg {
+        title,
+        description,
+        vote: if voting_power.power.is_zero() {
+            None
+        } else {
+            Some(SingleChoiceAutoVote {
+                vote: Vote::Yes,
+                rationale: None,
+            })
+        },
+        msgs,
+        proposer: Some(originator.to_string()),
+    });
 
     let propose_message = WasmMsg::Execute {
         contract_addr: proposal_module.into_string(),
@@ -284,7 +840,7 @@ pub fn propose(
             Ok(SubMsg::new(execute))
         })?;
 
-    Ok(Response::default()
+    let response = Response::default()
         .add_attribute("method", "execute_propose")
         .add_attribute("originator", originator)
         // It's important that the propose message is
@@ -293,7 +849,9 @@ pub fn propose(
         // {}` query.
         .add_message(propose_message)
         .add_submessages(hooks_msgs)
-        .add_messages(deposit_messages))
+        .add_messages(deposit_messages);
+
+    Ok((response, next_id))
 }
 
 pub fn update_categories(
@@ -310,6 +868,30 @@ pub fn update_categories(
                     category_id,
                     name: _,
                 } => category_id,
+                EditCompetitionCategory::SetRatingSystem {
+                    category_id,
+                    ref rating_system,
+                } => {
+                    rating_system.validate()?;
+
+                    category_id
+                }
+                EditCompetitionCategory::SetRatingConfig {
+                    category_id,
+                    ref rating_config,
+                } => {
+                    rating_config.validate()?;
+
+                    category_id
+                }
+                EditCompetitionCategory::SetMetadata {
+                    category_id,
+                    ref metadata,
+                } => {
+                    validate_category_metadata(deps.as_ref(), category_id, metadata)?;
+
+                    category_id
+                }
             };
             competition_categories().update(
                 deps.storage,
@@ -328,6 +910,24 @@ pub fn update_categories(
                         } => {
                             category.name = name;
                         }
+                        EditCompetitionCategory::SetRatingSystem {
+                            category_id: _,
+                            rating_system,
+                        } => {
+                            category.rating_system = Some(rating_system);
+                        }
+                        EditCompetitionCategory::SetRatingConfig {
+                            category_id: _,
+                            rating_config,
+                        } => {
+                            category.rating_config = Some(rating_config);
+                        }
+                        EditCompetitionCategory::SetMetadata {
+                            category_id: _,
+                            metadata,
+                        } => {
+                            category.metadata = Some(metadata);
+                        }
                     };
 
                     Ok(category)
@@ -343,12 +943,41 @@ pub fn update_categories(
         for category in to_add {
             current_id = current_id.checked_add(Uint128::one())?;
 
+            if let Some(rating_system) = &category.rating_system {
+                rating_system.validate()?;
+            }
+            if let Some(rating_config) = &category.rating_config {
+                rating_config.validate()?;
+            }
+            if let Some(parent_id) = category.parent_id {
+                ensure!(
+                    category_lineage(deps.storage, parent_id.u128())?.len() < MAX_CATEGORY_DEPTH,
+                    ContractError::StdError(StdError::generic_err(format!(
+                        "Categories cannot be nested more than {} levels deep",
+                        MAX_CATEGORY_DEPTH
+                    )))
+                );
+
+                CATEGORY_CHILDREN.save(
+                    deps.storage,
+                    (parent_id.u128(), current_id.u128()),
+                    &Empty {},
+                )?;
+            }
+
             let new_category = CompetitionCategory {
                 id: current_id,
                 name: category.name,
                 is_enabled: true,
+                rating_system: category.rating_system,
+                rating_config: category.rating_config,
+                parent_id: category.parent_id,
+                metadata: category.metadata,
             };
             competition_categories().save(deps.storage, current_id.u128(), &new_category)?;
+            if let Some(metadata) = &new_category.metadata {
+                validate_category_metadata(deps.as_ref(), current_id, metadata)?;
+            }
 
             attrs.push(Attribute::new(new_category.id, new_category.name));
         }
@@ -362,19 +991,60 @@ pub fn update_categories(
         .add_attributes(attrs))
 }
 
+/// Default rulesets must be valid for the category, which is saved before its metadata is validated
+fn validate_category_metadata(
+    deps: Deps,
+    category_id: Uint128,
+    metadata: &CategoryMetadata,
+) -> Result<(), ContractError> {
+    metadata.validate()?;
+
+    if let Some(default_rulesets) = &metadata.default_rulesets {
+        ensure!(
+            query::is_valid_category_and_rulesets(deps, category_id, default_rulesets.clone()),
+            ContractError::StdError(StdError::generic_err(
+                "The default rulesets must belong to the category or its parent categories"
+            ))
+        );
+    }
+
+    Ok(())
+}
+
 pub fn adjust_ratings(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     category_id: Uint128,
     member_results: Vec<(MemberResult<String>, MemberResult<String>)>,
+    competition_id: Option<Uint128>,
+    match_numbers: Option<Vec<Uint128>>,
 ) -> Result<Response, ContractError> {
     // Validate authorization - this message should only be executed by the competition modules
     ensure_active_competition_module(deps.as_ref(), &info.sender)?;
 
-    // Create a map to store the new ratings for attribute creation
-    let mut new_ratings: HashMap<String, Decimal> = HashMap::new();
-    for (member_result_1, member_result_2) in member_results {
+    if let Some(match_numbers) = &match_numbers {
+        ensure_eq!(
+            match_numbers.len(),
+            member_results.len(),
+            ContractError::StdError(StdError::generic_err(
+                "Each member result must have a match number"
+            ))
+        );
+    }
+
+    let integrity = category_integrity(deps.storage, category_id)?;
+    if let Some(min_stake) = integrity.as_ref().and_then(|x| x.min_stake.as_ref()) {
+        ensure_min_stake(deps.as_ref(), &info.sender, competition_id, min_stake)?;
+    }
+
+    // Results belong to the next period once the current one has ended
+    let category_ids = rated_category_ids(deps.storage, category_id)?;
+    for id in &category_ids {
+        end_rating_period(deps.storage, &env, *id)?;
+    }
+
+    for (i, (member_result_1, member_result_2)) in member_results.iter().enumerate() {
         // Ensure different addresses
         ensure_ne!(
             member_result_1.addr,
@@ -401,55 +1071,1010 @@ pub fn adjust_ratings(
 
         let addr_1 = deps.api.addr_validate(&member_result_1.addr)?;
         let addr_2 = deps.api.addr_validate(&member_result_2.addr)?;
+        let source = competition_id.map(|competition_id| RatingSource {
+            module: info.sender.clone(),
+            competition_id,
+            match_number: match_numbers.as_ref().map(|x| x[i]),
+        });
+        let weight = integrity
+            .as_ref()
+            .map(|integrity| {
+                record_pairing(
+                    deps.storage,
+                    &env,
+                    category_id.u128(),
+                    integrity,
+                    &addr_1,
+                    &addr_2,
+                )
+            })
+            .transpose()?;
 
-        let key_1 = (category_id.u128(), &addr_1);
-        let key_2 = (category_id.u128(), &addr_2);
-
-        let maybe_rating_1 = ratings().may_load(deps.storage, key_1)?;
-        let maybe_rating_2 = ratings().may_load(deps.storage, key_2)?;
-
-        let mut rating_1 = maybe_rating_1.clone().unwrap_or_default();
-        let mut rating_2 = maybe_rating_2.clone().unwrap_or_default();
-
-        // Calculate changes
-        glicko_2::update_rating(
-            &env,
-            &mut rating_1,
-            &mut rating_2,
+        buffer_rating_result(
+            deps.storage,
+            &category_ids,
+            &addr_1,
+            vec![addr_2.clone()],
             member_result_1.result,
+            source.clone(),
+            weight,
+        )?;
+        buffer_rating_result(
+            deps.storage,
+            &category_ids,
+            &addr_2,
+            vec![addr_1],
             member_result_2.result,
-            &RATING_PERIOD.load(deps.storage)?,
-        );
+            source,
+            weight,
+        )?;
+    }
 
-        // Update values
-        ratings().replace(
+    let buffered_results = member_results.len() * 2;
+    if buffered_results > 0 {
+        for id in &category_ids {
+            open_rating_period(deps.storage, &env, *id)?;
+        }
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "adjust_ratings")
+        .add_attribute("module", info.sender)
+        .add_attributes(competition_id.map(|x| ("competition_id", x)))
+        .add_attribute("buffered_results", buffered_results.to_string()))
+}
+
+pub fn adjust_team_ratings(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    category_id: Uint128,
+    matches: Vec<RatedMatch<String>>,
+    competition_id: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    // Validate authorization - this message should only be executed by the competition modules
+    ensure_active_competition_module(deps.as_ref(), &info.sender)?;
+
+    let integrity = category_integrity(deps.storage, category_id)?;
+    if let Some(min_stake) = integrity.as_ref().and_then(|x| x.min_stake.as_ref()) {
+        ensure_min_stake(deps.as_ref(), &info.sender, competition_id, min_stake)?;
+    }
+
+    // Results belong to the next period once the current one has ended
+    let category_ids = rated_category_ids(deps.storage, category_id)?;
+    for id in &category_ids {
+        end_rating_period(deps.storage, &env, *id)?;
+    }
+
+    let mut buffered_results = 0usize;
+    for rated_match in matches {
+        ensure!(
+            rated_match.teams.len() >= 2,
+            ContractError::StdError(StdError::generic_err(
+                "A rated match must have at least 2 teams"
+            ))
+        );
+
+        let mut seen = HashSet::new();
+        let mut teams = vec![];
+        for team in &rated_match.teams {
+            ensure!(
+                !team.members.is_empty(),
+                ContractError::StdError(StdError::generic_err("A team cannot be empty"))
+            );
+
+            let members = team
+                .members
+                .iter()
+                .map(|x| deps.api.addr_validate(x))
+                .collect::<StdResult<Vec<_>>>()?;
+            for member in &members {
+                ensure!(
+                    seen.insert(member.clone()),
+                    ContractError::StdError(StdError::generic_err(format!(
+                        "{} cannot appear more than once in a match",
+                        member
+                    )))
+                );
+            }
+
+            teams.push((members, team.placement));
+        }
+
+        let source = competition_id.map(|competition_id| RatingSource {
+            module: info.sender.clone(),
+            competition_id,
+            match_number: rated_match.match_number,
+        });
+
+        // Every pair of opponents is recorded once per match
+        let mut pair_weights: HashMap<(Addr, Addr), Decimal> = HashMap::new();
+        if let Some(integrity) = &integrity {
+            for (i, (members, _)) in teams.iter().enumerate() {
+                for (opponents, _) in &teams[i + 1..] {
+                    for member in members {
+                        for opponent in opponents {
+                            let weight = record_pairing(
+                                deps.storage,
+                                &env,
+                                category_id.u128(),
+                                integrity,
+                                member,
+                                opponent,
+                            )?;
+                            pair_weights.insert((member.clone(), opponent.clone()), weight);
+                            pair_weights.insert((opponent.clone(), member.clone()), weight);
+                        }
+                    }
+                }
+            }
+        }
+
+        // Every member plays each opposing team, scoring by placement
+        for (i, (members, placement)) in teams.iter().enumerate() {
+            for (j, (opponents, opponent_placement)) in teams.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+
+                let score = match placement.cmp(opponent_placement) {
+                    Ordering::Less => Decimal::one(),
+                    Ordering::Equal => Decimal::percent(50),
+                    Ordering::Greater => Decimal::zero(),
+                };
+
+                for member in members {
+                    // A result against a team keeps the average weight of its pairings
+                    let weight = if integrity.is_some() {
+                        let total = opponents.iter().try_fold(Decimal::zero(), |acc, x| {
+                            acc.checked_add(pair_weights[&(member.clone(), x.clone())])
+                        })?;
+
+                        Some(
+                            total
+                                .checked_mul(Decimal::from_ratio(1u128, opponents.len() as u128))?,
+                        )
+                    } else {
+                        None
+                    };
+
+                    buffer_rating_result(
+                        deps.storage,
+                        &category_ids,
+                        member,
+                        opponents.clone(),
+                        score,
+                        source.clone(),
+                        weight,
+                    )?;
+                    buffered_results += 1;
+                }
+            }
+        }
+    }
+
+    if buffered_results > 0 {
+        for id in &category_ids {
+            open_rating_period(deps.storage, &env, *id)?;
+        }
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "adjust_team_ratings")
+        .add_attribute("module", info.sender)
+        .add_attributes(competition_id.map(|x| ("competition_id", x)))
+        .add_attribute("buffered_results", buffered_results.to_string()))
+}
+
+/// Buffers a result in each category until its rating period closes
+fn buffer_rating_result(
+    storage: &mut dyn Storage,
+    category_ids: &[u128],
+    addr: &Addr,
+    opponents: Vec<Addr>,
+    score: Decimal,
+    source: Option<RatingSource>,
+    weight: Option<Decimal>,
+) -> StdResult<()> {
+    for category_id in category_ids {
+        let period_id = RATING_PERIOD_IDS
+            .may_load(storage, *category_id)?
+            .unwrap_or_default();
+        PENDING_RATING_RESULTS.update(
+            storage,
+            (*category_id, period_id, addr),
+            |x| -> StdResult<_> {
+                let mut results = x.unwrap_or_default();
+                results.push(PendingRatingResult {
+                    opponents: opponents.clone(),
+                    score,
+                    source: source.clone(),
+                    weight,
+                });
+                Ok(results)
+            },
+        )?;
+        if let Some(source) = &source {
+            COMPETITION_PENDING_RESULTS.save(
+                storage,
+                (
+                    (&source.module, source.competition_id.u128()),
+                    (*category_id, period_id, addr),
+                ),
+                &Empty {},
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Results in a subcategory are also rated in its parent categories
+fn rated_category_ids(storage: &dyn Storage, category_id: Uint128) -> StdResult<Vec<u128>> {
+    Ok(category_lineage(storage, category_id.u128())?
+        .iter()
+        .map(|x| x.id.u128())
+        .collect())
+}
+
+fn category_integrity(
+    storage: &dyn Storage,
+    category_id: Uint128,
+) -> Result<Option<IntegrityConfig>, ContractError> {
+    let category = competition_categories()
+        .may_load(storage, category_id.u128())?
+        .ok_or(ContractError::CompetitionCategoryDoesNotExist { id: category_id })?;
+
+    Ok(category.rating_config.and_then(|x| x.integrity))
+}
+
+/// Ensures the competition behind rated results has an escrow holding the minimum stake
+fn ensure_min_stake(
+    deps: Deps,
+    module: &Addr,
+    competition_id: Option<Uint128>,
+    min_stake: &Coin,
+) -> Result<(), ContractError> {
+    let error = || {
+        ContractError::StdError(StdError::generic_err(format!(
+            "Rated competitions require an escrow stake of at least {}",
+            min_stake
+        )))
+    };
+
+    let competition_id = competition_id.ok_or_else(error)?;
+    let competition = deps
+        .querier
+        .query_wasm_smart::<CompetitionResponse<Empty>>(
+            module,
+            &arena_interface::competition::msg::QueryBase::<Empty, Empty, Empty>::Competition {
+                competition_id,
+            },
+        )?;
+    let escrow = competition.escrow.ok_or_else(error)?;
+
+    let dues = deps.querier.query_wasm_smart::<Vec<MemberBalanceChecked>>(
+        escrow,
+        &arena_interface::escrow::QueryMsg::InitialDues {
+            start_after: None,
+            limit: None,
+        },
+    )?;
+    let stake = dues
+        .iter()
+        .flat_map(|x| x.balance.native.iter().flatten())
+        .filter(|x| x.denom == min_stake.denom)
+        .try_fold(Uint128::zero(), |acc, x| acc.checked_add(x.amount))?;
+    ensure!(stake >= min_stake.amount, error());
+
+    Ok(())
+}
+
+/// Records a pairing between two members, returning the share of its rating impact to keep
+/// Each earlier pairing within the window multiplies the share by the repeat decay
+fn record_pairing(
+    storage: &mut dyn Storage,
+    env: &Env,
+    category_id: u128,
+    integrity: &IntegrityConfig,
+    addr_1: &Addr,
+    addr_2: &Addr,
+) -> Result<Decimal, ContractError> {
+    let (addr_1, addr_2) = if addr_1 < addr_2 {
+        (addr_1, addr_2)
+    } else {
+        (addr_2, addr_1)
+    };
+
+    let mut pairings = PAIRINGS
+        .may_load(storage, (category_id, addr_1, addr_2))?
+        .unwrap_or_default();
+    pairings.retain(|x| !x.is_expired(&env.block));
+    let weight = integrity.repeat_decay.checked_pow(pairings.len() as u32)?;
+
+    pairings.push(integrity.pairing_window.after(&env.block));
+    PAIRINGS.save(storage, (category_id, addr_1, addr_2), &pairings)?;
+
+    let count = pairings.len() as u32;
+    if count >= integrity.flag_threshold {
+        FLAGGED_PAIRS.save(
+            storage,
+            (category_id, addr_1, addr_2),
+            &FlaggedPair {
+                addr_1: addr_1.clone(),
+                addr_2: addr_2.clone(),
+                pairings: count,
+                height: env.block.height,
+            },
+        )?;
+    }
+
+    Ok(weight)
+}
+
+/// Ends the category's open rating period once it has expired, so new results are buffered in the next one
+/// The ended period's results are applied through CloseRatingPeriod
+fn end_rating_period(storage: &mut dyn Storage, env: &Env, category_id: u128) -> StdResult<()> {
+    if let Some(period_end) = RATING_PERIOD_ENDS.may_load(storage, category_id)? {
+        if period_end.is_expired(&env.block) {
+            RATING_PERIOD_ENDS.remove(storage, category_id);
+            RATING_PERIOD_IDS.update(storage, category_id, |x| -> StdResult<_> {
+                Ok(x.unwrap_or_default() + 1)
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
+fn open_rating_period(
+    storage: &mut dyn Storage,
+    env: &Env,
+    category_id: u128,
+) -> Result<(), ContractError> {
+    if !RATING_PERIOD_ENDS.has(storage, category_id) {
+        let category = competition_categories().load(storage, category_id)?;
+        let period_end = category_rating_period(storage, &category)?.after(&env.block);
+
+        RATING_PERIOD_ENDS.save(storage, category_id, &period_end)?;
+    }
+
+    Ok(())
+}
+
+/// The category's rating period, falling back to the global one
+fn category_rating_period(
+    storage: &dyn Storage,
+    category: &CompetitionCategory,
+) -> StdResult<Duration> {
+    match category
+        .rating_config
+        .as_ref()
+        .and_then(|x| x.rating_period.clone())
+    {
+        Some(rating_period) => Ok(rating_period),
+        None => RATING_PERIOD.load(storage),
+    }
+}
+
+pub fn close_rating_period(
+    deps: DepsMut,
+    env: Env,
+    category_id: Uint128,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    end_rating_period(deps.storage, &env, category_id.u128())?;
+
+    let period_id =
+        oldest_pending_rating_period(deps.storage, category_id.u128())?.ok_or_else(|| {
+            ContractError::StdError(StdError::generic_err(
+                "There are no rating results to apply for this category",
+            ))
+        })?;
+    let open_period_id = RATING_PERIOD_IDS
+        .may_load(deps.storage, category_id.u128())?
+        .unwrap_or_default();
+    ensure!(
+        period_id < open_period_id,
+        ContractError::StdError(StdError::generic_err("The rating period has not ended"))
+    );
+    ensure!(
+        !SOFT_RESETS.has(deps.storage, category_id.u128()),
+        ContractError::StdError(StdError::generic_err(
+            "The season's soft reset must be completed first"
+        ))
+    );
+    ensure_ratings_migrated(deps.storage)?;
+
+    let start_after = start_after
+        .map(|x| deps.api.addr_validate(&x))
+        .transpose()?;
+    let limit = limit.unwrap_or(30).min(30);
+    let attrs = apply_rating_period(
+        deps,
+        &env,
+        category_id.u128(),
+        period_id,
+        start_after.as_ref(),
+        limit,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "close_rating_period")
+        .add_attribute("category_id", category_id)
+        .add_attribute("period_id", period_id.to_string())
+        .add_attributes(attrs))
+}
+
+/// A member's rating from before the rating period, even if their results in it were already applied
+fn period_start_rating(
+    storage: &dyn Storage,
+    category_id: u128,
+    period_id: u64,
+    addr: &Addr,
+) -> StdResult<Option<Rating>> {
+    match PERIOD_START_RATINGS.may_load(storage, (category_id, addr))? {
+        Some((applied_period_id, rating)) if applied_period_id == period_id => Ok(rating),
+        _ => ratings().may_load(storage, (category_id, addr)),
+    }
+}
+
+/// Applies a page of the buffered results of a category's ended rating period.
+/// Each member is rated against their opponents' ratings from before the period.
+fn apply_rating_period(
+    deps: DepsMut,
+    env: &Env,
+    category_id: u128,
+    period_id: u64,
+    start_after: Option<&Addr>,
+    limit: u32,
+) -> Result<Vec<Attribute>, ContractError> {
+    let pending = PENDING_RATING_RESULTS
+        .prefix((category_id, period_id))
+        .range(
             deps.storage,
-            key_1,
-            Some(&rating_1),
-            maybe_rating_1.as_ref(),
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .collect::<StdResult<Vec<_>>>()?;
+    let category = competition_categories().load(deps.storage, category_id)?;
+    let period = category_rating_period(deps.storage, &category)?;
+    let calculator = rating_calculator(
+        &category.rating_system.unwrap_or_default(),
+        &category.rating_config.unwrap_or_default(),
+    );
+
+    // Snapshot the ratings before any of them are updated
+    let mut previous_ratings: HashMap<Addr, Rating> = HashMap::new();
+    for (addr, results) in &pending {
+        let opponents = results.iter().flat_map(|x| x.opponents.iter());
+        for addr in std::iter::once(addr).chain(opponents) {
+            if !previous_ratings.contains_key(addr) {
+                let rating = period_start_rating(deps.storage, category_id, period_id, addr)?;
+                previous_ratings.insert(
+                    addr.clone(),
+                    rating.unwrap_or_else(|| calculator.initial_rating()),
+                );
+            }
+        }
+    }
+
+    let mut attrs = vec![];
+    for (addr, results) in pending {
+        let mut rating = previous_ratings[&addr].clone();
+
+        let mut sources: Vec<RatingSource> = vec![];
+        let mut competition_results: HashMap<(Addr, Uint128), u32> = HashMap::new();
+        for source in results.iter().filter_map(|x| x.source.as_ref()) {
+            *competition_results
+                .entry((source.module.clone(), source.competition_id))
+                .or_default() += 1;
+            if !sources.contains(source) {
+                sources.push(source.clone());
+            }
+        }
+        let result_count = results.len() as u32;
+        let weight = results
+            .iter()
+            .try_fold(Decimal::zero(), |acc, x| {
+                acc.checked_add(x.weight.unwrap_or(Decimal::one()))
+            })?
+            .checked_mul(Decimal::from_ratio(1u32, result_count))?;
+
+        let results: Vec<(Rating, Decimal)> = results
+            .into_iter()
+            .map(|result| {
+                // Teams are faced as a single combined opponent
+                let opponent = match result.opponents.as_slice() {
+                    [opponent] => previous_ratings[opponent].clone(),
+                    opponents => calculator.team_rating(
+                        &opponents
+                            .iter()
+                            .map(|x| previous_ratings[x].clone())
+                            .collect::<Vec<_>>(),
+                    ),
+                };
+
+                (opponent, result.score)
+            })
+            .collect();
+
+        // Calculate changes
+        calculator.update_rating_period(env, &mut rating, &results, &period);
+
+        // Repeated pairings only keep part of the value change
+        if weight < Decimal::one() {
+            let previous = previous_ratings[&addr].value;
+            rating.value = previous.checked_add(
+                rating.value.checked_sub(previous)?.checked_mul(
+                    SignedDecimal::try_from(weight)
+                        .map_err(|e| StdError::generic_err(e.to_string()))?,
+                )?,
+            )?;
+        }
+
+        // Update values, keeping the rating from before the period for the opponents applied later
+        let stored_rating = ratings().may_load(deps.storage, (category_id, &addr))?;
+        PERIOD_START_RATINGS.save(
+            deps.storage,
+            (category_id, &addr),
+            &(period_id, stored_rating.clone()),
         )?;
         ratings().replace(
             deps.storage,
-            key_2,
-            Some(&rating_2),
-            maybe_rating_2.as_ref(),
+            (category_id, &addr),
+            Some(&rating),
+            stored_rating.as_ref(),
+        )?;
+        RATING_HISTORY.save(
+            deps.storage,
+            (category_id, &addr, env.block.height),
+            &RatingSnapshot {
+                height: env.block.height,
+                rating: rating.clone(),
+                sources,
+            },
         )?;
+        PENDING_RATING_RESULTS.remove(deps.storage, (category_id, period_id, &addr));
+        for source in &sources {
+            COMPETITION_PENDING_RESULTS.remove(
+                deps.storage,
+                (
+                    (&source.module, source.competition_id.u128()),
+                    (category_id, period_id, &addr),
+                ),
+            );
+        }
 
-        // Store the new ratings in the map
-        new_ratings.insert(addr_1.to_string(), rating_1.value);
-        new_ratings.insert(addr_2.to_string(), rating_2.value);
+        // Each competition is credited with its share of the period's results
+        let change = rating.value.checked_sub(previous_ratings[&addr].value)?;
+        for ((module, competition_id), count) in competition_results {
+            let share = change.checked_mul(SignedDecimal::from_ratio(count, result_count))?;
+            RATING_ADJUSTMENTS.update(
+                deps.storage,
+                ((&module, competition_id.u128()), (category_id, &addr)),
+                |x| -> StdResult<_> { Ok(x.unwrap_or_default().checked_add(share)?) },
+            )?;
+        }
+
+        attrs.push(Attribute {
+            key: addr.to_string(),
+            value: rating.value.to_string(),
+        });
     }
 
-    let attrs = new_ratings.into_iter().map(|(addr, value)| Attribute {
-        key: addr,
-        value: value.to_string(),
-    });
+    Ok(attrs)
+}
+
+/// Reverts a page of a competition's rating adjustments, starting with its buffered results.
+/// Only rating values are restored; deviations, volatilities, and the ratings of opponents rated against the competition's results are kept.
+/// Once the DAO starts a revert, anyone can continue it until it is complete.
+pub fn revert_rating_adjustments(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    module: String,
+    competition_id: Uint128,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    ensure_ratings_migrated(deps.storage)?;
+    let module = deps.api.addr_validate(&module)?;
+    let key = (&module, competition_id.u128());
+    if !RATING_REVERTS.has(deps.storage, key) {
+        let dao = PrePropose::default().dao.load(deps.storage)?;
+        if dao != info.sender && env.contract.address != info.sender {
+            return Err(ContractError::Unauthorized {});
+        }
+
+        RATING_REVERTS.save(deps.storage, key, &Empty {})?;
+    }
+    let limit = limit.unwrap_or(30).min(30) as usize;
+    let is_reverted = |source: &Option<RatingSource>| {
+        source
+            .as_ref()
+            .is_some_and(|x| x.module == module && x.competition_id == competition_id)
+    };
+
+    // Drop the competition's results that have not been applied yet
+    let pending = COMPETITION_PENDING_RESULTS
+        .prefix(key)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+    let mut dropped_results = 0usize;
+    for (category_id, period_id, addr) in &pending {
+        let pending_key = (*category_id, *period_id, addr);
+        if let Some(results) = PENDING_RATING_RESULTS.may_load(deps.storage, pending_key)? {
+            let (reverted, kept): (Vec<_>, Vec<_>) =
+                results.into_iter().partition(|x| is_reverted(&x.source));
+
+            dropped_results += reverted.len();
+            if kept.is_empty() {
+                PENDING_RATING_RESULTS.remove(deps.storage, pending_key);
+            } else {
+                PENDING_RATING_RESULTS.save(deps.storage, pending_key, &kept)?;
+            }
+        }
+
+        COMPETITION_PENDING_RESULTS.remove(deps.storage, (key, pending_key));
+    }
+
+    // Reverse the rating value changes credited to the competition
+    // Deviations and other rating state are left as they are
+    let adjustments = RATING_ADJUSTMENTS
+        .prefix(key)
+        .range(deps.storage, None, None, Order::Ascending)
+        .take(limit - pending.len())
+        .collect::<StdResult<Vec<_>>>()?;
+    let mut attrs = vec![];
+    for ((category_id, addr), change) in adjustments {
+        if let Some(previous) = ratings().may_load(deps.storage, (category_id, &addr))? {
+            let mut rating = previous.clone();
+            rating.value = previous.value.checked_sub(change)?;
+
+            ratings().replace(
+                deps.storage,
+                (category_id, &addr),
+                Some(&rating),
+                Some(&previous),
+            )?;
+            RATING_HISTORY.save(
+                deps.storage,
+                (category_id, &addr, env.block.height),
+                &RatingSnapshot {
+                    height: env.block.height,
+                    rating: rating.clone(),
+                    sources: vec![],
+                },
+            )?;
+
+            attrs.push(Attribute {
+                key: addr.to_string(),
+                value: rating.value.to_string(),
+            });
+        }
+
+        RATING_ADJUSTMENTS.remove(deps.storage, (key, (category_id, &addr)));
+    }
+
+    let is_complete = COMPETITION_PENDING_RESULTS
+        .prefix(key)
+        .keys_raw(deps.storage, None, None, Order::Ascending)
+        .next()
+        .is_none()
+        && RATING_ADJUSTMENTS
+            .prefix(key)
+            .keys_raw(deps.storage, None, None, Order::Ascending)
+            .next()
+            .is_none();
+    if is_complete {
+        RATING_REVERTS.remove(deps.storage, key);
+    }
 
     Ok(Response::new()
-        .add_attribute("action", "adjust_ratings")
+        .add_attribute("action", "revert_rating_adjustments")
+        .add_attribute("module", module)
+        .add_attribute("competition_id", competition_id)
+        .add_attribute("dropped_results", dropped_results.to_string())
+        .add_attribute("is_complete", is_complete.to_string())
+        .add_attributes(attrs))
+}
+
+pub fn start_season(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    category_id: Uint128,
+    soft_reset: Option<SoftReset>,
+    reward: Option<SeasonReward>,
+) -> Result<Response, ContractError> {
+    let category = competition_categories()
+        .may_load(deps.storage, category_id.u128())?
+        .ok_or(ContractError::CompetitionCategoryDoesNotExist { id: category_id })?;
+    if let Some(soft_reset) = &soft_reset {
+        soft_reset.validate()?;
+    }
+    match &reward {
+        Some(reward) => {
+            reward.validate()?;
+            ensure!(
+                !info.funds.is_empty(),
+                ContractError::StdError(StdError::generic_err(
+                    "A season reward requires a reward pool"
+                ))
+            );
+        }
+        None => ensure!(
+            info.funds.is_empty(),
+            ContractError::StdError(StdError::generic_err(
+                "Funds can only be sent with a season reward"
+            ))
+        ),
+    }
+
+    // The final leaderboard includes the results of an ended rating period
+    end_rating_period(deps.storage, &env, category_id.u128())?;
+    let open_period_id = RATING_PERIOD_IDS
+        .may_load(deps.storage, category_id.u128())?
+        .unwrap_or_default();
+    ensure!(
+        oldest_pending_rating_period(deps.storage, category_id.u128())?
+            .is_none_or(|period_id| period_id == open_period_id),
+        ContractError::StdError(StdError::generic_err(
+            "The ended rating period must be closed before starting a season"
+        ))
+    );
+    ensure!(
+        !SOFT_RESETS.has(deps.storage, category_id.u128()),
+        ContractError::StdError(StdError::generic_err(
+            "The previous season's soft reset must be completed first"
+        ))
+    );
+    ensure_ratings_migrated(deps.storage)?;
+
+    let season_id = SEASONS
+        .prefix(category_id.u128())
+        .keys(deps.storage, None, None, Order::Descending)
+        .next()
+        .transpose()?
+        .unwrap_or_default()
+        + 1;
+    // Only the top of the leaderboard is archived
+    let standings_count = reward
+        .as_ref()
+        .map_or(0, |x| x.distribution.len())
+        .max(MIN_SEASON_STANDINGS);
+    let standings = ratings()
+        .idx
+        .rating
+        .sub_prefix(category_id.u128())
+        .range(deps.storage, None, None, Order::Descending)
+        .take(standings_count)
+        .map(|x| x.map(|((_, addr), rating)| (addr, rating)))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    // Each rank is paid its share of the pool, and the remainder goes to first place
+    let mut rewards: Vec<Vec<Coin>> = vec![vec![]; standings.len()];
+    if let Some(reward) = &reward {
+        for coin in &info.funds {
+            let mut remainder = coin.amount;
+            for (rank_reward, share) in rewards.iter_mut().zip(reward.distribution.iter()) {
+                let amount = coin.amount.checked_mul_floor(*share)?;
+                if !amount.is_zero() {
+                    remainder = remainder.checked_sub(amount)?;
+                    rank_reward.push(Coin {
+                        denom: coin.denom.clone(),
+                        amount,
+                    });
+                }
+            }
+            if !remainder.is_zero() {
+                if let Some(first_reward) = rewards.first_mut() {
+                    match first_reward.iter_mut().find(|x| x.denom == coin.denom) {
+                        Some(first_coin) => {
+                            first_coin.amount = first_coin.amount.checked_add(remainder)?
+                        }
+                        None => first_reward.push(Coin {
+                            denom: coin.denom.clone(),
+                            amount: remainder,
+                        }),
+                    }
+                }
+            }
+        }
+    }
+
+    // Without any rated members, the reward pool is returned
+    let mut msgs = vec![];
+    if standings.is_empty() && !info.funds.is_empty() {
+        msgs.push(CosmosMsg::Bank(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: info.funds.clone(),
+        }));
+    }
+
+    for (i, ((addr, rating), reward)) in standings.into_iter().zip(rewards).enumerate() {
+        if !reward.is_empty() {
+            msgs.push(CosmosMsg::Bank(BankMsg::Send {
+                to_address: addr.to_string(),
+                amount: reward.clone(),
+            }));
+        }
+        SEASON_STANDINGS.save(
+            deps.storage,
+            (category_id.u128(), season_id, i as u32 + 1),
+            &SeasonStanding {
+                rank: i as u32 + 1,
+                addr,
+                rating,
+                reward,
+            },
+        )?;
+    }
+
+    SEASONS.save(
+        deps.storage,
+        (category_id.u128(), season_id),
+        &Season {
+            id: season_id,
+            start_height: SEASON_STARTS
+                .may_load(deps.storage, category_id.u128())?
+                .unwrap_or_default(),
+            end_height: env.block.height,
+            reward_pool: info.funds,
+        },
+    )?;
+    SEASON_STARTS.save(deps.storage, category_id.u128(), &env.block.height)?;
+
+    // The soft reset starts with the first page of ratings
+    let mut attrs = vec![];
+    if let Some(soft_reset) = soft_reset {
+        SOFT_RESETS.save(deps.storage, category_id.u128(), &(soft_reset, None))?;
+        attrs = apply_soft_reset(deps.storage, &env, &category, None)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "start_season")
+        .add_attribute("category_id", category_id)
+        .add_attribute("season_id", season_id.to_string())
+        .add_attributes(attrs)
+        .add_messages(msgs))
+}
+
+pub fn continue_soft_reset(
+    deps: DepsMut,
+    env: Env,
+    category_id: Uint128,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let category = competition_categories()
+        .may_load(deps.storage, category_id.u128())?
+        .ok_or(ContractError::CompetitionCategoryDoesNotExist { id: category_id })?;
+    ensure_ratings_migrated(deps.storage)?;
+    let attrs = apply_soft_reset(deps.storage, &env, &category, limit)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "continue_soft_reset")
+        .add_attribute("category_id", category_id)
         .add_attributes(attrs))
 }
 
+/// Soft resets a page of a category's ratings, pulling each value toward the initial rating and raising Glicko-2 deviations
+fn apply_soft_reset(
+    storage: &mut dyn Storage,
+    env: &Env,
+    category: &CompetitionCategory,
+    limit: Option<u32>,
+) -> Result<Vec<Attribute>, ContractError> {
+    let category_id = category.id.u128();
+    let (soft_reset, start_after) =
+        SOFT_RESETS.may_load(storage, category_id)?.ok_or_else(|| {
+            ContractError::StdError(StdError::generic_err(
+                "There is no soft reset in progress for this category",
+            ))
+        })?;
+    let limit = limit.unwrap_or(30).min(30) as usize;
+
+    let calculator = rating_calculator(
+        &category.rating_system.clone().unwrap_or_default(),
+        &category.rating_config.clone().unwrap_or_default(),
+    );
+    let initial_value = calculator.initial_rating().value;
+    let max_deviation = category
+        .rating_config
+        .clone()
+        .unwrap_or_default()
+        .max_deviation;
+    let pull = SignedDecimal::try_from(soft_reset.pull)
+        .map_err(|e| StdError::generic_err(e.to_string()))?;
+
+    let page = ratings()
+        .prefix(category_id)
+        .range(
+            storage,
+            start_after.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+    let is_complete = page.len() < limit;
+    let last = page.last().map(|x| x.0.clone());
+
+    for (addr, previous) in page {
+        let mut rating = previous.clone();
+        rating.value = previous.value.checked_add(
+            initial_value
+                .checked_sub(previous.value)?
+                .checked_mul(pull)?,
+        )?;
+        if let RatingState::Glicko2 { phi, sigma: _ } = &mut rating.state {
+            *phi = phi
+                .checked_add(soft_reset.deviation_increase)?
+                .min(max_deviation.max(*phi));
+        }
+
+        ratings().replace(
+            storage,
+            (category_id, &addr),
+            Some(&rating),
+            Some(&previous),
+        )?;
+        // Keep the sources of a rating period closed at the same height
+        let key = (category_id, &addr, env.block.height);
+        let sources = RATING_HISTORY
+            .may_load(storage, key)?
+            .map(|x| x.sources)
+            .unwrap_or_default();
+        RATING_HISTORY.save(
+            storage,
+            key,
+            &RatingSnapshot {
+                height: env.block.height,
+                rating,
+                sources,
+            },
+        )?;
+    }
+
+    if is_complete {
+        SOFT_RESETS.remove(storage, category_id);
+    } else {
+        SOFT_RESETS.save(storage, category_id, &(soft_reset, last))?;
+    }
+
+    Ok(vec![Attribute {
+        key: "is_soft_reset_complete".to_string(),
+        value: is_complete.to_string(),
+    }])
+}
+
+pub fn continue_ratings_migration(
+    deps: DepsMut,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let attrs = migrate_ratings_page(deps.storage, limit)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "continue_ratings_migration")
+        .add_attributes(attrs))
+}
+
+/// Ratings are only updated once a ratings migration has rewritten all of them
+fn ensure_ratings_migrated(storage: &dyn Storage) -> Result<(), ContractError> {
+    ensure!(
+        !RATINGS_MIGRATION.exists(storage),
+        ContractError::StdError(StdError::generic_err(
+            "The ratings migration must be completed first"
+        ))
+    );
+
+    Ok(())
+}
+
 pub fn update_rating_period(deps: DepsMut, period: Duration) -> Result<Response, ContractError> {
     let value = match &period {
         Duration::Height(height) => height,
@@ -518,3 +2143,64 @@ pub fn set_payment_registry(deps: DepsMut, addr: String) -> Result<Response, Con
         .add_attribute("action", "set_payment_registry")
         .add_attribute("addr", addr))
 }
+
+pub fn record_host_activity(
+    deps: DepsMut,
+    info: MessageInfo,
+    host: String,
+    activity: HostActivity,
+) -> Result<Response, ContractError> {
+    // Disabled modules still report on the competitions they already host
+    ensure!(
+        competition_modules().has(deps.storage, &info.sender),
+        ContractError::CompetitionModuleDoesNotExist { addr: info.sender }
+    );
+
+    let host = deps.api.addr_validate(&host)?;
+    let mut stats = HOST_STATS
+        .may_load(deps.storage, &host)?
+        .unwrap_or_default();
+    let action = match activity {
+        HostActivity::Create {} => "create",
+        HostActivity::Process { .. } => "process",
+        HostActivity::Jail {} => "jail",
+    };
+    stats.record(activity)?;
+    HOST_STATS.save(deps.storage, &host, &stats)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "record_host_activity")
+        .add_attribute("host", host)
+        .add_attribute("activity", action))
+}
+
+pub fn update_host_denylist(
+    deps: DepsMut,
+    env: &Env,
+    to_add: Option<Vec<HostBan<String>>>,
+    to_remove: Option<Vec<String>>,
+) -> Result<Response, ContractError> {
+    let mut attrs = vec![];
+
+    if let Some(to_remove) = to_remove {
+        for host in to_remove {
+            let host = deps.api.addr_validate(&host)?;
+
+            HOST_DENYLIST.remove(deps.storage, &host);
+            attrs.push(Attribute::new("unban", host));
+        }
+    }
+
+    if let Some(to_add) = to_add {
+        for ban in to_add {
+            let ban = ban.into_checked(deps.as_ref(), &env.block)?;
+
+            HOST_DENYLIST.save(deps.storage, &ban.host, &ban)?;
+            attrs.push(Attribute::new("ban", ban.host));
+        }
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "update_host_denylist")
+        .add_attributes(attrs))
+}
@@ -1,12 +1,32 @@
-use arena_interface::fees::TaxConfiguration;
-use cosmwasm_std::{from_json, DepsMut, StdError, Uint128};
+use arena_interface::{
+    fees::TaxConfiguration,
+    ratings::{Rating, RatingState},
+};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    ensure, from_json, Addr, Attribute, BlockInfo, Decimal, DepsMut, Order, SignedDecimal,
+    StdError, StdResult, Storage, Uint128,
+};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Map, MultiIndex};
 use cw_utils::Duration;
 
 use crate::{
-    state::{ARENA_TAX_CONFIG, COMPETITION_CATEGORIES_COUNT, RATING_PERIOD},
+    state::{
+        ratings, RatingsMigration, ARENA_TAX_CONFIG, COMPETITION_CATEGORIES_COUNT,
+        RATINGS_MIGRATION, RATING_PERIOD,
+    },
     ContractError,
 };
 
+#[cw_serde]
+struct LegacyRating {
+    /// Serialized the same way as the unsigned value it was stored as
+    pub value: SignedDecimal,
+    pub phi: Decimal,
+    pub sigma: Decimal,
+    pub last_block: Option<BlockInfo>,
+}
+
 pub fn from_v1_3_to_v1_4(deps: DepsMut) -> Result<(), ContractError> {
     let prev_key = "competition-categories-count".as_bytes();
 
@@ -35,3 +55,132 @@ pub fn from_v1_4_to_v1_6(deps: DepsMut) -> Result<(), ContractError> {
 
     Ok(())
 }
+
+/// Starts rewriting every rating, where the first page is migrated with the patch and the rest through ContinueRatingsMigration
+/// Ratings cannot be updated until the migration is complete
+pub fn start_ratings_migration(
+    deps: DepsMut,
+    migration: RatingsMigration,
+) -> Result<(), ContractError> {
+    ensure!(
+        !RATINGS_MIGRATION.exists(deps.storage),
+        ContractError::StdError(StdError::generic_err(
+            "The previous ratings migration must be completed first"
+        ))
+    );
+    RATINGS_MIGRATION.save(deps.storage, &(migration, None))?;
+    migrate_ratings_page(deps.storage, None)?;
+
+    Ok(())
+}
+
+/// Migrates the page of ratings after the migration's cursor
+pub fn migrate_ratings_page(
+    storage: &mut dyn Storage,
+    limit: Option<u32>,
+) -> Result<Vec<Attribute>, ContractError> {
+    let (migration, start_after) = RATINGS_MIGRATION.may_load(storage)?.ok_or_else(|| {
+        ContractError::StdError(StdError::generic_err(
+            "There is no ratings migration in progress",
+        ))
+    })?;
+    let limit = limit.unwrap_or(30).min(30) as usize;
+    let start = start_after
+        .as_ref()
+        .map(|(category_id, addr)| Bound::exclusive((*category_id, addr)));
+
+    let page = match migration {
+        RatingsMigration::TaggedRatings {} => to_tagged_ratings(storage, start, limit)?,
+        RatingsMigration::SignedRatings {} => to_signed_ratings(storage, start, limit)?,
+    };
+    let is_complete = page.len() < limit;
+
+    match page.last() {
+        Some(last) if !is_complete => {
+            RATINGS_MIGRATION.save(storage, &(migration, Some(last.clone())))?
+        }
+        _ => RATINGS_MIGRATION.remove(storage),
+    }
+
+    Ok(vec![Attribute {
+        key: "is_ratings_migration_complete".to_string(),
+        value: is_complete.to_string(),
+    }])
+}
+
+/// Ratings were Glicko-2 only and stored phi and sigma as fields
+fn to_tagged_ratings(
+    storage: &mut dyn Storage,
+    start: Option<Bound<(u128, &Addr)>>,
+    limit: usize,
+) -> Result<Vec<(u128, Addr)>, ContractError> {
+    let legacy_ratings: Map<(u128, &Addr), LegacyRating> = Map::new("ratings");
+    let ratings: Map<(u128, &Addr), Rating> = Map::new("ratings");
+
+    let entries = legacy_ratings
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+    let keys = entries.iter().map(|(key, _)| key.clone()).collect();
+
+    // The value index is unchanged, so the ratings can be rewritten in place
+    for ((category_id, addr), legacy_rating) in entries {
+        ratings.save(
+            storage,
+            (category_id, &addr),
+            &Rating {
+                value: legacy_rating.value,
+                state: RatingState::Glicko2 {
+                    phi: legacy_rating.phi,
+                    sigma: legacy_rating.sigma,
+                },
+                last_block: legacy_rating.last_block,
+            },
+        )?;
+    }
+
+    Ok(keys)
+}
+
+struct LegacyRatingIndexes<'a> {
+    rating: MultiIndex<'a, u128, Rating, (u128, &'a Addr)>,
+}
+
+impl IndexList<Rating> for LegacyRatingIndexes<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Rating>> + '_> {
+        let v: Vec<&dyn Index<Rating>> = vec![&self.rating];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Rating values became signed and the value index is scoped by category, which changes its keys
+/// The stored values serialize the same way, so only the index is rebuilt
+fn to_signed_ratings(
+    storage: &mut dyn Storage,
+    start: Option<Bound<(u128, &Addr)>>,
+    limit: usize,
+) -> Result<Vec<(u128, Addr)>, ContractError> {
+    let legacy_ratings: IndexedMap<(u128, &Addr), Rating, LegacyRatingIndexes> = IndexedMap::new(
+        "ratings",
+        LegacyRatingIndexes {
+            rating: MultiIndex::new(
+                |_x, d: &Rating| d.value.atomics().i128() as u128,
+                "ratings",
+                "ratings__rating",
+            ),
+        },
+    );
+
+    let entries = legacy_ratings
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+    let keys = entries.iter().map(|(key, _)| key.clone()).collect();
+
+    for ((category_id, addr), rating) in entries {
+        legacy_ratings.remove(storage, (category_id, &addr))?;
+        ratings().save(storage, (category_id, &addr), &rating)?;
+    }
+
+    Ok(keys)
+}
@@ -1,15 +1,22 @@
 use crate::state::{
-    competition_categories, get_rulesets_category_and_is_enabled_idx, ratings, CompetitionModule,
-    ARENA_TAX_CONFIG, ENROLLMENT_MODULES, KEYS, TAX,
+    category_lineage, competition_categories, get_rulesets_category_and_is_enabled_idx,
+    oldest_pending_rating_period, ratings, CompetitionModule, ARENA_TAX_CONFIG, CATEGORY_CHILDREN,
+    CATEGORY_TAXES, ENROLLMENT_MODULES, FLAGGED_PAIRS, HOST_DENYLIST, HOST_STATS, KEYS,
+    MODULE_TAXES, MODULE_VERSIONS, PENDING_RATING_RESULTS, RATING_HISTORY, RATING_PERIOD_IDS,
+    RULESET_VERSIONS, SEASONS, SEASON_STANDINGS, TAX, TAX_TIERS,
 };
 use arena_interface::{
     core::{
-        CompetitionCategory, CompetitionModuleQuery, CompetitionModuleResponse, DumpStateResponse,
-        RatingResponse, Ruleset, TaxConfigurationResponse,
+        CategoryMetadata, CompetitionCategory, CompetitionModuleQuery, CompetitionModuleResponse,
+        DumpStateResponse, ModuleVersion, RatingResponse, Ruleset, TaxConfigurationResponse,
+    },
+    hosts::{HostBan, HostStatsResponse},
+    ratings::{
+        rating_index_key, FlaggedPair, PendingRatingResult, Rating, RatingSnapshot, Season,
+        SeasonStanding,
     },
-    ratings::Rating,
 };
-use cosmwasm_std::{Decimal, Deps, Empty, Env, StdResult, Uint128};
+use cosmwasm_std::{Addr, Decimal, Deps, Empty, Env, Order, SignedDecimal, StdResult, Uint128};
 use cw_paginate::paginate_indexed_map;
 use cw_storage_plus::Bound;
 use cw_utils::maybe_addr;
@@ -131,6 +138,48 @@ pub fn ruleset(deps: Deps, id: Uint128) -> StdResult<Option<Ruleset>> {
     crate::state::rulesets().may_load(deps.storage, id.u128())
 }
 
+pub fn ruleset_version(deps: Deps, id: Uint128, version: u64) -> StdResult<Option<Ruleset>> {
+    if let Some(ruleset) = RULESET_VERSIONS.may_load(deps.storage, (id.u128(), version))? {
+        return Ok(Some(ruleset));
+    }
+
+    // Rulesets that were never edited only have their current version
+    Ok(ruleset(deps, id)?.filter(|x| x.current_version() == version))
+}
+
+pub fn ruleset_history(
+    deps: Deps,
+    id: Uint128,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<Ruleset>> {
+    let limit = limit.unwrap_or(30).min(30);
+
+    let has_versions = RULESET_VERSIONS
+        .prefix(id.u128())
+        .keys(deps.storage, None, None, Order::Ascending)
+        .next()
+        .is_some();
+    if !has_versions {
+        return Ok(ruleset(deps, id)?
+            .filter(|x| start_after.map_or(true, |start_after| x.current_version() > start_after))
+            .into_iter()
+            .collect());
+    }
+
+    RULESET_VERSIONS
+        .prefix(id.u128())
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .map(|x| x.map(|(_, ruleset)| ruleset))
+        .collect()
+}
+
 pub fn categories(
     deps: Deps,
     start_after: Option<Uint128>,
@@ -172,6 +221,48 @@ pub fn category(deps: Deps, id: Uint128) -> StdResult<Option<CompetitionCategory
     crate::state::competition_categories().may_load(deps.storage, id.u128())
 }
 
+pub fn subcategories(
+    deps: Deps,
+    category_id: Uint128,
+    start_after: Option<Uint128>,
+    limit: Option<u32>,
+) -> StdResult<Vec<CompetitionCategory>> {
+    let limit = limit.unwrap_or(30).min(30);
+
+    CATEGORY_CHILDREN
+        .prefix(category_id.u128())
+        .keys(
+            deps.storage,
+            start_after.map(|x| Bound::exclusive(x.u128())),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .map(|x| x.and_then(|id| competition_categories().load(deps.storage, id)))
+        .collect()
+}
+
+pub fn category_path(deps: Deps, category_id: Uint128) -> StdResult<Vec<CompetitionCategory>> {
+    let mut lineage = category_lineage(deps.storage, category_id.u128())?;
+    lineage.reverse();
+
+    Ok(lineage)
+}
+
+pub fn category_metadata(deps: Deps, category_id: Uint128) -> StdResult<Option<CategoryMetadata>> {
+    if !competition_categories().has(deps.storage, category_id.u128()) {
+        return Ok(None);
+    }
+
+    Ok(Some(
+        category_lineage(deps.storage, category_id.u128())?
+            .into_iter()
+            .fold(CategoryMetadata::default(), |metadata, category| {
+                metadata.inherit(&category.metadata.unwrap_or_default())
+            }),
+    ))
+}
+
 pub fn competition_module(
     deps: Deps,
     env: Env,
@@ -214,9 +305,9 @@ pub fn is_valid_category_and_rulesets(
     category_id: Uint128,
     rulesets: Vec<Uint128>,
 ) -> bool {
-    if !competition_categories().has(deps.storage, category_id.u128()) {
+    let Ok(lineage) = category_lineage(deps.storage, category_id.u128()) else {
         return false;
-    }
+    };
 
     for ruleset_id in rulesets {
         if !crate::state::rulesets().has(deps.storage, ruleset_id.u128()) {
@@ -228,7 +319,7 @@ pub fn is_valid_category_and_rulesets(
                 if !ruleset.is_enabled {
                     return false;
                 }
-                if ruleset.category_id != category_id {
+                if !lineage.iter().any(|x| x.id == ruleset.category_id) {
                     return false;
                 }
             }
@@ -239,43 +330,153 @@ pub fn is_valid_category_and_rulesets(
     true
 }
 
-pub fn arena_fee_config(deps: Deps, height: u64) -> StdResult<TaxConfigurationResponse> {
-    Ok(ARENA_TAX_CONFIG.load(deps.storage)?.into_response(
-        TAX.may_load_at_height(deps.storage, height)?
+pub fn arena_fee_config(
+    deps: Deps,
+    height: u64,
+    category_id: Option<Uint128>,
+    module: Option<String>,
+) -> StdResult<TaxConfigurationResponse> {
+    let category_tax = match category_id {
+        Some(category_id) => {
+            CATEGORY_TAXES.may_load_at_height(deps.storage, category_id.u128(), height)?
+        }
+        None => None,
+    };
+    let module_tax = match module {
+        Some(module) => MODULE_TAXES.may_load_at_height(deps.storage, module, height)?,
+        None => None,
+    };
+    let tax = match category_tax.or(module_tax) {
+        Some(tax) => tax,
+        None => TAX
+            .may_load_at_height(deps.storage, height)?
             .unwrap_or_default(),
-    ))
+    };
+    let volume_tiers = TAX_TIERS
+        .may_load_at_height(deps.storage, height)?
+        .filter(|x| !x.is_empty());
+
+    Ok(ARENA_TAX_CONFIG
+        .load(deps.storage)?
+        .into_response(tax, volume_tiers))
 }
 
-pub fn rating(deps: Deps, category_id: Uint128, addr: String) -> StdResult<Option<Rating>> {
+pub fn rating(
+    deps: Deps,
+    category_id: Uint128,
+    addr: String,
+    height: Option<u64>,
+) -> StdResult<Option<Rating>> {
     let addr = deps.api.addr_validate(&addr)?;
-    ratings().may_load(deps.storage, (category_id.u128(), &addr))
+    let current = ratings().may_load(deps.storage, (category_id.u128(), &addr))?;
+
+    let Some(height) = height else {
+        return Ok(current);
+    };
+
+    let history = RATING_HISTORY.prefix((category_id.u128(), &addr));
+    match history
+        .range(
+            deps.storage,
+            None,
+            Some(Bound::inclusive(height)),
+            Order::Descending,
+        )
+        .next()
+        .transpose()?
+    {
+        Some((_, snapshot)) => Ok(Some(snapshot.rating)),
+        // Ratings from before the history was kept are only known as of their last update
+        None if history
+            .keys(deps.storage, None, None, Order::Ascending)
+            .next()
+            .is_none() =>
+        {
+            Ok(current.filter(|rating| {
+                rating
+                    .last_block
+                    .as_ref()
+                    .is_some_and(|block| block.height <= height)
+            }))
+        }
+        None => Ok(None),
+    }
+}
+
+pub fn rating_history(
+    deps: Deps,
+    category_id: Uint128,
+    addr: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<RatingSnapshot>> {
+    let addr = deps.api.addr_validate(&addr)?;
+    let limit = limit.unwrap_or(30).min(30);
+
+    RATING_HISTORY
+        .prefix((category_id.u128(), &addr))
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .map(|x| x.map(|(_, snapshot)| snapshot))
+        .collect()
+}
+
+pub fn pending_rating_results(
+    deps: Deps,
+    category_id: Uint128,
+    addr: String,
+) -> StdResult<Vec<PendingRatingResult>> {
+    let addr = deps.api.addr_validate(&addr)?;
+    let Some(oldest_period_id) = oldest_pending_rating_period(deps.storage, category_id.u128())?
+    else {
+        return Ok(vec![]);
+    };
+    let open_period_id = RATING_PERIOD_IDS
+        .may_load(deps.storage, category_id.u128())?
+        .unwrap_or_default();
+
+    let mut results = vec![];
+    for period_id in oldest_period_id..=open_period_id {
+        if let Some(period_results) =
+            PENDING_RATING_RESULTS.may_load(deps.storage, (category_id.u128(), period_id, &addr))?
+        {
+            results.extend(period_results);
+        }
+    }
+
+    Ok(results)
 }
 
 pub fn rating_leaderboard(
     deps: Deps,
     category_id: Uint128,
-    start_after: Option<(Uint128, String)>,
+    start_after: Option<(SignedDecimal, String)>,
     limit: Option<u32>,
 ) -> StdResult<Vec<RatingResponse>> {
-    let start_after_addr = start_after
-        .as_ref()
-        .map(|x| deps.api.addr_validate(&x.1))
+    let start_after = start_after
+        .map(|(rating, addr)| -> StdResult<_> {
+            Ok((rating_index_key(rating), deps.api.addr_validate(&addr)?))
+        })
         .transpose()?;
-    let start_after_bound = start_after.map(|(rating, _addr)| {
-        Bound::exclusive((
-            rating.u128(),
-            (category_id.u128(), start_after_addr.as_ref().unwrap()),
-        ))
-    });
-    let limit = limit.unwrap_or(30).max(30);
+    // The leaderboard is descending, so the cursor is the upper bound
+    let start_after_bound = start_after
+        .as_ref()
+        .map(|(key, addr)| Bound::exclusive((*key, (category_id.u128(), addr))));
+    let limit = limit.unwrap_or(30).min(30);
 
     ratings()
         .idx
         .rating
+        .sub_prefix(category_id.u128())
         .range(
             deps.storage,
-            start_after_bound,
             None,
+            start_after_bound,
             cosmwasm_std::Order::Descending,
         )
         .map(|x| {
@@ -293,3 +494,159 @@ pub fn is_valid_enrollment_module(deps: Deps, addr: String) -> StdResult<bool> {
 
     Ok(ENROLLMENT_MODULES.has(deps.storage, &addr))
 }
+
+pub fn seasons(
+    deps: Deps,
+    category_id: Uint128,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<Season>> {
+    let limit = limit.unwrap_or(30).min(30);
+
+    SEASONS
+        .prefix(category_id.u128())
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .map(|x| x.map(|(_, season)| season))
+        .collect()
+}
+
+pub fn season_leaderboard(
+    deps: Deps,
+    category_id: Uint128,
+    season_id: u64,
+    start_after: Option<u32>,
+    limit: Option<u32>,
+) -> StdResult<Vec<SeasonStanding>> {
+    let limit = limit.unwrap_or(30).min(30);
+
+    SEASON_STANDINGS
+        .prefix((category_id.u128(), season_id))
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .map(|x| x.map(|(_, standing)| standing))
+        .collect()
+}
+
+pub fn flagged_pairs(
+    deps: Deps,
+    category_id: Uint128,
+    start_after: Option<(String, String)>,
+    limit: Option<u32>,
+) -> StdResult<Vec<FlaggedPair>> {
+    let start_after = start_after
+        .map(|(addr_1, addr_2)| -> StdResult<_> {
+            Ok((
+                deps.api.addr_validate(&addr_1)?,
+                deps.api.addr_validate(&addr_2)?,
+            ))
+        })
+        .transpose()?;
+    let limit = limit.unwrap_or(30).min(30);
+
+    FLAGGED_PAIRS
+        .prefix(category_id.u128())
+        .range(
+            deps.storage,
+            start_after
+                .as_ref()
+                .map(|(addr_1, addr_2)| Bound::exclusive((addr_1, addr_2))),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .map(|x| x.map(|(_, pair)| pair))
+        .collect()
+}
+
+pub fn host_stats(deps: Deps, host: String) -> StdResult<Option<HostStatsResponse>> {
+    let host = deps.api.addr_validate(&host)?;
+
+    Ok(HOST_STATS
+        .may_load(deps.storage, &host)?
+        .map(|stats| stats.into_response(host)))
+}
+
+pub fn host_stats_list(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<HostStatsResponse>> {
+    let start_after = maybe_addr(deps.api, start_after)?;
+    let limit = limit.unwrap_or(30).min(30);
+
+    HOST_STATS
+        .range(
+            deps.storage,
+            start_after.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .map(|x| x.map(|(host, stats)| stats.into_response(host)))
+        .collect()
+}
+
+pub fn module_versions(
+    deps: Deps,
+    key: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<ModuleVersion>> {
+    let limit = limit.unwrap_or(30).min(30);
+
+    MODULE_VERSIONS
+        .prefix(&key)
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit as usize)
+        .map(|x| x.map(|(_, module_version)| module_version))
+        .collect()
+}
+
+pub fn host_ban(deps: Deps, env: Env, host: String) -> StdResult<Option<HostBan<Addr>>> {
+    let host = deps.api.addr_validate(&host)?;
+
+    Ok(HOST_DENYLIST
+        .may_load(deps.storage, &host)?
+        .filter(|ban| ban.is_active(&env.block)))
+}
+
+pub fn host_denylist(
+    deps: Deps,
+    env: Env,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<HostBan<Addr>>> {
+    let start_after = maybe_addr(deps.api, start_after)?;
+    let limit = limit.unwrap_or(30).min(30);
+
+    HOST_DENYLIST
+        .range(
+            deps.storage,
+            start_after.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .filter(|x| {
+            x.as_ref()
+                .map_or(true, |(_, ban)| ban.is_active(&env.block))
+        })
+        .take(limit as usize)
+        .map(|x| x.map(|(_, ban)| ban))
+        .collect()
+}
@@ -1,14 +1,20 @@
 use arena_interface::{
-    core::{CompetitionCategory, Ruleset},
-    fees::TaxConfiguration,
-    ratings::Rating,
+    core::{CompetitionCategory, EscrowMigration, JailProposal, ModuleVersion, Ruleset},
+    fees::{TaxConfiguration, TaxTier},
+    hosts::{HostBan, HostStats},
+    ratings::{
+        rating_index_key, FlaggedPair, PendingRatingResult, Rating, RatingSnapshot, Season,
+        SeasonStanding, SoftReset,
+    },
 };
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Decimal, Empty, Uint128};
+use cosmwasm_std::{
+    Addr, Decimal, Empty, Order, SignedDecimal, StdError, StdResult, Storage, Uint128,
+};
 use cw_storage_plus::{
-    Index, IndexList, IndexedMap, Item, Map, MultiIndex, SnapshotItem, SnapshotMap,
+    Index, IndexList, IndexedMap, Item, KeyDeserialize, Map, MultiIndex, SnapshotItem, SnapshotMap,
 };
-use cw_utils::Duration;
+use cw_utils::{Duration, Expiration};
 
 pub const ARENA_TAX_CONFIG: Item<TaxConfiguration> = Item::new("arena_tax_config");
 pub const COMPETITION_CATEGORIES_COUNT: Item<Uint128> = Item::new("competition_categories_count");
@@ -18,7 +24,33 @@ pub const TAX: SnapshotItem<Decimal> = SnapshotItem::new(
     "tax__change",
     cw_storage_plus::Strategy::EveryBlock,
 );
+/// Arena tax overrides for a category's competitions
+pub const CATEGORY_TAXES: SnapshotMap<u128, Decimal> = SnapshotMap::new(
+    "category_taxes",
+    "category_taxes__check",
+    "category_taxes__change",
+    cw_storage_plus::Strategy::EveryBlock,
+);
+/// Arena tax overrides for a competition module's competitions, stored by module key
+pub const MODULE_TAXES: SnapshotMap<String, Decimal> = SnapshotMap::new(
+    "module_taxes",
+    "module_taxes__check",
+    "module_taxes__change",
+    cw_storage_plus::Strategy::EveryBlock,
+);
+/// Lower Arena tax rates for competitions with larger prize pools
+pub const TAX_TIERS: SnapshotItem<Vec<TaxTier>> = SnapshotItem::new(
+    "tax_tiers",
+    "tax_tiers__check",
+    "tax_tiers__change",
+    cw_storage_plus::Strategy::EveryBlock,
+);
+/// The highest combined rate the Arena tax and a competition's additional fees can take
+pub const FEE_CEILING: Item<Decimal> = Item::new("fee_ceiling");
 pub const RULESETS_COUNT: Item<Uint128> = Item::new("ruleset_count");
+/// Every version of each ruleset, stored by id and version
+/// Rulesets from before versioning have no entries until they are edited
+pub const RULESET_VERSIONS: Map<(u128, u64), Ruleset> = Map::new("ruleset_versions");
 pub const KEYS: SnapshotMap<String, Addr> = SnapshotMap::new(
     "keys",
     "keys__check",
@@ -26,8 +58,72 @@ pub const KEYS: SnapshotMap<String, Addr> = SnapshotMap::new(
     cw_storage_plus::Strategy::EveryBlock,
 );
 pub const RATING_PERIOD: Item<Duration> = Item::new("rating_period");
+/// The end of each category's open rating period
+pub const RATING_PERIOD_ENDS: Map<u128, Expiration> = Map::new("rating_period_ends");
+/// The id of each category's latest rating period, which is open until its end expires
+pub const RATING_PERIOD_IDS: Map<u128, u64> = Map::new("rating_period_ids");
+/// Results buffered until their rating period is closed, stored by category, rating period, and member
+pub const PENDING_RATING_RESULTS: Map<(u128, u64, &Addr), Vec<PendingRatingResult>> =
+    Map::new("pending_rating_results");
+/// Each member's rating from before their latest applied rating period, stored by category and member along with the period id
+/// Opponents applied in an earlier page of the period are rated at this rating
+pub const PERIOD_START_RATINGS: Map<(u128, &Addr), (u64, Option<Rating>)> =
+    Map::new("period_start_ratings");
+/// Each member's rating after every change, stored by category, member, and height
+/// Ratings from before the history was kept have no entries until their next change
+pub const RATING_HISTORY: Map<(u128, &Addr, u64), RatingSnapshot> = Map::new("rating_history");
+/// The net rating value change credited to each competition, stored by module and competition id, then by category and member
+/// A rating period's change is split between competitions by their share of the member's results
+pub const RATING_ADJUSTMENTS: Map<((&Addr, u128), (u128, &Addr)), SignedDecimal> =
+    Map::new("rating_adjustments");
+/// The buffered results of each competition, stored by module and competition id, then by category, rating period, and member
+pub const COMPETITION_PENDING_RESULTS: Map<((&Addr, u128), (u128, u64, &Addr)), Empty> =
+    Map::new("competition_pending_results");
+/// Competitions whose rating adjustments are being reverted, stored by module and competition id
+pub const RATING_REVERTS: Map<(&Addr, u128), Empty> = Map::new("rating_reverts");
+/// The height each category's current season started at, where the first season starts at height 0
+pub const SEASON_STARTS: Map<u128, u64> = Map::new("season_starts");
+/// Each category's ended seasons, stored by category and season id
+pub const SEASONS: Map<(u128, u64), Season> = Map::new("seasons");
+/// The final leaderboard of each ended season, stored by category, season id, and rank
+pub const SEASON_STANDINGS: Map<(u128, u64, u32), SeasonStanding> = Map::new("season_standings");
+/// Soft resets still being applied to each category's ratings, along with the last member reset
+pub const SOFT_RESETS: Map<u128, (SoftReset, Option<Addr>)> = Map::new("soft_resets");
+/// The expirations of each pair's pairings within the pairing window, stored by category and the sorted pair
+pub const PAIRINGS: Map<(u128, &Addr, &Addr), Vec<Expiration>> = Map::new("pairings");
+/// Pairs that reached the category's flag threshold, stored by category and the sorted pair
+pub const FLAGGED_PAIRS: Map<(u128, &Addr, &Addr), FlaggedPair> = Map::new("flagged_pairs");
 pub const ENROLLMENT_MODULES: Map<&Addr, Empty> = Map::new("enrollment_modules");
 pub const PAYMENT_REGISTRY: Item<Addr> = Item::new("payment_registry");
+/// Each host's competition activity as reported by the competition modules
+pub const HOST_STATS: Map<&Addr, HostStats> = Map::new("host_stats");
+/// Hosts barred from creating competitions and enrollments, including expired bans until they are removed
+pub const HOST_DENYLIST: Map<&Addr, HostBan<Addr>> = Map::new("host_denylist");
+/// The code id and contract version of each key's competition module after every registration or upgrade, stored by key and height
+pub const MODULE_VERSIONS: Map<(&str, u64), ModuleVersion> = Map::new("module_versions");
+/// Escrow migrations still being applied to each key's competition module, along with the last competition id migrated
+pub const ESCROW_MIGRATIONS: Map<&str, (EscrowMigration, Option<Uint128>)> =
+    Map::new("escrow_migrations");
+/// The ratings migration still being applied, along with the last rating migrated
+pub const RATINGS_MIGRATION: Item<(RatingsMigration, Option<(u128, Addr)>)> =
+    Item::new("ratings_migration");
+/// Stores the competition module being upgraded in a reply
+pub const TEMP_MODULE_UPGRADE: Item<ModuleUpgrade> = Item::new("temp_module_upgrade");
+/// The jailed competition behind each jail proposal, stored by proposal id
+pub const JAIL_PROPOSALS: Map<u64, JailProposal> = Map::new("jail_proposals");
+
+/// A migration patch rewriting every rating, which is applied a page at a time
+#[cw_serde]
+pub enum RatingsMigration {
+    TaggedRatings {},
+    SignedRatings {},
+}
+
+#[cw_serde]
+pub struct ModuleUpgrade {
+    pub key: String,
+    pub escrow_migration: Option<EscrowMigration>,
+}
 
 // Competition Modules
 
@@ -86,6 +182,28 @@ pub fn competition_categories<'a>(
     IndexedMap::new("competition_categories", indexes)
 }
 
+/// The subcategories of each category, stored by parent and subcategory id
+pub const CATEGORY_CHILDREN: Map<(u128, u128), Empty> = Map::new("category_children");
+
+/// The category followed by its parent categories
+pub fn category_lineage(
+    storage: &dyn Storage,
+    category_id: u128,
+) -> StdResult<Vec<CompetitionCategory>> {
+    let mut lineage = vec![];
+    let mut next_id = Some(category_id);
+    while let Some(id) = next_id {
+        let category = competition_categories()
+            .may_load(storage, id)?
+            .ok_or_else(|| StdError::generic_err(format!("Could not find the category {}", id)))?;
+
+        next_id = category.parent_id.map(|x| x.u128());
+        lineage.push(category);
+    }
+
+    Ok(lineage)
+}
+
 // Rulesets
 
 pub fn get_rulesets_category_and_is_enabled_idx(category_id: Uint128, is_enabled: bool) -> String {
@@ -116,8 +234,21 @@ pub fn rulesets<'a>() -> IndexedMap<'a, u128, Ruleset, RulesetIndexes<'a>> {
 
 // Ratings
 
+/// The oldest rating period of the category with results left to apply, which has ended if it is not the open one
+pub fn oldest_pending_rating_period(
+    storage: &dyn Storage,
+    category_id: u128,
+) -> StdResult<Option<u64>> {
+    Ok(PENDING_RATING_RESULTS
+        .sub_prefix(category_id)
+        .keys(storage, None, None, Order::Ascending)
+        .next()
+        .transpose()?
+        .map(|(period_id, _)| period_id))
+}
+
 pub struct RatingIndexes<'a> {
-    pub rating: MultiIndex<'a, u128, Rating, (u128, &'a Addr)>, // We want to be able to sort by rating value
+    pub rating: MultiIndex<'a, (u128, u128), Rating, (u128, &'a Addr)>, // We want to be able to sort by rating value within a category
 }
 
 impl IndexList<Rating> for RatingIndexes<'_> {
@@ -127,11 +258,18 @@ impl IndexList<Rating> for RatingIndexes<'_> {
     }
 }
 
-// Ratings are stored by category id and address
+/// Reads the category id a rating is stored under from its primary key
+fn rating_category_id(pk: &[u8]) -> u128 {
+    <(u128, Addr)>::from_vec(pk.to_vec())
+        .expect("rating keys start with a category id")
+        .0
+}
+
+// Ratings are stored by category id and address, and indexed by category id and value
 pub fn ratings<'a>() -> IndexedMap<'a, (u128, &'a Addr), Rating, RatingIndexes<'a>> {
     let indexes = RatingIndexes {
         rating: MultiIndex::new(
-            |_x, d: &Rating| d.value.atomics().u128(),
+            |pk, d: &Rating| (rating_category_id(pk), rating_index_key(d.value)),
             "ratings",
             "ratings__rating",
         ),
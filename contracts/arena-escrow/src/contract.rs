@@ -68,6 +68,7 @@ pub fn execute(
 ) -> Result<Response, ContractError> {
     match msg {
         ExecuteMsg::ReceiveNative {} => execute::receive_native(deps, info),
+        ExecuteMsg::ReceiveNativeFor { addr } => execute::receive_native_for(deps, info, addr),
         ExecuteMsg::Withdraw {
             cw20_msg,
             cw721_msg,
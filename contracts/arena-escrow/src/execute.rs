@@ -121,6 +121,22 @@ pub fn receive_native(deps: DepsMut, info: MessageInfo) -> Result<Response, Cont
     receive_balance(deps, info.sender, balance)
 }
 
+// This function receives native tokens on behalf of a member and updates their balance
+pub fn receive_native_for(
+    deps: DepsMut,
+    info: MessageInfo,
+    addr: String,
+) -> Result<Response, ContractError> {
+    let addr = deps.api.addr_validate(&addr)?;
+    let balance = BalanceVerified {
+        native: Some(info.funds),
+        cw20: None,
+        cw721: None,
+    };
+
+    receive_balance(deps, addr, balance)
+}
+
 // This function receives CW20 tokens and updates the balance
 pub fn receive_cw20(
     deps: DepsMut,
@@ -252,7 +268,7 @@ pub fn distribute(
 
         // Process each fee
         for fee in validated_layered_fees {
-            let fee_amounts = total_balance.checked_mul_floor(fee.tax)?;
+            let fee_amounts = fee.fee_amounts(&total_balance)?;
 
             // Update total balance
             total_balance = TOTAL_BALANCE.update(deps.storage, |x| -> Result<_, BalanceError> {
@@ -57,6 +57,7 @@ pub fn execute(
             to_update,
             to_remove,
         } => execute::update_members(deps, env, info, to_add, to_update, to_remove),
+        ExecuteMsg::UpdateRosters { rosters } => execute::update_rosters(deps, info, rosters),
         ExecuteMsg::UpdateOwnership(action) => {
             let ownership = cw_ownable::update_ownership(deps, &env.block, &info.sender, action)?;
 
@@ -77,6 +78,7 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             to_json_binary(&query::is_valid_distribution(deps, addrs)?)
         }
         QueryMsg::IsMember { addr } => to_json_binary(&query::is_member(deps, addr)?),
+        QueryMsg::Rosters { addrs } => to_json_binary(&query::rosters(deps, addrs)?),
     }
 }
 
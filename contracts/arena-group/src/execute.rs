@@ -1,9 +1,11 @@
-use arena_interface::group::{AddMemberMsg, MemberMsg};
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdError, Uint64};
+use std::collections::HashSet;
+
+use arena_interface::group::{AddMemberMsg, MemberMsg, RosterMsg};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdError, StdResult, Uint64};
 use cw_ownable::assert_owner;
 
 use crate::{
-    state::{members, MEMBER_COUNT},
+    state::{members, MEMBER_COUNT, ROSTERS},
     ContractError,
 };
 
@@ -50,6 +52,7 @@ pub fn update_members(
             let addr = deps.api.addr_validate(&addr_str)?;
             if members().has(deps.storage, &addr) {
                 members().remove(deps.storage, &addr)?;
+                ROSTERS.remove(deps.storage, &addr);
                 member_count -= Uint64::one();
             } else {
                 return Err(ContractError::NotMember { member: addr });
@@ -63,3 +66,38 @@ pub fn update_members(
         .add_attribute("action", "update_members")
         .add_attribute("member_count", member_count.to_string()))
 }
+
+pub fn update_rosters(
+    deps: DepsMut,
+    info: MessageInfo,
+    rosters: Vec<RosterMsg<String>>,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+
+    for RosterMsg { addr, players } in rosters {
+        let addr = deps.api.addr_validate(&addr)?;
+        if !members().has(deps.storage, &addr) {
+            return Err(ContractError::NotMember { member: addr });
+        }
+
+        if players.is_empty() {
+            ROSTERS.remove(deps.storage, &addr);
+            continue;
+        }
+
+        let players = players
+            .iter()
+            .map(|x| deps.api.addr_validate(x))
+            .collect::<StdResult<Vec<_>>>()?;
+        let mut seen = HashSet::new();
+        if let Some(player) = players.iter().find(|x| !seen.insert(*x)) {
+            return Err(ContractError::DuplicateMembers {
+                member: player.clone(),
+            });
+        }
+
+        ROSTERS.save(deps.storage, &addr, &players)?;
+    }
+
+    Ok(Response::new().add_attribute("action", "update_rosters"))
+}
@@ -1,8 +1,8 @@
-use arena_interface::group::MemberMsg;
+use arena_interface::group::{MemberMsg, RosterMsg};
 use cosmwasm_std::{Addr, Deps, Order, StdResult};
 use cw_storage_plus::Bound;
 
-use crate::state::{members as members_map, MEMBER_COUNT};
+use crate::state::{members as members_map, MEMBER_COUNT, ROSTERS};
 
 pub fn members(
     deps: Deps,
@@ -51,3 +51,16 @@ pub fn is_valid_distribution(deps: Deps, addrs: Vec<String>) -> StdResult<bool>
 pub fn is_member(deps: Deps, addr: String) -> StdResult<bool> {
     Ok(members_map().has(deps.storage, &deps.api.addr_validate(&addr)?))
 }
+
+pub fn rosters(deps: Deps, addrs: Vec<String>) -> StdResult<Vec<RosterMsg<Addr>>> {
+    let mut rosters = vec![];
+    for addr in addrs {
+        let addr = deps.api.addr_validate(&addr)?;
+
+        if let Some(players) = ROSTERS.may_load(deps.storage, &addr)? {
+            rosters.push(RosterMsg { addr, players });
+        }
+    }
+
+    Ok(rosters)
+}
@@ -1,5 +1,5 @@
 use cosmwasm_std::{Addr, Uint64};
-use cw_storage_plus::{Index, IndexList, IndexedMap, Item, MultiIndex};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 
 pub struct MemberIndexes<'a> {
     pub seed: MultiIndex<'a, u64, Uint64, &'a Addr>,
@@ -13,6 +13,7 @@ impl IndexList<Uint64> for MemberIndexes<'_> {
 }
 
 pub const MEMBER_COUNT: Item<Uint64> = Item::new("member_count");
+pub const ROSTERS: Map<&Addr, Vec<Addr>> = Map::new("rosters");
 pub fn members<'a>() -> IndexedMap<'a, &'a Addr, Uint64, MemberIndexes<'a>> {
     let indexes = MemberIndexes {
         seed: MultiIndex::new(|_, d| d.u64(), "members", "members__seed"),
@@ -60,6 +60,7 @@ pub fn execute(
             expiration,
             rules,
             rulesets,
+            ruleset_versions,
             banner,
             instantiate_extension,
             group_contract,
@@ -76,6 +77,7 @@ pub fn execute(
                 expiration,
                 rules,
                 rulesets,
+                ruleset_versions,
                 banner,
                 group_contract,
                 instantiate_extension,
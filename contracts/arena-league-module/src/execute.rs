@@ -148,6 +148,7 @@ pub fn process_matches(
 
     let mut processed_matches = league.extension.processed_matches;
     let mut member_results = vec![];
+    let mut match_numbers = vec![];
 
     // Process each match result
     for match_result in match_results {
@@ -187,6 +188,7 @@ pub fn process_matches(
                                     result: member_result_2,
                                 },
                             ));
+                            match_numbers.push(m.match_number);
                         }
                     }
                     m.result = Some(match_result.match_result);
@@ -207,10 +209,13 @@ pub fn process_matches(
             &league.host,
             league.start_height,
         ) {
-            sub_msgs.push(CompetitionModule::default().trigger_rating_adjustment(
-                deps.storage,
+            sub_msgs.push(CompetitionModule::default().trigger_team_rating_adjustment(
+                deps.as_ref(),
+                &league.group_contract,
                 category_id,
+                league_id,
                 member_results,
+                Some(match_numbers),
             )?);
         }
     }
@@ -0,0 +1,135 @@
+use cosmwasm_std::{
+    entry_point, to_json_binary, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Reply,
+    Response, StdError, StdResult, WasmMsg,
+};
+use cw2::{ensure_from_older_version, set_contract_version};
+
+use crate::{
+    execute::{self, MATCH_REPLY_ID},
+    msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
+    query,
+    state::{CONFIG, TEMP_MATCH_STAKES},
+    ContractError,
+};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:arena-matchmaking-module";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let config = msg.config.into_checked(deps.as_ref())?;
+    CONFIG.save(deps.storage, &config)?;
+    let owner = deps.api.addr_validate(&msg.owner)?;
+    let ownership = cw_ownable::initialize_owner(deps.storage, deps.api, Some(owner.as_str()))?;
+
+    Ok(Response::new().add_attributes(ownership.into_attributes()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::UpdateOwnership(action) => {
+            let ownership = cw_ownable::update_ownership(deps, &env.block, &info.sender, action)?;
+            Ok(Response::new().add_attributes(ownership.into_attributes()))
+        }
+        ExecuteMsg::JoinQueue { category_id } => execute::join_queue(deps, env, info, category_id),
+        ExecuteMsg::LeaveQueue { category_id } => execute::leave_queue(deps, info, category_id),
+        ExecuteMsg::Match {
+            category_id,
+            start_after,
+        } => execute::match_players(deps, env, category_id, start_after),
+        ExecuteMsg::UpdateConfig { config } => execute::update_config(deps, info, config),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Ownership {} => to_json_binary(&cw_ownable::get_ownership(deps.storage)?),
+        QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Queue {
+            category_id,
+            start_after,
+            limit,
+        } => to_json_binary(&query::queue(deps, env, category_id, start_after, limit)?),
+        QueryMsg::QueueEntry { category_id, addr } => {
+            to_json_binary(&query::queue_entry(deps, env, category_id, addr)?)
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    let _version = ensure_from_older_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    match msg {
+        MigrateMsg::FromCompatible {} => {}
+    }
+
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::default())
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        MATCH_REPLY_ID => {
+            let (players, stake) = TEMP_MATCH_STAKES.load(deps.storage)?;
+            TEMP_MATCH_STAKES.remove(deps.storage);
+
+            let response = msg.result.into_result().map_err(StdError::generic_err)?;
+            let escrow_addr = response
+                .events
+                .iter()
+                .find(|x| {
+                    x.attributes
+                        .iter()
+                        .any(|y| y.key == "action" && y.value == "create_competition")
+                })
+                .and_then(|x| {
+                    x.attributes
+                        .iter()
+                        .find(|y| y.key == "escrow_addr")
+                        .map(|y| y.value.clone())
+                })
+                .ok_or(ContractError::StdError(StdError::generic_err(
+                    "Missing escrow_addr",
+                )))?;
+            let escrow_addr = deps.api.addr_validate(&escrow_addr)?;
+
+            // Pay each player's dues with their queued stake, so refunds go back to the players
+            let msgs = players
+                .into_iter()
+                .map(|player| -> StdResult<_> {
+                    Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+                        contract_addr: escrow_addr.to_string(),
+                        msg: to_json_binary(
+                            &arena_interface::escrow::ExecuteMsg::ReceiveNativeFor {
+                                addr: player.to_string(),
+                            },
+                        )?,
+                        funds: vec![stake.clone()],
+                    }))
+                })
+                .collect::<StdResult<Vec<_>>>()?;
+
+            Ok(Response::new()
+                .add_attribute("reply", "reply_match")
+                .add_attribute("escrow_addr", escrow_addr.to_string())
+                .add_messages(msgs))
+        }
+        _ => Err(ContractError::UnknownReplyId { id: msg.id }),
+    }
+}
@@ -0,0 +1,37 @@
+use cosmwasm_std::{OverflowError, StdError};
+use cw_ownable::OwnershipError;
+use cw_utils::{ParseReplyError, PaymentError};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+
+    #[error("{0}")]
+    OwnershipError(#[from] OwnershipError),
+
+    #[error("{0}")]
+    ParseReplyError(#[from] ParseReplyError),
+
+    #[error("{0}")]
+    OverflowError(#[from] OverflowError),
+
+    #[error("{0}")]
+    PaymentError(#[from] PaymentError),
+
+    #[error("UnknownReplyId")]
+    UnknownReplyId { id: u64 },
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Already in the queue")]
+    AlreadyQueued {},
+
+    #[error("Not in the queue")]
+    NotQueued {},
+
+    #[error("No queued players are within each other's rating window")]
+    NoMatchFound {},
+}
@@ -0,0 +1,327 @@
+use arena_interface::{
+    competition::msg::EscrowInstantiateInfo,
+    core::{
+        CategoryMetadata, CompetitionCategory, CompetitionModuleQuery, CompetitionModuleResponse,
+    },
+    group::{self, GroupContractInfo},
+    ratings::{rating_index_key, Rating},
+};
+use arena_wager_module::msg::WagerInstantiateExt;
+use cosmwasm_std::{
+    ensure, to_json_binary, Addr, BankMsg, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo,
+    Order, Response, SignedDecimal, StdError, StdResult, SubMsg, Uint128, WasmMsg,
+};
+use cw_balance::{BalanceUnchecked, MemberBalanceUnchecked};
+use cw_storage_plus::Bound;
+use cw_utils::one_coin;
+use dao_interface::state::ModuleInstantiateInfo;
+
+use crate::{
+    state::{
+        queue_entries, stake_index_key, MatchmakingConfig, QueueEntry, CONFIG, QUEUE_POSITIONS,
+        QUEUE_SEQUENCE, TEMP_MATCH_STAKES,
+    },
+    ContractError,
+};
+
+pub const MATCH_REPLY_ID: u64 = 1;
+
+fn get_arena_core(deps: Deps) -> Result<Addr, ContractError> {
+    cw_ownable::get_ownership(deps.storage)?
+        .owner
+        .ok_or(ContractError::OwnershipError(
+            cw_ownable::OwnershipError::NoOwner,
+        ))
+}
+
+pub fn join_queue(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    category_id: Uint128,
+) -> Result<Response, ContractError> {
+    ensure!(
+        !QUEUE_POSITIONS.has(deps.storage, (category_id.u128(), &info.sender)),
+        ContractError::AlreadyQueued {}
+    );
+
+    let stake = if info.funds.is_empty() {
+        None
+    } else {
+        Some(one_coin(&info)?)
+    };
+
+    let arena_core = get_arena_core(deps.as_ref())?;
+    let category = deps
+        .querier
+        .query_wasm_smart::<Option<CompetitionCategory>>(
+            &arena_core,
+            &arena_interface::core::QueryMsg::QueryExtension {
+                msg: arena_interface::core::QueryExt::Category { id: category_id },
+            },
+        )?
+        .ok_or(ContractError::StdError(StdError::generic_err(
+            "Could not find the category",
+        )))?;
+    ensure!(
+        category.is_enabled,
+        ContractError::StdError(StdError::generic_err(
+            "Cannot queue for a disabled category"
+        ))
+    );
+
+    if let Some(metadata) = deps.querier.query_wasm_smart::<Option<CategoryMetadata>>(
+        &arena_core,
+        &arena_interface::core::QueryMsg::QueryExtension {
+            msg: arena_interface::core::QueryExt::CategoryMetadata { category_id },
+        },
+    )? {
+        metadata.check_stake(stake.as_ref())?;
+    }
+
+    // Unrated players are queued with the category's initial rating
+    let rating = deps
+        .querier
+        .query_wasm_smart::<Option<Rating>>(
+            &arena_core,
+            &arena_interface::core::QueryMsg::QueryExtension {
+                msg: arena_interface::core::QueryExt::Rating {
+                    category_id,
+                    addr: info.sender.to_string(),
+                    height: None,
+                },
+            },
+        )?
+        .unwrap_or_else(|| category.rating_config.unwrap_or_default().initial_rating());
+
+    let position = QUEUE_SEQUENCE
+        .may_load(deps.storage, category_id.u128())?
+        .unwrap_or_default();
+    QUEUE_SEQUENCE.save(deps.storage, category_id.u128(), &(position + 1))?;
+    QUEUE_POSITIONS.save(deps.storage, (category_id.u128(), &info.sender), &position)?;
+    queue_entries().save(
+        deps.storage,
+        (category_id.u128(), position),
+        &QueueEntry {
+            addr: info.sender.clone(),
+            stake,
+            rating: rating.value,
+            height: env.block.height,
+            time: env.block.time,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "join_queue")
+        .add_attribute("category_id", category_id)
+        .add_attribute("addr", info.sender)
+        .add_attribute("rating", rating.value.to_string())
+        .add_attribute("position", position.to_string()))
+}
+
+pub fn leave_queue(
+    deps: DepsMut,
+    info: MessageInfo,
+    category_id: Uint128,
+) -> Result<Response, ContractError> {
+    let position = QUEUE_POSITIONS
+        .may_load(deps.storage, (category_id.u128(), &info.sender))?
+        .ok_or(ContractError::NotQueued {})?;
+    let entry = queue_entries().load(deps.storage, (category_id.u128(), position))?;
+
+    QUEUE_POSITIONS.remove(deps.storage, (category_id.u128(), &info.sender));
+    queue_entries().remove(deps.storage, (category_id.u128(), position))?;
+
+    let msgs = entry.stake.map(|stake| {
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![stake],
+        })
+    });
+
+    Ok(Response::new()
+        .add_attribute("action", "leave_queue")
+        .add_attribute("category_id", category_id)
+        .add_attribute("addr", info.sender)
+        .add_messages(msgs))
+}
+
+pub fn match_players(
+    deps: DepsMut,
+    env: Env,
+    category_id: Uint128,
+    start_after: Option<u64>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // A page of the longest queued players is considered to bound the gas used
+    let entries = queue_entries()
+        .prefix(category_id.u128())
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(30)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    // Both players must accept the rating difference and have staked the same amount
+    let mut matched = None;
+    for (position, entry) in &entries {
+        let window = SignedDecimal::try_from(config.rating_window(&env.block, entry))
+            .unwrap_or(SignedDecimal::MAX);
+        let lowest = rating_index_key(entry.rating.saturating_sub(window));
+        let highest = rating_index_key(entry.rating.saturating_add(window));
+
+        // Only a page of the players within the window is checked against their own window
+        let other = queue_entries()
+            .idx
+            .stake_rating
+            .sub_prefix((category_id.u128(), stake_index_key(&entry.stake)))
+            .range(
+                deps.storage,
+                Some(Bound::inclusive((lowest, (category_id.u128(), u64::MIN)))),
+                Some(Bound::inclusive((highest, (category_id.u128(), u64::MAX)))),
+                Order::Ascending,
+            )
+            .take(30)
+            .find(|x| {
+                x.as_ref().map_or(true, |((_, other_position), other)| {
+                    other_position != position
+                        && entry.rating.abs_diff(other.rating)
+                            <= config.rating_window(&env.block, other)
+                })
+            })
+            .transpose()?;
+        if let Some(((_, other_position), other)) = other {
+            matched = Some(((*position, entry.clone()), (other_position, other)));
+            break;
+        }
+    }
+    let (first, second) = matched.ok_or(ContractError::NoMatchFound {})?;
+
+    for (position, entry) in [&first, &second] {
+        queue_entries().remove(deps.storage, (category_id.u128(), *position))?;
+        QUEUE_POSITIONS.remove(deps.storage, (category_id.u128(), &entry.addr));
+    }
+    let players = [first.1.addr.clone(), second.1.addr.clone()];
+
+    let arena_core = get_arena_core(deps.as_ref())?;
+    let wager_module = deps
+        .querier
+        .query_wasm_smart::<Option<CompetitionModuleResponse<Addr>>>(
+            arena_core,
+            &arena_interface::core::QueryMsg::QueryExtension {
+                msg: arena_interface::core::QueryExt::CompetitionModule {
+                    query: CompetitionModuleQuery::Key("Wagers".to_string(), None),
+                },
+            },
+        )?
+        .ok_or(ContractError::StdError(StdError::generic_err(
+            "Could not find the competition module",
+        )))?;
+    ensure!(
+        wager_module.is_enabled,
+        ContractError::StdError(StdError::generic_err(
+            "Cannot use a disabled competition module"
+        ))
+    );
+
+    // Each player owes the escrow their stake, which this contract pays for them once it exists
+    let stake = first.1.stake.clone();
+    let escrow = stake
+        .as_ref()
+        .map(|stake| -> StdResult<_> {
+            Ok(EscrowInstantiateInfo {
+                code_id: config.escrow_id,
+                msg: to_json_binary(&arena_interface::escrow::InstantiateMsg {
+                    dues: players
+                        .iter()
+                        .map(|player| MemberBalanceUnchecked {
+                            addr: player.to_string(),
+                            balance: BalanceUnchecked {
+                                native: Some(vec![stake.clone()]),
+                                cw20: None,
+                                cw721: None,
+                            },
+                        })
+                        .collect(),
+                })?,
+                label: "Arena Escrow".to_string(),
+                additional_layered_fees: None,
+            })
+        })
+        .transpose()?;
+
+    let msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: wager_module.addr.to_string(),
+        msg: to_json_binary(&arena_wager_module::msg::ExecuteMsg::CreateCompetition {
+            host: config.host.as_ref().map(|host| host.to_string()),
+            category_id: Some(category_id),
+            escrow,
+            name: "Matchmaking Wager".to_string(),
+            description: format!("A rated wager between {} and {}", players[0], players[1]),
+            expiration: config.wager_duration.after(&env.block),
+            rules: None,
+            rulesets: None,
+            ruleset_versions: None,
+            banner: None,
+            group_contract: GroupContractInfo::New {
+                info: ModuleInstantiateInfo {
+                    code_id: config.group_id,
+                    msg: to_json_binary(&group::InstantiateMsg {
+                        members: Some(
+                            players
+                                .iter()
+                                .map(|player| group::AddMemberMsg {
+                                    addr: player.to_string(),
+                                    seed: None,
+                                })
+                                .collect(),
+                        ),
+                    })?,
+                    admin: None,
+                    funds: vec![],
+                    label: "Arena Group".to_string(),
+                },
+            },
+            instantiate_extension: WagerInstantiateExt {},
+        })?,
+        funds: vec![],
+    });
+
+    let response = Response::new()
+        .add_attribute("action", "match")
+        .add_attribute("category_id", category_id)
+        .add_attribute("player1", players[0].to_string())
+        .add_attribute("player2", players[1].to_string());
+
+    Ok(match stake {
+        Some(stake) => {
+            TEMP_MATCH_STAKES.save(deps.storage, &(players.to_vec(), stake.clone()))?;
+
+            response
+                .add_attribute("stake", stake.to_string())
+                .add_submessage(SubMsg::reply_on_success(msg, MATCH_REPLY_ID))
+        }
+        None => response.add_message(msg),
+    })
+}
+
+pub fn update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    config: MatchmakingConfig<String>,
+) -> Result<Response, ContractError> {
+    let dao: Addr = deps.querier.query_wasm_smart(
+        get_arena_core(deps.as_ref())?,
+        &dao_pre_propose_base::msg::QueryMsg::<Empty>::Dao {},
+    )?;
+    ensure!(info.sender == dao, ContractError::Unauthorized {});
+
+    let config = config.into_checked(deps.as_ref())?;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attribute("action", "update_config"))
+}
@@ -0,0 +1,51 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Uint128;
+
+use crate::state::{MatchmakingConfig, QueueEntryResponse};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub owner: String,
+    pub config: MatchmakingConfig<String>,
+}
+
+#[cw_ownable::cw_ownable_execute]
+#[cw_serde]
+#[derive(cw_orch::ExecuteFns)]
+pub enum ExecuteMsg {
+    /// Joins the category's queue, where any funds sent are staked on the matched wager
+    #[cw_orch(payable)]
+    JoinQueue { category_id: Uint128 },
+    /// Leaves the category's queue and refunds the stake
+    LeaveQueue { category_id: Uint128 },
+    /// Creates a wager for the longest queued player with another player within each other's rating window
+    /// Only a page of the queue is considered, starting after the given queue position
+    #[cw_orch(fn_name("match_players"))]
+    Match {
+        category_id: Uint128,
+        start_after: Option<u64>,
+    },
+    /// Updates the matchmaking config, which can only be done by the DAO
+    UpdateConfig { config: MatchmakingConfig<String> },
+}
+
+#[cw_ownable::cw_ownable_query]
+#[cw_serde]
+#[derive(QueryResponses, cw_orch::QueryFns)]
+pub enum QueryMsg {
+    #[returns(MatchmakingConfig<cosmwasm_std::Addr>)]
+    Config {},
+    #[returns(Vec<QueueEntryResponse>)]
+    Queue {
+        category_id: Uint128,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(Option<QueueEntryResponse>)]
+    QueueEntry { category_id: Uint128, addr: String },
+}
+
+#[cw_serde]
+pub enum MigrateMsg {
+    FromCompatible {},
+}
@@ -0,0 +1,42 @@
+use cosmwasm_std::{Deps, Env, Order, StdResult, Uint128};
+use cw_storage_plus::Bound;
+
+use crate::state::{queue_entries, QueueEntryResponse, CONFIG, QUEUE_POSITIONS};
+
+pub fn queue(
+    deps: Deps,
+    env: Env,
+    category_id: Uint128,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<QueueEntryResponse>> {
+    let config = CONFIG.load(deps.storage)?;
+    let start_after_bound = start_after.map(Bound::exclusive);
+    let limit = limit.unwrap_or(30).min(30);
+
+    queue_entries()
+        .prefix(category_id.u128())
+        .range(deps.storage, start_after_bound, None, Order::Ascending)
+        .map(|x| x.map(|(position, entry)| entry.into_response(&config, &env.block, position)))
+        .take(limit as usize)
+        .collect()
+}
+
+pub fn queue_entry(
+    deps: Deps,
+    env: Env,
+    category_id: Uint128,
+    addr: String,
+) -> StdResult<Option<QueueEntryResponse>> {
+    let addr = deps.api.addr_validate(&addr)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    QUEUE_POSITIONS
+        .may_load(deps.storage, (category_id.u128(), &addr))?
+        .map(|position| {
+            queue_entries()
+                .load(deps.storage, (category_id.u128(), position))
+                .map(|entry| entry.into_response(&config, &env.block, position))
+        })
+        .transpose()
+}
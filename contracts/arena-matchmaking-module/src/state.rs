@@ -0,0 +1,179 @@
+use arena_interface::ratings::rating_index_key;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    ensure, Addr, BlockInfo, Coin, Decimal, Deps, SignedDecimal, StdError, StdResult, Timestamp,
+};
+use cw_address_like::AddressLike;
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, KeyDeserialize, Map, MultiIndex};
+use cw_utils::Duration;
+
+#[cw_serde]
+pub struct MatchmakingConfig<T: AddressLike> {
+    /// The code id of the escrow holding the stakes of each matched wager
+    pub escrow_id: u64,
+    /// The code id of the group contract holding each matched wager's players
+    pub group_id: u64,
+    /// The host of matched wagers, which requires this module to be a valid enrollment module
+    /// Defaults to this contract, leaving results to the DAO
+    pub host: Option<T>,
+    /// The rating difference accepted for a player who has just joined the queue
+    pub rating_window: Decimal,
+    /// The amount the rating window widens by each interval a player has been queued
+    pub window_increase: Decimal,
+    pub window_interval: Duration,
+    /// The widest the rating window can get
+    pub max_window: Option<Decimal>,
+    /// How long matched wagers remain open
+    pub wager_duration: Duration,
+}
+
+impl MatchmakingConfig<String> {
+    pub fn into_checked(self, deps: Deps) -> StdResult<MatchmakingConfig<Addr>> {
+        let interval = match self.window_interval {
+            Duration::Height(height) => height,
+            Duration::Time(seconds) => seconds,
+        };
+        ensure!(
+            interval != 0,
+            StdError::generic_err("Cannot have a window interval of 0")
+        );
+        let duration = match self.wager_duration {
+            Duration::Height(height) => height,
+            Duration::Time(seconds) => seconds,
+        };
+        ensure!(
+            duration != 0,
+            StdError::generic_err("Cannot have a wager duration of 0")
+        );
+        if let Some(max_window) = self.max_window {
+            ensure!(
+                max_window >= self.rating_window,
+                StdError::generic_err("The max window cannot be lower than the rating window")
+            );
+        }
+
+        Ok(MatchmakingConfig {
+            escrow_id: self.escrow_id,
+            group_id: self.group_id,
+            host: self
+                .host
+                .map(|host| deps.api.addr_validate(&host))
+                .transpose()?,
+            rating_window: self.rating_window,
+            window_increase: self.window_increase,
+            window_interval: self.window_interval,
+            max_window: self.max_window,
+            wager_duration: self.wager_duration,
+        })
+    }
+}
+
+impl MatchmakingConfig<Addr> {
+    /// The rating difference accepted for a queued player at the given block
+    pub fn rating_window(&self, block: &BlockInfo, entry: &QueueEntry) -> Decimal {
+        let intervals = match self.window_interval {
+            Duration::Height(height) => block.height.saturating_sub(entry.height) / height,
+            Duration::Time(seconds) => {
+                block.time.seconds().saturating_sub(entry.time.seconds()) / seconds
+            }
+        };
+        let window = self.rating_window.saturating_add(
+            self.window_increase
+                .saturating_mul(Decimal::from_ratio(intervals, 1u64)),
+        );
+
+        match self.max_window {
+            Some(max_window) => window.min(max_window),
+            None => window,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct QueueEntry {
+    pub addr: Addr,
+    pub stake: Option<Coin>,
+    /// The player's rating in the category when they joined the queue
+    pub rating: SignedDecimal,
+    pub height: u64,
+    pub time: Timestamp,
+}
+
+#[cw_serde]
+pub struct QueueEntryResponse {
+    pub position: u64,
+    pub addr: Addr,
+    pub stake: Option<Coin>,
+    pub rating: SignedDecimal,
+    pub height: u64,
+    pub time: Timestamp,
+    /// The rating difference currently accepted for the player
+    pub rating_window: Decimal,
+}
+
+impl QueueEntry {
+    pub fn into_response(
+        self,
+        config: &MatchmakingConfig<Addr>,
+        block: &BlockInfo,
+        position: u64,
+    ) -> QueueEntryResponse {
+        QueueEntryResponse {
+            position,
+            rating_window: config.rating_window(block, &self),
+            addr: self.addr,
+            stake: self.stake,
+            rating: self.rating,
+            height: self.height,
+            time: self.time,
+        }
+    }
+}
+
+pub const CONFIG: Item<MatchmakingConfig<Addr>> = Item::new("config");
+pub struct QueueIndexes<'a> {
+    pub stake_rating: MultiIndex<'a, (u128, String, u128), QueueEntry, (u128, u64)>, // We want to find players with the same stake by rating
+}
+
+impl IndexList<QueueEntry> for QueueIndexes<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<QueueEntry>> + '_> {
+        let v: Vec<&dyn Index<QueueEntry>> = vec![&self.stake_rating];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Maps a stake to an index key, where players without a stake share the empty key
+pub fn stake_index_key(stake: &Option<Coin>) -> String {
+    stake.as_ref().map(|x| x.to_string()).unwrap_or_default()
+}
+
+/// Reads the category id an entry is queued in from its primary key
+fn queue_category_id(pk: &[u8]) -> u128 {
+    <(u128, u64)>::from_vec(pk.to_vec())
+        .expect("queue keys start with a category id")
+        .0
+}
+
+/// Stores each category's queued players in FIFO order by their queue position
+pub fn queue_entries<'a>() -> IndexedMap<'a, (u128, u64), QueueEntry, QueueIndexes<'a>> {
+    let indexes = QueueIndexes {
+        stake_rating: MultiIndex::new(
+            |pk, d: &QueueEntry| {
+                (
+                    queue_category_id(pk),
+                    stake_index_key(&d.stake),
+                    rating_index_key(d.rating),
+                )
+            },
+            "queue",
+            "queue__stake_rating",
+        ),
+    };
+    IndexedMap::new("queue", indexes)
+}
+/// Stores the queue position of each queued player
+pub const QUEUE_POSITIONS: Map<(u128, &Addr), u64> = Map::new("queue_positions");
+/// Stores the next queue position to assign for a category's queue
+pub const QUEUE_SEQUENCE: Map<u128, u64> = Map::new("queue_sequence");
+/// Stores the matched players and their stake to fund the escrow of the wager being created in a reply
+pub const TEMP_MATCH_STAKES: Item<(Vec<Addr>, Coin)> = Item::new("temp_match_stakes");
@@ -453,6 +453,7 @@ pub fn process_matches(
 
     // Process each match result
     let mut member_results = vec![];
+    let mut match_numbers = vec![];
     for result in match_results {
         let mut match_ = MATCHES.update(
             deps.storage,
@@ -500,6 +501,7 @@ pub fn process_matches(
                             result: member_result_2,
                         },
                     ));
+                    match_numbers.push(match_info.match_number);
                 }
 
                 match_info.result = Some(result.match_result.clone());
@@ -588,10 +590,13 @@ pub fn process_matches(
             &tournament.host,
             tournament.start_height,
         ) {
-            sub_msgs.push(CompetitionModule::default().trigger_rating_adjustment(
-                deps.storage,
+            sub_msgs.push(CompetitionModule::default().trigger_team_rating_adjustment(
+                deps.as_ref(),
+                &tournament.group_contract,
                 category_id,
+                tournament_id,
                 member_results,
+                Some(match_numbers),
             )?);
         }
     }
@@ -131,7 +131,9 @@ fn post_processing(deps: DepsMut, competition: &Wager) -> Result<Option<SubMsg>,
                         Some(CompetitionModule::default().trigger_rating_adjustment(
                             deps.storage,
                             category_id,
+                            competition.id,
                             vec![(member_result1, member_result2)],
+                            None,
                         )?)
                     } else {
                         None
@@ -141,6 +143,7 @@ fn post_processing(deps: DepsMut, competition: &Wager) -> Result<Option<SubMsg>,
                 None => Some(CompetitionModule::default().trigger_rating_adjustment(
                     deps.storage,
                     category_id,
+                    competition.id,
                     vec![(
                         MemberResult {
                             addr: registered_members[0].clone(),
@@ -151,6 +154,7 @@ fn post_processing(deps: DepsMut, competition: &Wager) -> Result<Option<SubMsg>,
                             result: Decimal::percent(50),
                         },
                     )],
+                    None,
                 )?),
             });
         }
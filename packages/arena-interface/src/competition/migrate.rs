@@ -45,6 +45,7 @@ impl<T> CompetitionV2<T> {
             start_height: self.start_height,
             expiration: self.expiration,
             rulesets: self.rulesets,
+            ruleset_versions: None,
             status: self.status,
             extension: self.extension.into_competition_ext(),
             fees: self.fees,
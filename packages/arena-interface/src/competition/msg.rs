@@ -1,13 +1,16 @@
 use std::marker::PhantomData;
 
 #[allow(unused_imports)]
-use crate::competition::state::{CompetitionResponse, CompetitionStatus, Config, Evidence};
+use crate::competition::state::{
+    CompetitionResponse, CompetitionStatus, Config, Evidence, HostResult,
+};
 use crate::{
+    core::{JailBond, RulesetVersion},
     fees::FeeInformation,
     group::{self},
 };
 use cosmwasm_schema::{cw_serde, schemars::JsonSchema, QueryResponses};
-use cosmwasm_std::{Addr, Binary, Deps, StdResult, Uint128};
+use cosmwasm_std::{Addr, Binary, Coin, Deps, StdResult, Uint128};
 use cw_balance::Distribution;
 use cw_ownable::{cw_ownable_execute, cw_ownable_query};
 use cw_utils::Expiration;
@@ -28,6 +31,8 @@ pub struct InstantiateBase<InstantiateExt> {
 #[allow(clippy::large_enum_variant)]
 #[derive(cw_orch::ExecuteFns)]
 pub enum ExecuteBase<ExecuteExt, CompetitionInstantiateExt> {
+    /// Jails an expired competition for the DAO to resolve
+    /// The competition's category can require a jail bond, which is taken from the funds before the rest is sent as the proposal deposit
     #[cw_orch(payable)]
     JailCompetition {
         competition_id: Uint128,
@@ -58,6 +63,9 @@ pub enum ExecuteBase<ExecuteExt, CompetitionInstantiateExt> {
         expiration: Expiration,
         rules: Option<Vec<String>>,
         rulesets: Option<Vec<Uint128>>,
+        /// Pins each ruleset to a version or its latest edits, in the same order as the rulesets
+        /// Defaults to the rulesets' versions at creation
+        ruleset_versions: Option<Vec<RulesetVersion>>,
         banner: Option<String>,
         group_contract: group::GroupContractInfo,
         instantiate_extension: CompetitionInstantiateExt,
@@ -66,10 +74,25 @@ pub enum ExecuteBase<ExecuteExt, CompetitionInstantiateExt> {
         competition_id: Uint128,
         evidence: Vec<String>,
     },
+    /// Records the host's result for an active competition without processing it
+    /// Jail bonds are slashed if the DAO resolves the competition with the host's last submitted result
+    SubmitResult {
+        competition_id: Uint128,
+        distribution: Option<Distribution<String>>,
+    },
     ProcessCompetition {
         competition_id: Uint128,
         distribution: Option<Distribution<String>>,
     },
+    /// Settles the bond of a jail proposal that completed without being executed
+    /// The bond is slashed if the DAO rejected the proposal while the host has a result, and refunded otherwise
+    /// This can only be called by arena-core
+    ReleaseJailBond {
+        competition_id: Uint128,
+        originator: String,
+        amount: Coin,
+        is_rejected: bool,
+    },
     Extension {
         msg: ExecuteExt,
     },
@@ -122,6 +145,13 @@ where
     },
     #[returns(Option<Distribution<String>>)]
     Result { competition_id: Uint128 },
+    #[returns(Option<HostResult>)]
+    HostResult { competition_id: Uint128 },
+    #[returns(Option<JailBond>)]
+    JailBond {
+        competition_id: Uint128,
+        addr: String,
+    },
     #[returns(cosmwasm_std::Binary)]
     QueryExtension { msg: QueryExt },
     #[returns(Option<String>)]
@@ -1,6 +1,7 @@
-use crate::fees::FeeInformation;
+use crate::{core::RulesetVersion, fees::FeeInformation};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, BlockInfo, Timestamp, Uint128};
+use cw_balance::Distribution;
 use cw_utils::Expiration;
 use std::fmt;
 
@@ -45,6 +46,9 @@ pub struct Competition<CompetitionExt> {
     pub start_height: u64,
     pub expiration: Expiration,
     pub rulesets: Option<Vec<Uint128>>,
+    /// The version each ruleset is pinned to, in the same order as the rulesets
+    /// None for competitions created before ruleset versioning, which follow the latest versions
+    pub ruleset_versions: Option<Vec<RulesetVersion>>,
     pub status: CompetitionStatus,
     pub extension: CompetitionExt,
     /// Additional layered fees
@@ -66,6 +70,7 @@ pub struct TempCompetition<CompetitionInstantiateExt> {
     pub start_height: u64,
     pub expiration: Expiration,
     pub rulesets: Option<Vec<Uint128>>,
+    pub ruleset_versions: Option<Vec<RulesetVersion>>,
     pub status: CompetitionStatus,
     /// Additional layered fees
     pub fees: Option<Vec<FeeInformation<Addr>>>,
@@ -87,6 +92,7 @@ pub struct CompetitionResponse<CompetitionExt> {
     pub is_expired: bool,
     pub rules: Option<Vec<String>>,
     pub rulesets: Option<Vec<Uint128>>,
+    pub ruleset_versions: Option<Vec<RulesetVersion>>,
     pub status: CompetitionStatus,
     pub extension: CompetitionExt,
     pub expiration: Expiration,
@@ -114,6 +120,7 @@ impl<CompetitionExt> Competition<CompetitionExt> {
             is_expired,
             rules,
             rulesets: self.rulesets,
+            ruleset_versions: self.ruleset_versions,
             status: self.status,
             extension: self.extension,
             expiration: self.expiration,
@@ -131,6 +138,12 @@ pub struct Config<InstantiateExt> {
     pub extension: InstantiateExt,
 }
 
+#[cw_serde]
+pub struct HostResult {
+    pub distribution: Option<Distribution<Addr>>,
+    pub height: u64,
+}
+
 #[cw_serde]
 pub struct Evidence {
     pub id: Uint128,
@@ -1,8 +1,10 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Binary, Decimal, Uint128};
+use cosmwasm_std::{
+    ensure, Addr, Binary, Coin, Decimal, SignedDecimal, StdError, StdResult, Uint128,
+};
 use cw_address_like::AddressLike;
-use cw_balance::Distribution;
-use cw_utils::Duration;
+use cw_balance::{Distribution, MemberBalanceChecked};
+use cw_utils::{Duration, Expiration};
 use dao_interface::state::ModuleInstantiateInfo;
 use dao_pre_propose_base::{
     msg::{
@@ -14,8 +16,13 @@ use dao_pre_propose_base::{
 use dao_voting::proposal::SingleChoiceProposeMsg;
 
 use crate::{
-    fees::TaxConfiguration,
-    ratings::{MemberResult, Rating},
+    competition::{state::HostResult, stats::StatTableEntry},
+    fees::{TaxConfiguration, TaxTier},
+    hosts::{HostActivity, HostBan, HostStatsResponse},
+    ratings::{
+        FlaggedPair, MemberResult, PendingRatingResult, RatedMatch, Rating, RatingConfig,
+        RatingSnapshot, RatingSystem, Season, SeasonReward, SeasonStanding, SoftReset,
+    },
 };
 
 #[cw_serde]
@@ -36,12 +43,34 @@ pub enum ExecuteExt {
         to_add: Option<Vec<ModuleInstantiateInfo>>,
         to_disable: Option<Vec<String>>,
     },
+    /// Migrates the competition module registered under the key in place, keeping its live competitions
+    /// The arena core must be the module's admin
+    /// The escrows of its active competitions are migrated a page at a time, starting with the upgrade
+    UpgradeCompetitionModule {
+        key: String,
+        code_id: u64,
+        migrate_msg: Binary,
+        escrow_migration: Option<EscrowMigration>,
+    },
     UpdateTax {
         tax: Decimal,
     },
+    /// Sets the Arena tax overrides and volume tiers, where an override of None is removed
+    /// Category overrides take precedence over competition module overrides, which are set by module key
+    UpdateTaxSchedule {
+        category_taxes: Option<Vec<(Uint128, Option<Decimal>)>>,
+        module_taxes: Option<Vec<(String, Option<Decimal>)>>,
+        volume_tiers: Option<Vec<TaxTier>>,
+    },
+    /// Sets the highest combined rate the Arena tax and a competition's additional fees can take, where None removes it
+    UpdateFeeCeiling {
+        fee_ceiling: Option<Decimal>,
+    },
     UpdateRulesets {
         to_add: Option<Vec<NewRuleset>>,
         to_disable: Option<Vec<Uint128>>,
+        /// Each edit creates a new version of the ruleset, keeping its earlier versions
+        to_edit: Option<Vec<EditRuleset>>,
     },
     UpdateCategories {
         to_add: Option<Vec<NewCompetitionCategory>>,
@@ -50,10 +79,59 @@ pub enum ExecuteExt {
     AdjustRatings {
         category_id: Uint128,
         member_results: Vec<(MemberResult<String>, MemberResult<String>)>,
+        /// The competition the results come from, which allows reverting them later
+        competition_id: Option<Uint128>,
+        /// The match number of each member result, in the same order
+        match_numbers: Option<Vec<Uint128>>,
+    },
+    /// Rates N-way placements between teams, where every member is rated against the opposing teams
+    AdjustTeamRatings {
+        category_id: Uint128,
+        matches: Vec<RatedMatch<String>>,
+        competition_id: Option<Uint128>,
+    },
+    /// Reverses the rating value changes caused by a competition and drops its buffered results
+    /// Only rating values are restored, so the revert is approximate for deviations and volatilities
+    /// Up to `limit` entries are reverted per call; the DAO starts a revert and anyone can continue it
+    RevertRatingAdjustments {
+        module: String,
+        competition_id: Uint128,
+        limit: Option<u32>,
     },
     UpdateRatingPeriod {
         period: Duration,
     },
+    /// Applies a page of the buffered results of a category's oldest ended rating period
+    /// The period is closed once every member's results are applied
+    CloseRatingPeriod {
+        category_id: Uint128,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Ends a category's current season by archiving its leaderboard and soft resetting its ratings
+    /// Any funds sent are the reward pool, which is split between the top of the final leaderboard
+    #[cw_orch(payable)]
+    StartSeason {
+        category_id: Uint128,
+        soft_reset: Option<SoftReset>,
+        reward: Option<SeasonReward>,
+    },
+    /// Soft resets the next page of a category's ratings when the reset did not finish with its season start
+    /// Rating periods of the category cannot be closed until the reset is complete
+    ContinueSoftReset {
+        category_id: Uint128,
+        limit: Option<u32>,
+    },
+    /// Migrates the escrows of the next page of competitions of a module upgraded under the key
+    ContinueEscrowMigration {
+        key: String,
+        limit: Option<u32>,
+    },
+    /// Migrates the next page of ratings when a ratings migration patch did not finish with the contract migration
+    /// Ratings cannot be updated until the migration is complete
+    ContinueRatingsMigration {
+        limit: Option<u32>,
+    },
     UpdateEnrollmentModules {
         to_add: Option<Vec<String>>,
         to_remove: Option<Vec<String>>,
@@ -61,6 +139,30 @@ pub enum ExecuteExt {
     SetPaymentRegistry {
         addr: String,
     },
+    /// Bans hosts from creating competitions and enrollments, where an existing ban is replaced
+    UpdateHostDenylist {
+        to_add: Option<Vec<HostBan<String>>>,
+        to_remove: Option<Vec<String>>,
+    },
+    /// Records a host's competition activity, which is sent by the competition modules
+    RecordHostActivity {
+        host: String,
+        activity: HostActivity,
+    },
+    /// Submits a DAO proposal to add the ruleset, which anyone can do by paying the proposal deposit
+    #[cw_orch(payable)]
+    ProposeRuleset {
+        title: String,
+        description: String,
+        ruleset: NewRuleset,
+    },
+    /// Submits a DAO proposal to add the category, which anyone can do by paying the proposal deposit
+    #[cw_orch(payable)]
+    ProposeCategory {
+        title: String,
+        description: String,
+        category: NewCompetitionCategory,
+    },
 }
 
 impl From<ExecuteExt> for ExecuteMsg {
@@ -78,8 +180,18 @@ pub enum QueryExt {
         limit: Option<u32>,
         include_disabled: Option<bool>,
     },
+    /// The latest version of the ruleset
     #[returns(Ruleset)]
     Ruleset { id: Uint128 },
+    #[returns(Option<Ruleset>)]
+    RulesetVersion { id: Uint128, version: u64 },
+    /// Every version of the ruleset, from oldest to newest
+    #[returns(Vec<Ruleset>)]
+    RulesetHistory {
+        id: Uint128,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
     #[returns(Vec<Ruleset>)]
     Rulesets {
         category_id: Uint128,
@@ -91,6 +203,13 @@ pub enum QueryExt {
     Tax { height: Option<u64> },
     #[returns(Option<CompetitionModuleResponse<Addr>>)]
     CompetitionModule { query: CompetitionModuleQuery },
+    /// The code id and contract version history of the modules registered under a key
+    #[returns(Vec<ModuleVersion>)]
+    ModuleVersions {
+        key: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
     #[returns(CompetitionCategory)]
     Category { id: Uint128 },
     #[returns(Vec<CompetitionCategory>)]
@@ -99,6 +218,20 @@ pub enum QueryExt {
         limit: Option<u32>,
         include_disabled: Option<bool>,
     },
+    /// The category's direct subcategories
+    #[returns(Vec<CompetitionCategory>)]
+    Subcategories {
+        category_id: Uint128,
+        start_after: Option<Uint128>,
+        limit: Option<u32>,
+    },
+    /// The category and its parent categories, from the top-level category down
+    #[returns(Vec<CompetitionCategory>)]
+    CategoryPath { category_id: Uint128 },
+    /// The category's metadata with unset fields inherited from its parent categories
+    #[returns(Option<CategoryMetadata>)]
+    CategoryMetadata { category_id: Uint128 },
+    /// Rulesets of the category's parent categories are valid for the category
     #[returns(bool)]
     IsValidCategoryAndRulesets {
         category_id: Uint128,
@@ -109,20 +242,91 @@ pub enum QueryExt {
     #[returns(DumpStateResponse)]
     DumpState {},
     /// This query is used to get a competition's fee configuration for the Arena tax at its start height
+    /// The tax resolves to the category's override, then the competition module's override by key, then the global tax
     #[returns(TaxConfigurationResponse)]
-    TaxConfig { height: u64 },
+    TaxConfig {
+        height: u64,
+        category_id: Option<Uint128>,
+        module: Option<String>,
+    },
+    #[returns(Option<Decimal>)]
+    FeeCeiling {},
+    /// The member's rating as of the given height, defaulting to the current rating
     #[returns(Option<Rating>)]
-    Rating { category_id: Uint128, addr: String },
+    Rating {
+        category_id: Uint128,
+        addr: String,
+        height: Option<u64>,
+    },
+    /// Every change of the member's rating, from oldest to newest
+    #[returns(Vec<RatingSnapshot>)]
+    RatingHistory {
+        category_id: Uint128,
+        addr: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
     #[returns(Vec<RatingResponse>)]
     RatingLeaderboard {
         category_id: Uint128,
-        start_after: Option<(Uint128, String)>,
+        start_after: Option<(SignedDecimal, String)>,
         limit: Option<u32>,
     },
     #[returns(Option<Duration>)]
     RatingPeriod {},
+    #[returns(Option<Expiration>)]
+    RatingPeriodEnd { category_id: Uint128 },
+    /// Results buffered for a member in the category's rating periods that are not closed yet
+    #[returns(Vec<PendingRatingResult>)]
+    PendingRatingResults { category_id: Uint128, addr: String },
     #[returns(Addr)]
     PaymentRegistry {},
+    #[returns(Option<Season>)]
+    Season {
+        category_id: Uint128,
+        season_id: u64,
+    },
+    /// A category's ended seasons, from oldest to newest
+    #[returns(Vec<Season>)]
+    Seasons {
+        category_id: Uint128,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// The final leaderboard of an ended season, ordered by rank
+    #[returns(Vec<SeasonStanding>)]
+    SeasonLeaderboard {
+        category_id: Uint128,
+        season_id: u64,
+        start_after: Option<u32>,
+        limit: Option<u32>,
+    },
+    /// Pairs of members who faced each other often enough to be reviewed for rating farming
+    #[returns(Vec<FlaggedPair>)]
+    FlaggedPairs {
+        category_id: Uint128,
+        start_after: Option<(String, String)>,
+        limit: Option<u32>,
+    },
+    #[returns(Option<HostStatsResponse>)]
+    HostStats { host: String },
+    #[returns(Vec<HostStatsResponse>)]
+    HostStatsList {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// The host's ban if it has not expired
+    #[returns(Option<HostBan<Addr>>)]
+    HostBan { host: String },
+    /// The bans that have not expired
+    #[returns(Vec<HostBan<Addr>>)]
+    HostDenylist {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// The competition snapshot attached to a jail proposal
+    #[returns(Option<JailProposal>)]
+    JailProposal { proposal_id: u64 },
 }
 
 impl From<QueryExt> for QueryMsg {
@@ -178,12 +382,43 @@ pub struct NewRuleset {
 #[cw_serde]
 pub struct NewCompetitionCategory {
     pub name: String,
+    /// Defaults to Glicko-2
+    pub rating_system: Option<RatingSystem>,
+    /// Defaults to the standard Glicko-2 parameters and the global rating period
+    pub rating_config: Option<RatingConfig>,
+    /// Nests the category under an existing category, such as a region under a game mode
+    pub parent_id: Option<Uint128>,
+    pub metadata: Option<CategoryMetadata>,
 }
 
 #[cw_serde]
 pub enum EditCompetitionCategory {
-    Disable { category_id: Uint128 },
-    Edit { category_id: Uint128, name: String },
+    Disable {
+        category_id: Uint128,
+    },
+    Edit {
+        category_id: Uint128,
+        name: String,
+    },
+    SetRatingSystem {
+        category_id: Uint128,
+        rating_system: RatingSystem,
+    },
+    SetRatingConfig {
+        category_id: Uint128,
+        rating_config: RatingConfig,
+    },
+    SetMetadata {
+        category_id: Uint128,
+        metadata: CategoryMetadata,
+    },
+}
+
+#[cw_serde]
+pub struct EditRuleset {
+    pub id: Uint128,
+    pub rules: Vec<String>,
+    pub description: String,
 }
 
 #[cw_serde]
@@ -193,6 +428,24 @@ pub struct Ruleset {
     pub rules: Vec<String>,
     pub description: String,
     pub is_enabled: bool,
+    /// None for rulesets created before versioning, which are on their first version
+    pub version: Option<u64>,
+}
+
+impl Ruleset {
+    pub fn current_version(&self) -> u64 {
+        self.version.unwrap_or(1)
+    }
+}
+
+/// The version of a ruleset a competition follows
+#[cw_serde]
+pub enum RulesetVersion {
+    /// Follows the ruleset's edits
+    Latest {},
+    Pinned {
+        version: u64,
+    },
 }
 
 #[cw_serde]
@@ -200,6 +453,122 @@ pub struct CompetitionCategory {
     pub id: Uint128,
     pub name: String,
     pub is_enabled: bool,
+    pub rating_system: Option<RatingSystem>,
+    pub rating_config: Option<RatingConfig>,
+    /// Results rated in the category are also rated in its parent categories
+    pub parent_id: Option<Uint128>,
+    pub metadata: Option<CategoryMetadata>,
+}
+
+/// Unset fields are inherited from the parent category
+#[cw_serde]
+#[derive(Default)]
+pub struct CategoryMetadata {
+    pub image: Option<String>,
+    pub description: Option<String>,
+    /// The keys of the competition modules that can host the category's competitions
+    pub competition_modules: Option<Vec<String>>,
+    /// The rulesets of competitions created without any
+    pub default_rulesets: Option<Vec<Uint128>>,
+    /// The bounds of each player's stake in matchmaking queues and enrollment entry fees
+    pub min_stake: Option<Coin>,
+    pub max_stake: Option<Coin>,
+    /// The bond required to jail the category's competitions
+    pub jail_bond: Option<JailBond>,
+}
+
+impl CategoryMetadata {
+    pub fn validate(&self) -> StdResult<()> {
+        if let (Some(min_stake), Some(max_stake)) = (&self.min_stake, &self.max_stake) {
+            ensure!(
+                min_stake.denom == max_stake.denom,
+                StdError::generic_err("The min and max stake must have the same denom")
+            );
+            ensure!(
+                min_stake.amount <= max_stake.amount,
+                StdError::generic_err("The min stake cannot be larger than the max stake")
+            );
+        }
+
+        if let Some(jail_bond) = &self.jail_bond {
+            ensure!(
+                !jail_bond.amount.amount.is_zero(),
+                StdError::generic_err("The jail bond cannot be zero")
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Fills the unset fields from the parent category's metadata
+    pub fn inherit(self, parent: &CategoryMetadata) -> CategoryMetadata {
+        CategoryMetadata {
+            image: self.image.or_else(|| parent.image.clone()),
+            description: self.description.or_else(|| parent.description.clone()),
+            competition_modules: self
+                .competition_modules
+                .or_else(|| parent.competition_modules.clone()),
+            default_rulesets: self
+                .default_rulesets
+                .or_else(|| parent.default_rulesets.clone()),
+            min_stake: self.min_stake.or_else(|| parent.min_stake.clone()),
+            max_stake: self.max_stake.or_else(|| parent.max_stake.clone()),
+            jail_bond: self.jail_bond.or_else(|| parent.jail_bond.clone()),
+        }
+    }
+
+    pub fn allows_module(&self, key: &str) -> bool {
+        self.competition_modules
+            .as_ref()
+            .map_or(true, |keys| keys.iter().any(|x| x == key))
+    }
+
+    /// Ensures a player's stake is within the category's bounds
+    pub fn check_stake(&self, stake: Option<&Coin>) -> StdResult<()> {
+        let amount = |bound: &Coin| {
+            stake
+                .filter(|x| x.denom == bound.denom)
+                .map(|x| x.amount)
+                .unwrap_or_default()
+        };
+
+        if let Some(min_stake) = &self.min_stake {
+            ensure!(
+                amount(min_stake) >= min_stake.amount,
+                StdError::generic_err(format!(
+                    "The category requires a stake of at least {}",
+                    min_stake
+                ))
+            );
+        }
+        if let Some(max_stake) = &self.max_stake {
+            ensure!(
+                stake.map_or(true, |x| x.denom == max_stake.denom)
+                    && amount(max_stake) <= max_stake.amount,
+                StdError::generic_err(format!(
+                    "The category allows a stake of at most {}",
+                    max_stake
+                ))
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// The bond posted to jail a competition, which is refunded unless the DAO upholds the host's submitted result
+#[cw_serde]
+pub struct JailBond {
+    pub amount: Coin,
+    pub slash_recipient: SlashRecipient,
+}
+
+/// Who receives a jail bond when the DAO upholds the host
+#[cw_serde]
+pub enum SlashRecipient {
+    Host {},
+    /// The DAO treasury
+    Dao {},
 }
 
 #[cw_serde]
@@ -209,6 +578,40 @@ pub struct ProposeMessage {
     pub description: String,
     pub distribution: Option<Distribution<String>>,
     pub originator: String,
+    /// None for competition modules that predate jail proposal snapshots
+    pub snapshot: Option<CompetitionSnapshot>,
+    /// The bond withheld from the proposal deposit, which is settled if the proposal is not executed
+    pub jail_bond: Option<Coin>,
+}
+
+/// A jailed competition's state at the time of a jail proposal, for the DAO to review
+#[cw_serde]
+pub struct CompetitionSnapshot {
+    /// The height the snapshot was taken at, where the competition's full state can be queried
+    pub height: u64,
+    /// The competition's first evidence ids
+    pub evidence_ids: Vec<Uint128>,
+    /// The first page of the competition's stats table
+    pub stats: Vec<StatTableEntry>,
+    /// The module's competition extension, such as a tournament's bracket progress or a league's rounds
+    pub extension: Binary,
+    pub escrow_balances: Option<Vec<MemberBalanceChecked>>,
+    /// The host's last submitted result
+    pub host_result: Option<HostResult>,
+    /// Whether the evidence, stats, or escrow balances have more entries than the snapshot keeps
+    pub is_truncated: bool,
+}
+
+#[cw_serde]
+pub struct JailProposal {
+    pub proposal_id: u64,
+    pub module: Addr,
+    pub competition_id: Uint128,
+    pub originator: Addr,
+    /// The result proposed by the member jailing the competition
+    pub distribution: Option<Distribution<String>>,
+    pub snapshot: Option<CompetitionSnapshot>,
+    pub jail_bond: Option<Coin>,
 }
 
 #[cw_serde]
@@ -216,6 +619,23 @@ pub struct TaxConfigurationResponse {
     pub tax: Decimal,
     pub cw20_msg: Option<Binary>,
     pub cw721_msg: Option<Binary>,
+    pub volume_tiers: Option<Vec<TaxTier>>,
+}
+
+impl TaxConfigurationResponse {
+    /// The tax for a prize pool, which is the lowest rate among the tax and the tiers the pool reaches
+    pub fn tax_for_prize_pool(&self, prize_pool: &[Coin]) -> Decimal {
+        self.volume_tiers
+            .iter()
+            .flatten()
+            .filter(|tier| {
+                prize_pool.iter().any(|coin| {
+                    coin.denom == tier.threshold.denom && coin.amount >= tier.threshold.amount
+                })
+            })
+            .map(|tier| tier.tax)
+            .fold(self.tax, Decimal::min)
+    }
 }
 
 #[cw_serde]
@@ -223,6 +643,22 @@ pub enum ProposeMessages {
     Propose(SingleChoiceProposeMsg),
 }
 
+#[cw_serde]
+pub struct ModuleVersion {
+    pub addr: Addr,
+    pub code_id: u64,
+    /// The module's cw2 contract name and version
+    pub contract: String,
+    pub version: String,
+    pub height: u64,
+}
+
+#[cw_serde]
+pub struct EscrowMigration {
+    pub escrow_code_id: u64,
+    pub escrow_migrate_msg: crate::escrow::MigrateMsg,
+}
+
 #[cw_serde]
 pub enum CompetitionModuleQuery {
     Key(String, Option<u64>),
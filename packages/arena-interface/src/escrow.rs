@@ -24,6 +24,11 @@ pub enum ExecuteMsg {
     },
     #[cw_orch(payable)]
     ReceiveNative {},
+    /// Credits the sent funds to a member, such as a contract paying the dues of its users
+    #[cw_orch(payable)]
+    ReceiveNativeFor {
+        addr: String,
+    },
     Receive(Cw20ReceiveMsg),
     ReceiveNft(Cw721ReceiveMsg),
     Distribute {
@@ -1,6 +1,7 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{ensure, Addr, Binary, Decimal, Deps, StdError, StdResult};
+use cosmwasm_std::{ensure, Addr, Binary, Coin, Decimal, Deps, StdError, StdResult};
 use cw_address_like::AddressLike;
+use cw_balance::{BalanceError, BalanceVerified};
 
 #[cw_serde]
 pub struct FeeInformation<T: AddressLike> {
@@ -8,6 +9,13 @@ pub struct FeeInformation<T: AddressLike> {
     pub receiver: T,
     pub cw20_msg: Option<Binary>,
     pub cw721_msg: Option<Binary>,
+    /// The least taken of each native denom, limited to the amount available
+    /// Minimums count against the fee ceiling on the prize pool known at creation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_amounts: Option<Vec<Coin>>,
+    /// The most taken of each native denom
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_amounts: Option<Vec<Coin>>,
 }
 
 impl FeeInformation<String> {
@@ -16,12 +24,117 @@ impl FeeInformation<String> {
             self.tax < Decimal::one(),
             StdError::generic_err("Tax must be less than 100%")
         );
+        for min_amount in self.min_amounts.iter().flatten() {
+            if let Some(max_amount) = self
+                .max_amounts
+                .iter()
+                .flatten()
+                .find(|x| x.denom == min_amount.denom)
+            {
+                ensure!(
+                    min_amount.amount <= max_amount.amount,
+                    StdError::generic_err(format!(
+                        "The minimum fee of {} cannot be more than its cap",
+                        min_amount.denom
+                    ))
+                );
+            }
+        }
 
         Ok(FeeInformation {
             receiver: deps.api.addr_validate(&self.receiver)?,
             tax: self.tax,
             cw20_msg: self.cw20_msg.clone(),
             cw721_msg: self.cw721_msg.clone(),
+            min_amounts: self.min_amounts.clone(),
+            max_amounts: self.max_amounts.clone(),
         })
     }
 }
+
+impl<T: AddressLike> FeeInformation<T> {
+    /// The fee taken from a balance, where native amounts are raised to their minimum and lowered to their cap
+    pub fn fee_amounts(&self, balance: &BalanceVerified) -> Result<BalanceVerified, BalanceError> {
+        let mut fee_amounts = balance.checked_mul_floor(self.tax)?;
+        if self.min_amounts.is_none() && self.max_amounts.is_none() {
+            return Ok(fee_amounts);
+        }
+
+        let native: Vec<Coin> = balance
+            .native
+            .iter()
+            .flatten()
+            .filter_map(|coin| {
+                let find = |amounts: &Option<Vec<Coin>>| {
+                    amounts
+                        .iter()
+                        .flatten()
+                        .find(|x| x.denom == coin.denom)
+                        .map(|x| x.amount)
+                };
+
+                let mut amount = find(&fee_amounts.native).unwrap_or_default();
+                if let Some(min_amount) = find(&self.min_amounts) {
+                    amount = amount.max(min_amount.min(coin.amount));
+                }
+                if let Some(max_amount) = find(&self.max_amounts) {
+                    amount = amount.min(max_amount);
+                }
+
+                (!amount.is_zero()).then(|| Coin {
+                    denom: coin.denom.clone(),
+                    amount,
+                })
+            })
+            .collect();
+        fee_amounts.native = if native.is_empty() {
+            None
+        } else {
+            Some(native)
+        };
+
+        Ok(fee_amounts)
+    }
+}
+
+/// The share of each native denom of a prize pool taken by fees applied one after another, including their minimums and caps
+pub fn native_fee_shares<T: AddressLike>(
+    fees: &[FeeInformation<T>],
+    prize_pool: &BalanceVerified,
+) -> Result<Vec<(String, Decimal)>, BalanceError> {
+    let mut remaining = prize_pool.clone();
+    for fee in fees {
+        let fee_amounts = fee.fee_amounts(&remaining)?;
+        remaining = remaining.checked_sub(&fee_amounts)?;
+    }
+
+    Ok(prize_pool
+        .native
+        .iter()
+        .flatten()
+        .filter(|coin| !coin.amount.is_zero())
+        .map(|coin| {
+            let kept = remaining
+                .native
+                .iter()
+                .flatten()
+                .find(|x| x.denom == coin.denom)
+                .map(|x| x.amount)
+                .unwrap_or_default();
+
+            (
+                coin.denom.clone(),
+                Decimal::from_ratio(coin.amount - kept, coin.amount),
+            )
+        })
+        .collect())
+}
+
+/// The share of a prize pool taken by fee rates applied one after another
+pub fn effective_rate(taxes: impl IntoIterator<Item = Decimal>) -> StdResult<Decimal> {
+    let kept = taxes.into_iter().try_fold(Decimal::one(), |acc, tax| {
+        acc.checked_mul(Decimal::one().checked_sub(tax)?)
+    })?;
+
+    Ok(Decimal::one().checked_sub(kept)?)
+}
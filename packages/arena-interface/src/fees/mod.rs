@@ -1,5 +1,7 @@
 pub mod fee_information;
 pub mod tax_configuration;
+pub mod tax_tier;
 
-pub use fee_information::FeeInformation;
+pub use fee_information::{effective_rate, native_fee_shares, FeeInformation};
 pub use tax_configuration::TaxConfiguration;
+pub use tax_tier::TaxTier;
@@ -3,6 +3,8 @@ use cosmwasm_std::{Binary, Decimal};
 
 use crate::core::TaxConfigurationResponse;
 
+use super::TaxTier;
+
 #[cw_serde]
 pub struct TaxConfiguration {
     pub cw20_msg: Option<Binary>,
@@ -10,11 +12,16 @@ pub struct TaxConfiguration {
 }
 
 impl TaxConfiguration {
-    pub fn into_response(self, tax: Decimal) -> TaxConfigurationResponse {
+    pub fn into_response(
+        self,
+        tax: Decimal,
+        volume_tiers: Option<Vec<TaxTier>>,
+    ) -> TaxConfigurationResponse {
         TaxConfigurationResponse {
             tax,
             cw20_msg: self.cw20_msg,
             cw721_msg: self.cw721_msg,
+            volume_tiers,
         }
     }
 }
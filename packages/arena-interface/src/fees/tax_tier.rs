@@ -0,0 +1,24 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{ensure, Coin, Decimal, StdError, StdResult};
+
+/// A lower Arena tax for competitions whose prize pool reaches the threshold
+#[cw_serde]
+pub struct TaxTier {
+    pub threshold: Coin,
+    pub tax: Decimal,
+}
+
+impl TaxTier {
+    pub fn validate(&self) -> StdResult<()> {
+        ensure!(
+            !self.threshold.amount.is_zero(),
+            StdError::generic_err("Cannot have a tax tier threshold of 0")
+        );
+        ensure!(
+            self.tax < Decimal::one(),
+            StdError::generic_err("The dao tax must be less than 100%.")
+        );
+
+        Ok(())
+    }
+}
@@ -18,6 +18,8 @@ pub enum ExecuteMsg {
         to_update: Option<Vec<MemberMsg<String>>>,
         to_remove: Option<Vec<String>>,
     },
+    /// Sets the players on each member's roster, where an empty list removes the roster
+    UpdateRosters { rosters: Vec<RosterMsg<String>> },
 }
 
 #[cw_serde]
@@ -33,6 +35,13 @@ pub struct MemberMsg<T: AddressLike> {
     pub seed: Uint64,
 }
 
+/// The players competing on behalf of a member, used for individual rating adjustments
+#[cw_serde]
+pub struct RosterMsg<T: AddressLike> {
+    pub addr: T,
+    pub players: Vec<T>,
+}
+
 #[cw_ownable_query]
 #[cw_serde]
 #[derive(QueryResponses, cw_orch::QueryFns)]
@@ -48,6 +57,9 @@ pub enum QueryMsg {
     IsValidDistribution { addrs: Vec<String> },
     #[returns(bool)]
     IsMember { addr: String },
+    /// Returns the rosters of the given members that have one
+    #[returns(Vec<RosterMsg<cosmwasm_std::Addr>>)]
+    Rosters { addrs: Vec<String> },
 }
 
 #[cw_serde]
@@ -0,0 +1,114 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{ensure, Addr, BlockInfo, Coin, Decimal, Deps, StdError, StdResult};
+use cw_address_like::AddressLike;
+use cw_utils::Expiration;
+
+/// A competition module's notification of a host's competition activity
+#[cw_serde]
+pub enum HostActivity {
+    Create {},
+    /// The native fees paid to the host from the competition's prize pool
+    Process {
+        earnings: Vec<Coin>,
+    },
+    Jail {},
+}
+
+/// A host's competition activity across all competition modules
+#[cw_serde]
+#[derive(Default)]
+pub struct HostStats {
+    pub competitions_created: u64,
+    pub competitions_processed: u64,
+    pub competitions_jailed: u64,
+    pub earnings: Vec<Coin>,
+}
+
+impl HostStats {
+    pub fn record(&mut self, activity: HostActivity) -> StdResult<()> {
+        match activity {
+            HostActivity::Create {} => self.competitions_created += 1,
+            HostActivity::Jail {} => self.competitions_jailed += 1,
+            HostActivity::Process { earnings } => {
+                self.competitions_processed += 1;
+
+                for coin in earnings.into_iter().filter(|x| !x.amount.is_zero()) {
+                    match self.earnings.iter_mut().find(|x| x.denom == coin.denom) {
+                        Some(existing) => {
+                            existing.amount = existing.amount.checked_add(coin.amount)?
+                        }
+                        None => self.earnings.push(coin),
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The share of the host's competitions that were never jailed
+    pub fn reputation(&self) -> Option<Decimal> {
+        if self.competitions_created == 0 {
+            return None;
+        }
+
+        Some(Decimal::from_ratio(
+            self.competitions_created
+                .saturating_sub(self.competitions_jailed),
+            self.competitions_created,
+        ))
+    }
+
+    pub fn into_response(self, host: Addr) -> HostStatsResponse {
+        HostStatsResponse {
+            host,
+            reputation: self.reputation(),
+            stats: self,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct HostStatsResponse {
+    pub host: Addr,
+    pub stats: HostStats,
+    /// None until the host has created a competition
+    pub reputation: Option<Decimal>,
+}
+
+/// A host barred from creating competitions and enrollments
+#[cw_serde]
+pub struct HostBan<T: AddressLike> {
+    pub host: T,
+    pub reason: String,
+    /// The ban is permanent without an expiration
+    pub expiration: Option<Expiration>,
+}
+
+impl HostBan<String> {
+    pub fn into_checked(self, deps: Deps, block: &BlockInfo) -> StdResult<HostBan<Addr>> {
+        ensure!(
+            !self.reason.is_empty(),
+            StdError::generic_err("A host ban must have a reason")
+        );
+        if let Some(expiration) = self.expiration {
+            ensure!(
+                !expiration.is_expired(block),
+                StdError::generic_err("Cannot add an expired host ban")
+            );
+        }
+
+        Ok(HostBan {
+            host: deps.api.addr_validate(&self.host)?,
+            reason: self.reason,
+            expiration: self.expiration,
+        })
+    }
+}
+
+impl HostBan<Addr> {
+    pub fn is_active(&self, block: &BlockInfo) -> bool {
+        self.expiration
+            .map_or(true, |expiration| !expiration.is_expired(block))
+    }
+}
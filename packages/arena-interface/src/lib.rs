@@ -3,5 +3,6 @@ pub mod core;
 pub mod escrow;
 pub mod fees;
 pub mod group;
+pub mod hosts;
 pub mod ratings;
 pub mod registry;
@@ -0,0 +1,85 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{ensure, Decimal, SignedDecimal, StdError, StdResult};
+use cw_utils::Duration;
+
+use super::{IntegrityConfig, Rating, RatingState};
+
+/// The rating parameters of a competition category
+#[cw_serde]
+pub struct RatingConfig {
+    /// The rating given to unrated members
+    pub initial_rating: SignedDecimal,
+    /// The Glicko-2 or Gaussian deviation given to unrated members
+    pub initial_deviation: Decimal,
+    /// The Glicko-2 volatility given to unrated members
+    pub initial_volatility: Decimal,
+    /// The Glicko-2 system constant, which constrains the change in volatility over time
+    pub tau: Decimal,
+    /// The highest deviation a Glicko-2 rating can reach through inactivity
+    pub max_deviation: Decimal,
+    /// Overrides the global rating period
+    pub rating_period: Option<Duration>,
+    /// Anti-farming rules, which are not enforced if unset
+    pub integrity: Option<IntegrityConfig>,
+}
+
+impl Default for RatingConfig {
+    fn default() -> Self {
+        Self {
+            initial_rating: SignedDecimal::from_ratio(1500, 1),
+            initial_deviation: Decimal::from_ratio(300u128, 1u128),
+            initial_volatility: Decimal::from_ratio(6u128, 100u128),
+            tau: Decimal::one(),
+            max_deviation: Decimal::from_ratio(350u128, 1u128),
+            rating_period: None,
+            integrity: None,
+        }
+    }
+}
+
+impl RatingConfig {
+    pub fn validate(&self) -> StdResult<()> {
+        ensure!(
+            !self.initial_deviation.is_zero(),
+            StdError::generic_err("The initial deviation cannot be 0")
+        );
+        ensure!(
+            !self.initial_volatility.is_zero(),
+            StdError::generic_err("The initial volatility cannot be 0")
+        );
+        ensure!(
+            !self.tau.is_zero(),
+            StdError::generic_err("The tau cannot be 0")
+        );
+        ensure!(
+            self.max_deviation >= self.initial_deviation,
+            StdError::generic_err("The max deviation cannot be lower than the initial deviation")
+        );
+        if let Some(rating_period) = &self.rating_period {
+            let value = match rating_period {
+                Duration::Height(height) => height,
+                Duration::Time(seconds) => seconds,
+            };
+            ensure!(
+                *value != 0,
+                StdError::generic_err("Cannot have a period of 0")
+            );
+        }
+        if let Some(integrity) = &self.integrity {
+            integrity.validate()?;
+        }
+
+        Ok(())
+    }
+
+    /// The Glicko-2 rating given to unrated members
+    pub fn initial_rating(&self) -> Rating {
+        Rating::new(
+            self.initial_rating,
+            RatingState::Glicko2 {
+                phi: self.initial_deviation,
+                sigma: self.initial_volatility,
+            },
+        )
+    }
+}
@@ -0,0 +1,50 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{ensure, Addr, Coin, Decimal, StdError, StdResult};
+use cw_utils::Duration;
+
+/// Anti-farming rules for a category's rated results
+#[cw_serde]
+pub struct IntegrityConfig {
+    /// How long a pairing counts towards a pair's repeated pairings
+    pub pairing_window: Duration,
+    /// The share of the rating impact kept for each earlier pairing of the pair within the window
+    pub repeat_decay: Decimal,
+    /// The number of pairings within the window at which a pair is flagged for review
+    pub flag_threshold: u32,
+    /// The escrow stake a competition needs for its results to be rated
+    pub min_stake: Option<Coin>,
+}
+
+impl IntegrityConfig {
+    pub fn validate(&self) -> StdResult<()> {
+        let window = match self.pairing_window {
+            Duration::Height(height) => height,
+            Duration::Time(seconds) => seconds,
+        };
+        ensure!(
+            window != 0,
+            StdError::generic_err("Cannot have a pairing window of 0")
+        );
+        ensure!(
+            self.repeat_decay <= Decimal::one(),
+            StdError::generic_err("The repeat decay cannot be more than 100%")
+        );
+        ensure!(
+            self.flag_threshold != 0,
+            StdError::generic_err("Cannot have a flag threshold of 0")
+        );
+
+        Ok(())
+    }
+}
+
+/// A pair of members who have faced each other suspiciously often
+#[cw_serde]
+pub struct FlaggedPair {
+    pub addr_1: Addr,
+    pub addr_2: Addr,
+    /// The pair's pairings within the window when it was last flagged
+    pub pairings: u32,
+    /// The height the pair was last flagged at
+    pub height: u64,
+}
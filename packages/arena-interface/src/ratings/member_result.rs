@@ -1,5 +1,5 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Decimal};
+use cosmwasm_std::{Addr, Decimal, Uint128};
 use cw_address_like::AddressLike;
 
 #[cw_serde]
@@ -16,3 +16,45 @@ impl From<MemberResult<Addr>> for MemberResult<String> {
         }
     }
 }
+
+/// A team's placement in a rated match, where 1 is first and equal placements are ties
+/// A single member competing alone is a team of one
+#[cw_serde]
+pub struct TeamResult<T: AddressLike> {
+    pub members: Vec<T>,
+    pub placement: u32,
+}
+
+/// A match between 2 or more teams
+#[cw_serde]
+pub struct RatedMatch<T: AddressLike> {
+    pub teams: Vec<TeamResult<T>>,
+    pub match_number: Option<Uint128>,
+}
+
+impl From<RatedMatch<Addr>> for RatedMatch<String> {
+    fn from(rated_match: RatedMatch<Addr>) -> Self {
+        RatedMatch {
+            teams: rated_match
+                .teams
+                .into_iter()
+                .map(|team| TeamResult {
+                    members: team.members.into_iter().map(|x| x.to_string()).collect(),
+                    placement: team.placement,
+                })
+                .collect(),
+            match_number: rated_match.match_number,
+        }
+    }
+}
+
+/// The competition and match behind a rating adjustment
+#[cw_serde]
+pub struct RatingSource {
+    /// The competition module that reported the result
+    pub module: Addr,
+    pub competition_id: Uint128,
+    /// Competitions without matches, such as wagers, have no match number
+    /// League match numbers are only unique within their round
+    pub match_number: Option<Uint128>,
+}
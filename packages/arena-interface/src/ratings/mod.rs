@@ -1,5 +1,13 @@
+pub mod config;
+pub mod integrity;
 pub mod member_result;
 pub mod rating;
+pub mod season;
+pub mod system;
 
-pub use member_result::MemberResult;
-pub use rating::Rating;
+pub use config::RatingConfig;
+pub use integrity::{FlaggedPair, IntegrityConfig};
+pub use member_result::{MemberResult, RatedMatch, RatingSource, TeamResult};
+pub use rating::{rating_index_key, PendingRatingResult, Rating, RatingSnapshot, RatingState};
+pub use season::{Season, SeasonReward, SeasonStanding, SoftReset};
+pub use system::{RatingCalculator, RatingSystem};
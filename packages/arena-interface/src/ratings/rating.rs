@@ -1,22 +1,57 @@
 use std::fmt;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{BlockInfo, Decimal};
+use cosmwasm_std::{Addr, BlockInfo, Decimal, SignedDecimal};
+
+use super::{RatingConfig, RatingSource};
+
+/// The state kept by each rating system in addition to the rating value
+#[cw_serde]
+pub enum RatingState {
+    Elo {},
+    Glicko2 {
+        phi: Decimal,
+        sigma: Decimal,
+    },
+    /// The rating value is the mean of the skill estimate
+    Gaussian {
+        sigma: Decimal,
+    },
+}
 
 #[cw_serde]
 pub struct Rating {
-    pub value: Decimal,
-    pub phi: Decimal,
-    pub sigma: Decimal,
+    /// Ratings can go below zero after long losing streaks
+    pub value: SignedDecimal,
+    pub state: RatingState,
     pub last_block: Option<BlockInfo>,
 }
 
+/// A member's rating as of the block where it changed
+#[cw_serde]
+pub struct RatingSnapshot {
+    pub height: u64,
+    pub rating: Rating,
+    /// The results applied in the change, which is empty for reverted adjustments
+    pub sources: Vec<RatingSource>,
+}
+
+/// A result waiting for its rating period to close
+/// A member facing a team is rated against the team's combined rating
+#[cw_serde]
+pub struct PendingRatingResult {
+    pub opponents: Vec<Addr>,
+    pub score: Decimal,
+    pub source: Option<RatingSource>,
+    /// The share of the result's rating impact kept, which diminishes for repeated pairings
+    pub weight: Option<Decimal>,
+}
+
 impl Rating {
-    pub fn new(value: Decimal, phi: Decimal, sigma: Decimal) -> Self {
+    pub fn new(value: SignedDecimal, state: RatingState) -> Self {
         Self {
             value,
-            phi,
-            sigma,
+            state,
             last_block: None,
         }
     }
@@ -24,22 +59,25 @@ impl Rating {
 
 impl Default for Rating {
     fn default() -> Self {
-        Self {
-            value: Decimal::from_ratio(1500u128, 1u128),
-            phi: Decimal::from_ratio(300u128, 1u128),
-            sigma: Decimal::from_ratio(6u128, 100u128),
-            last_block: None,
+        RatingConfig::default().initial_rating()
+    }
+}
+
+impl fmt::Display for RatingState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RatingState::Elo {} => write!(f, "elo"),
+            RatingState::Glicko2 { phi, sigma } => {
+                write!(f, "glicko2(phi: {}, sigma: {})", phi, sigma)
+            }
+            RatingState::Gaussian { sigma } => write!(f, "gaussian(sigma: {})", sigma),
         }
     }
 }
 
 impl fmt::Display for Rating {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "Rating(value: {}, phi: {}, sigma: {})",
-            self.value, self.phi, self.sigma
-        )?;
+        write!(f, "Rating(value: {}, state: {})", self.value, self.state)?;
 
         if let Some(block_info) = &self.last_block {
             write!(
@@ -52,3 +90,10 @@ impl fmt::Display for Rating {
         }
     }
 }
+
+/// Maps a signed rating value to an index key with the same order
+/// Flipping the sign bit moves negative values below positive ones
+/// Indexes ranged by rating, such as arena-core's leaderboard and the matchmaking queue, share this encoding
+pub fn rating_index_key(value: SignedDecimal) -> u128 {
+    (value.atomics().i128() as u128) ^ (1 << 127)
+}
@@ -0,0 +1,67 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{ensure, Addr, Coin, Decimal, StdError, StdResult};
+
+use super::Rating;
+
+/// How ratings carry over into a new season
+#[cw_serde]
+pub struct SoftReset {
+    /// The share of the distance to the initial rating each rating is pulled by
+    pub pull: Decimal,
+    /// The deviation added to Glicko-2 ratings, up to the category's max deviation
+    pub deviation_increase: Decimal,
+}
+
+impl SoftReset {
+    pub fn validate(&self) -> StdResult<()> {
+        ensure!(
+            self.pull <= Decimal::one(),
+            StdError::generic_err("The pull cannot be more than 100%")
+        );
+
+        Ok(())
+    }
+}
+
+/// A season reward pool split between the top of the final leaderboard
+#[cw_serde]
+pub struct SeasonReward {
+    /// The share of the pool for each rank, starting from first place
+    pub distribution: Vec<Decimal>,
+}
+
+impl SeasonReward {
+    pub fn validate(&self) -> StdResult<()> {
+        ensure!(
+            !self.distribution.is_empty(),
+            StdError::generic_err("The reward distribution cannot be empty")
+        );
+        ensure!(
+            self.distribution
+                .iter()
+                .try_fold(Decimal::zero(), |acc, x| acc.checked_add(*x))?
+                == Decimal::one(),
+            StdError::generic_err("The reward distribution must sum up to 1")
+        );
+
+        Ok(())
+    }
+}
+
+/// A category's ended season
+#[cw_serde]
+pub struct Season {
+    pub id: u64,
+    pub start_height: u64,
+    pub end_height: u64,
+    pub reward_pool: Vec<Coin>,
+}
+
+/// A member's final standing in an ended season
+#[cw_serde]
+pub struct SeasonStanding {
+    pub rank: u32,
+    pub addr: Addr,
+    pub rating: Rating,
+    pub reward: Vec<Coin>,
+}
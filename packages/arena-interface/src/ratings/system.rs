@@ -0,0 +1,78 @@
+use std::fmt;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{ensure, Decimal, Env, StdError, StdResult};
+use cw_utils::Duration;
+
+use super::Rating;
+
+/// The rating system used by a competition category
+#[cw_serde]
+pub enum RatingSystem {
+    /// Classic Elo where each rating period moves the rating by `k_factor` times the score surplus
+    Elo {
+        k_factor: Decimal,
+    },
+    Glicko2 {},
+    /// A Gaussian skill estimate suited for team and free-for-all games
+    /// `beta` is the performance variability and `tau` the skill drift added each update
+    Gaussian {
+        beta: Decimal,
+        tau: Decimal,
+    },
+}
+
+impl Default for RatingSystem {
+    fn default() -> Self {
+        RatingSystem::Glicko2 {}
+    }
+}
+
+impl RatingSystem {
+    pub fn validate(&self) -> StdResult<()> {
+        match self {
+            RatingSystem::Elo { k_factor } => ensure!(
+                !k_factor.is_zero(),
+                StdError::generic_err("The Elo k-factor cannot be 0")
+            ),
+            RatingSystem::Glicko2 {} => {}
+            RatingSystem::Gaussian { beta, tau: _ } => ensure!(
+                !beta.is_zero(),
+                StdError::generic_err("The Gaussian beta cannot be 0")
+            ),
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for RatingSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RatingSystem::Elo { k_factor } => write!(f, "elo(k_factor: {})", k_factor),
+            RatingSystem::Glicko2 {} => write!(f, "glicko2"),
+            RatingSystem::Gaussian { beta, tau } => {
+                write!(f, "gaussian(beta: {}, tau: {})", beta, tau)
+            }
+        }
+    }
+}
+
+/// Implemented by every rating system
+pub trait RatingCalculator {
+    /// The rating given to members without a rating in the category
+    fn initial_rating(&self) -> Rating;
+
+    /// Updates a member's rating from all the (opponent, score) results of a rating period.
+    /// Ratings holding another system's state are treated as the system's initial state with the same value.
+    fn update_rating_period(
+        &self,
+        env: &Env,
+        player: &mut Rating,
+        results: &[(Rating, Decimal)],
+        period: &Duration,
+    );
+
+    /// Combines the ratings of a team's members into the rating of the team as a single opponent
+    fn team_rating(&self, members: &[Rating]) -> Rating;
+}
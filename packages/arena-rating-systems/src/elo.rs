@@ -0,0 +1,64 @@
+use arena_interface::ratings::{Rating, RatingCalculator, RatingState};
+use cosmwasm_std::{Decimal as StdDecimal, Env, SignedDecimal};
+use cw_utils::Duration;
+use glicko_2::util::{logistic, rust_to_signed, signed_to_rust, std_to_rust};
+use rust_decimal::prelude::*;
+use rust_decimal_macros::dec;
+
+/// The rating difference at which the stronger player is expected to score 10 times more
+pub const SCALE: Decimal = dec!(400);
+
+pub struct Elo {
+    pub k_factor: StdDecimal,
+    pub initial_rating: SignedDecimal,
+}
+
+/// The expected score of a player against an opponent, which saturates at 0 or 1 for large rating gaps
+pub fn expect_score(value: Decimal, opponent_value: Decimal) -> Decimal {
+    logistic((opponent_value - value) / SCALE * Decimal::TEN.ln())
+}
+
+impl RatingCalculator for Elo {
+    fn initial_rating(&self) -> Rating {
+        Rating::new(self.initial_rating, RatingState::Elo {})
+    }
+
+    fn update_rating_period(
+        &self,
+        env: &Env,
+        player: &mut Rating,
+        results: &[(Rating, StdDecimal)],
+        _period: &Duration,
+    ) {
+        if results.is_empty() {
+            return;
+        }
+
+        let value = signed_to_rust(player.value);
+
+        // Every game is expected against the ratings from before the period
+        let surplus: Decimal = results
+            .iter()
+            .map(|(opponent, score)| {
+                std_to_rust(*score) - expect_score(value, signed_to_rust(opponent.value))
+            })
+            .sum();
+
+        let new_value = value + std_to_rust(self.k_factor) * surplus;
+
+        player.value = rust_to_signed(new_value);
+        player.state = RatingState::Elo {};
+        player.last_block = Some(env.block.clone());
+    }
+
+    fn team_rating(&self, members: &[Rating]) -> Rating {
+        let count = Decimal::from(members.len());
+        let value = members
+            .iter()
+            .map(|x| signed_to_rust(x.value))
+            .sum::<Decimal>()
+            / count;
+
+        Rating::new(rust_to_signed(value), RatingState::Elo {})
+    }
+}
@@ -0,0 +1,108 @@
+use arena_interface::ratings::{Rating, RatingCalculator, RatingState};
+use cosmwasm_std::{Decimal as StdDecimal, Env, SignedDecimal};
+use cw_utils::Duration;
+use glicko_2::util::{logistic, rust_to_signed, rust_to_std, signed_to_rust, std_to_rust};
+use rust_decimal::prelude::*;
+use rust_decimal_macros::dec;
+
+/// The lowest fraction of the variance kept after an update
+pub const KAPPA: Decimal = dec!(0.0001);
+
+/// A Bradley-Terry skill estimate, where each rating is a mean and a standard deviation
+pub struct Gaussian {
+    pub beta: StdDecimal,
+    pub tau: StdDecimal,
+    pub initial_rating: SignedDecimal,
+    /// The standard deviation of unrated members and of ratings coming from another system
+    pub initial_sigma: StdDecimal,
+}
+
+impl Gaussian {
+    fn sigma_of(&self, rating: &Rating) -> Decimal {
+        match rating.state {
+            RatingState::Gaussian { sigma } => std_to_rust(sigma),
+            _ => std_to_rust(self.initial_sigma),
+        }
+    }
+}
+
+impl RatingCalculator for Gaussian {
+    fn initial_rating(&self) -> Rating {
+        Rating::new(
+            self.initial_rating,
+            RatingState::Gaussian {
+                sigma: self.initial_sigma,
+            },
+        )
+    }
+
+    fn update_rating_period(
+        &self,
+        env: &Env,
+        player: &mut Rating,
+        results: &[(Rating, StdDecimal)],
+        _period: &Duration,
+    ) {
+        if results.is_empty() {
+            return;
+        }
+
+        let beta = std_to_rust(self.beta);
+        let tau = std_to_rust(self.tau);
+
+        let mu = signed_to_rust(player.value);
+        // Skill drift since the last update
+        let variance = self.sigma_of(player).powu(2) + tau.powu(2);
+
+        let mut omega = Decimal::ZERO;
+        let mut delta = Decimal::ZERO;
+        for (opponent, score) in results {
+            let opponent_mu = signed_to_rust(opponent.value);
+            let opponent_variance = self.sigma_of(opponent).powu(2);
+
+            let c = (variance + opponent_variance + Decimal::TWO * beta.powu(2))
+                .sqrt()
+                .unwrap();
+            let expected_score = logistic((opponent_mu - mu) / c);
+
+            omega += variance / c * (std_to_rust(*score) - expected_score);
+
+            let gamma = variance.sqrt().unwrap() / c;
+            delta += gamma * variance / (c * c) * expected_score * (Decimal::ONE - expected_score);
+        }
+
+        let new_mu = mu + omega;
+        let new_sigma = (variance * (Decimal::ONE - delta).max(KAPPA))
+            .sqrt()
+            .unwrap();
+
+        player.value = rust_to_signed(new_mu);
+        player.state = RatingState::Gaussian {
+            sigma: rust_to_std(new_sigma),
+        };
+        player.last_block = Some(env.block.clone());
+    }
+
+    fn team_rating(&self, members: &[Rating]) -> Rating {
+        let count = Decimal::from(members.len());
+        let mu = members
+            .iter()
+            .map(|x| signed_to_rust(x.value))
+            .sum::<Decimal>()
+            / count;
+        let sigma = (members
+            .iter()
+            .map(|x| self.sigma_of(x).powu(2))
+            .sum::<Decimal>()
+            / count)
+            .sqrt()
+            .unwrap();
+
+        Rating::new(
+            rust_to_signed(mu),
+            RatingState::Gaussian {
+                sigma: rust_to_std(sigma),
+            },
+        )
+    }
+}
@@ -0,0 +1,33 @@
+mod elo;
+mod gaussian;
+
+#[cfg(test)]
+mod tests;
+
+use arena_interface::ratings::{RatingCalculator, RatingConfig, RatingSystem};
+use glicko_2::Glicko2;
+
+pub use elo::Elo;
+pub use gaussian::Gaussian;
+
+/// Returns the implementation of a category's rating system with the category's parameters
+pub fn rating_calculator(
+    rating_system: &RatingSystem,
+    rating_config: &RatingConfig,
+) -> Box<dyn RatingCalculator> {
+    match rating_system {
+        RatingSystem::Elo { k_factor } => Box::new(Elo {
+            k_factor: *k_factor,
+            initial_rating: rating_config.initial_rating,
+        }),
+        RatingSystem::Glicko2 {} => Box::new(Glicko2 {
+            config: rating_config.into(),
+        }),
+        RatingSystem::Gaussian { beta, tau } => Box::new(Gaussian {
+            beta: *beta,
+            tau: *tau,
+            initial_rating: rating_config.initial_rating,
+            initial_sigma: rating_config.initial_deviation,
+        }),
+    }
+}
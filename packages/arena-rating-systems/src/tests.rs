@@ -0,0 +1,379 @@
+use std::str::FromStr;
+
+use arena_interface::ratings::{Rating, RatingConfig, RatingState, RatingSystem};
+use cosmwasm_std::{testing::mock_env, Decimal, SignedDecimal};
+use cw_utils::Duration;
+
+use crate::rating_calculator;
+
+#[test]
+fn test_initial_ratings() {
+    let elo = rating_calculator(
+        &RatingSystem::Elo {
+            k_factor: Decimal::from_str("32").unwrap(),
+        },
+        &RatingConfig::default(),
+    );
+    assert_eq!(elo.initial_rating().state, RatingState::Elo {});
+
+    let glicko_2 = rating_calculator(&RatingSystem::Glicko2 {}, &RatingConfig::default());
+    assert_eq!(glicko_2.initial_rating(), Rating::default());
+
+    let gaussian = rating_calculator(
+        &RatingSystem::Gaussian {
+            beta: Decimal::from_str("250").unwrap(),
+            tau: Decimal::from_str("5").unwrap(),
+        },
+        &RatingConfig::default(),
+    );
+    assert_eq!(
+        gaussian.initial_rating().state,
+        RatingState::Gaussian {
+            sigma: RatingConfig::default().initial_deviation
+        }
+    );
+
+    // The category's initial deviation is the Gaussian sigma of unrated members
+    let gaussian = rating_calculator(
+        &RatingSystem::Gaussian {
+            beta: Decimal::from_str("250").unwrap(),
+            tau: Decimal::from_str("5").unwrap(),
+        },
+        &RatingConfig {
+            initial_deviation: Decimal::from_str("500").unwrap(),
+            max_deviation: Decimal::from_str("500").unwrap(),
+            ..RatingConfig::default()
+        },
+    );
+    assert_eq!(
+        gaussian.initial_rating().state,
+        RatingState::Gaussian {
+            sigma: Decimal::from_str("500").unwrap()
+        }
+    );
+}
+
+#[test]
+fn test_large_rating_gaps() {
+    let env = mock_env();
+    let period = Duration::Height(10u64);
+    let value = |x: &str| SignedDecimal::from_str(x).unwrap();
+
+    // Elo saturates the expected score instead of overflowing
+    let elo = rating_calculator(
+        &RatingSystem::Elo {
+            k_factor: Decimal::from_str("32").unwrap(),
+        },
+        &RatingConfig::default(),
+    );
+    let mut player = elo.initial_rating();
+    let opponent = Rating::new(value("30000"), RatingState::Elo {});
+    elo.update_rating_period(&env, &mut player, &[(opponent, Decimal::one())], &period);
+    assert_eq!(player.value, value("1532"));
+
+    // A small beta with shrunk sigmas turns a gap of a few hundred points into a huge exponent
+    let gaussian = rating_calculator(
+        &RatingSystem::Gaussian {
+            beta: Decimal::from_str("1").unwrap(),
+            tau: Decimal::from_str("1").unwrap(),
+        },
+        &RatingConfig::default(),
+    );
+    let sigma = RatingState::Gaussian {
+        sigma: Decimal::from_str("1").unwrap(),
+    };
+    let mut player = Rating::new(value("1500"), sigma.clone());
+    let opponent = Rating::new(value("1800"), sigma.clone());
+    gaussian.update_rating_period(&env, &mut player, &[(opponent, Decimal::one())], &period);
+    assert!(player.value > value("1500"));
+
+    // Glicko-2 keeps the volatility when every expected score rounds to 0 or 1
+    let glicko_2 = rating_calculator(&RatingSystem::Glicko2 {}, &RatingConfig::default());
+    let mut player = Rating::default();
+    let opponent = Rating::new(
+        value("200000"),
+        RatingState::Glicko2 {
+            phi: Decimal::from_str("30").unwrap(),
+            sigma: Decimal::from_str("0.06").unwrap(),
+        },
+    );
+    glicko_2.update_rating_period(&env, &mut player, &[(opponent, Decimal::one())], &period);
+    assert!(player.value > Rating::default().value);
+    match player.state {
+        RatingState::Glicko2 { sigma, .. } => {
+            assert_eq!(sigma, Decimal::from_str("0.06").unwrap())
+        }
+        _ => panic!("Expected a Glicko-2 rating state"),
+    }
+}
+
+#[test]
+fn test_elo_update() {
+    let env = mock_env();
+    let period = Duration::Height(10u64);
+    let elo = rating_calculator(
+        &RatingSystem::Elo {
+            k_factor: Decimal::from_str("32").unwrap(),
+        },
+        &RatingConfig::default(),
+    );
+
+    // Equal ratings expect a score of 0.5, so a win is worth half of the k-factor
+    let mut player = elo.initial_rating();
+    let opponent = elo.initial_rating();
+    elo.update_rating_period(
+        &env,
+        &mut player,
+        &[(opponent.clone(), Decimal::one())],
+        &period,
+    );
+    assert_eq!(player.value, SignedDecimal::from_str("1516").unwrap());
+    assert_eq!(player.last_block, Some(env.block.clone()));
+
+    // A win and a loss against equal opponents cancel out
+    let mut player = elo.initial_rating();
+    elo.update_rating_period(
+        &env,
+        &mut player,
+        &[
+            (opponent.clone(), Decimal::one()),
+            (opponent, Decimal::zero()),
+        ],
+        &period,
+    );
+    assert_eq!(player.value, SignedDecimal::from_str("1500").unwrap());
+}
+
+#[test]
+fn test_gaussian_update() {
+    let env = mock_env();
+    let period = Duration::Height(10u64);
+    let gaussian = rating_calculator(
+        &RatingSystem::Gaussian {
+            beta: Decimal::from_str("250").unwrap(),
+            tau: Decimal::from_str("5").unwrap(),
+        },
+        &RatingConfig::default(),
+    );
+
+    let mut winner = gaussian.initial_rating();
+    let mut loser = gaussian.initial_rating();
+    let previous = gaussian.initial_rating();
+
+    gaussian.update_rating_period(
+        &env,
+        &mut winner,
+        &[(previous.clone(), Decimal::one())],
+        &period,
+    );
+    gaussian.update_rating_period(
+        &env,
+        &mut loser,
+        &[(previous.clone(), Decimal::zero())],
+        &period,
+    );
+
+    assert!(winner.value > previous.value);
+    assert!(loser.value < previous.value);
+
+    // The changes are symmetric and the uncertainty shrinks for both players
+    assert_eq!(winner.value - previous.value, previous.value - loser.value);
+    assert_eq!(winner.state, loser.state);
+    match (winner.state, previous.state) {
+        (
+            RatingState::Gaussian { sigma },
+            RatingState::Gaussian {
+                sigma: initial_sigma,
+            },
+        ) => {
+            assert!(sigma < initial_sigma)
+        }
+        _ => panic!("Expected a gaussian rating state"),
+    }
+}
+
+#[test]
+fn test_rating_from_another_system() {
+    let env = mock_env();
+    let period = Duration::Height(10u64);
+    let elo = rating_calculator(
+        &RatingSystem::Elo {
+            k_factor: Decimal::from_str("32").unwrap(),
+        },
+        &RatingConfig::default(),
+    );
+
+    // A Glicko-2 rating keeps its value when the category switches to Elo
+    let mut player = Rating::default();
+    elo.update_rating_period(
+        &env,
+        &mut player,
+        &[(Rating::default(), Decimal::one())],
+        &period,
+    );
+    assert_eq!(player.value, SignedDecimal::from_str("1516").unwrap());
+    assert_eq!(player.state, RatingState::Elo {});
+}
+
+#[test]
+fn test_team_ratings() {
+    let elo = rating_calculator(
+        &RatingSystem::Elo {
+            k_factor: Decimal::from_str("32").unwrap(),
+        },
+        &RatingConfig::default(),
+    );
+    let team = elo.team_rating(&[
+        Rating::new(
+            SignedDecimal::from_str("1400").unwrap(),
+            RatingState::Elo {},
+        ),
+        Rating::new(
+            SignedDecimal::from_str("1600").unwrap(),
+            RatingState::Elo {},
+        ),
+    ]);
+    assert_eq!(team.value, SignedDecimal::from_str("1500").unwrap());
+
+    let gaussian = rating_calculator(
+        &RatingSystem::Gaussian {
+            beta: Decimal::from_str("250").unwrap(),
+            tau: Decimal::from_str("5").unwrap(),
+        },
+        &RatingConfig::default(),
+    );
+    let team = gaussian.team_rating(&[
+        Rating::new(
+            SignedDecimal::from_str("1400").unwrap(),
+            RatingState::Gaussian {
+                sigma: Decimal::from_str("100").unwrap(),
+            },
+        ),
+        Rating::new(
+            SignedDecimal::from_str("1600").unwrap(),
+            RatingState::Gaussian {
+                sigma: Decimal::from_str("700").unwrap(),
+            },
+        ),
+    ]);
+    assert_eq!(team.value, SignedDecimal::from_str("1500").unwrap());
+    assert_eq!(
+        team.state,
+        RatingState::Gaussian {
+            sigma: Decimal::from_str("500").unwrap()
+        }
+    );
+
+    let glicko_2 = rating_calculator(&RatingSystem::Glicko2 {}, &RatingConfig::default());
+    let team = glicko_2.team_rating(&[Rating::default(), Rating::default()]);
+    assert_eq!(team.value, Rating::default().value);
+    assert_eq!(team.state, Rating::default().state);
+}
+
+#[test]
+fn test_rating_config() {
+    let env = mock_env();
+    let period = Duration::Height(10u64);
+    let rating_config = RatingConfig {
+        initial_rating: SignedDecimal::from_str("1000").unwrap(),
+        initial_deviation: Decimal::from_str("200").unwrap(),
+        initial_volatility: Decimal::from_str("0.05").unwrap(),
+        tau: Decimal::from_str("0.5").unwrap(),
+        max_deviation: Decimal::from_str("250").unwrap(),
+        rating_period: None,
+        integrity: None,
+    };
+
+    // Every system starts unrated members from the configured rating
+    let elo = rating_calculator(
+        &RatingSystem::Elo {
+            k_factor: Decimal::from_str("32").unwrap(),
+        },
+        &rating_config,
+    );
+    assert_eq!(elo.initial_rating().value, rating_config.initial_rating);
+
+    let gaussian = rating_calculator(
+        &RatingSystem::Gaussian {
+            beta: Decimal::from_str("250").unwrap(),
+            tau: Decimal::from_str("5").unwrap(),
+        },
+        &rating_config,
+    );
+    assert_eq!(
+        gaussian.initial_rating().value,
+        rating_config.initial_rating
+    );
+
+    let glicko_2 = rating_calculator(&RatingSystem::Glicko2 {}, &rating_config);
+    assert_eq!(glicko_2.initial_rating(), rating_config.initial_rating());
+
+    // Ratings from another system start from the configured deviation
+    let mut player = elo.initial_rating();
+    glicko_2.update_rating_period(
+        &env,
+        &mut player,
+        &[(elo.initial_rating(), Decimal::one())],
+        &period,
+    );
+    let mut expected = glicko_2.initial_rating();
+    glicko_2.update_rating_period(
+        &env,
+        &mut expected,
+        &[(glicko_2.initial_rating(), Decimal::one())],
+        &period,
+    );
+    assert_eq!(player, expected);
+    assert!(player.value > rating_config.initial_rating);
+}
+
+#[test]
+fn test_negative_ratings() {
+    let env = mock_env();
+    let period = Duration::Height(10u64);
+    let elo = rating_calculator(
+        &RatingSystem::Elo {
+            k_factor: Decimal::from_str("32").unwrap(),
+        },
+        &RatingConfig::default(),
+    );
+
+    // Ratings are not clamped at 0
+    let low = Rating::new(SignedDecimal::from_str("5").unwrap(), RatingState::Elo {});
+    let mut player = low.clone();
+    elo.update_rating_period(
+        &env,
+        &mut player,
+        &[(low.clone(), Decimal::zero())],
+        &period,
+    );
+    assert_eq!(player.value, SignedDecimal::from_str("-11").unwrap());
+
+    // Negative opponents are expected to lose to higher ratings
+    let mut opponent = low.clone();
+    elo.update_rating_period(
+        &env,
+        &mut opponent,
+        &[(player.clone(), Decimal::one())],
+        &period,
+    );
+    assert!(opponent.value > low.value);
+    assert!(opponent.value < SignedDecimal::from_str("21").unwrap());
+
+    let gaussian = rating_calculator(
+        &RatingSystem::Gaussian {
+            beta: Decimal::from_str("250").unwrap(),
+            tau: Decimal::from_str("5").unwrap(),
+        },
+        &RatingConfig::default(),
+    );
+    let low = Rating::new(
+        SignedDecimal::zero(),
+        RatingState::Gaussian {
+            sigma: Decimal::from_str("500").unwrap(),
+        },
+    );
+    let mut player = low.clone();
+    gaussian.update_rating_period(&env, &mut player, &[(low, Decimal::zero())], &period);
+    assert!(player.value < SignedDecimal::zero());
+}
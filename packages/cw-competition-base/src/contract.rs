@@ -1,5 +1,6 @@
 use std::{
-    collections::{BTreeMap, HashMap},
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet, HashMap},
     marker::PhantomData,
 };
 
@@ -11,25 +12,30 @@ use arena_interface::{
             QueryBase, ToCompetitionExt,
         },
         state::{
-            Competition, CompetitionResponse, CompetitionStatus, Config, Evidence, TempCompetition,
+            Competition, CompetitionResponse, CompetitionStatus, Config, Evidence, HostResult,
+            TempCompetition,
         },
         stats::{
             MemberStatsMsg, StatAggregationType, StatMsg, StatTableEntry, StatType, StatValue,
             StatValueType,
         },
     },
-    core::{CompetitionModuleResponse, ProposeMessage, TaxConfigurationResponse},
-    fees::FeeInformation,
-    group::GroupContractInfo,
-    ratings::MemberResult,
+    core::{
+        CategoryMetadata, CompetitionModuleResponse, CompetitionSnapshot, JailBond, ProposeMessage,
+        Ruleset, RulesetVersion, SlashRecipient, TaxConfigurationResponse,
+    },
+    fees::{effective_rate, native_fee_shares, FeeInformation},
+    group::{self, GroupContractInfo, RosterMsg},
+    hosts::{HostActivity, HostBan},
+    ratings::{MemberResult, RatedMatch, TeamResult},
 };
 use cosmwasm_schema::schemars::JsonSchema;
 use cosmwasm_std::{
-    ensure, ensure_eq, instantiate2_address, to_json_binary, Addr, Binary, CosmosMsg, Decimal,
-    Deps, DepsMut, Empty, Env, MessageInfo, Order, Reply, Response, StdError, StdResult, Storage,
-    SubMsg, Uint128, WasmMsg,
+    ensure, from_json, instantiate2_address, to_json_binary, Addr, BankMsg, Binary, Coin,
+    CosmosMsg, Decimal, Deps, DepsMut, Empty, Env, MessageInfo, Order, Reply, Response, StdError,
+    StdResult, Storage, SubMsg, Uint128, WasmMsg,
 };
-use cw_balance::Distribution;
+use cw_balance::{BalanceVerified, Distribution, MemberBalanceChecked};
 use cw_ownable::{get_ownership, initialize_owner};
 use cw_storage_plus::{
     Bound, Index, IndexList, IndexedMap, Item, Map, MultiIndex, SnapshotMap, Strategy,
@@ -44,6 +50,9 @@ pub const PROCESS_REPLY_ID: u64 = 1;
 pub const UPDATE_RATING_FAILED_REPLY_ID: u64 = 2;
 pub const MIGRATE_ESCROW_ERROR_REPLY_ID: u64 = 3;
 pub const GROUP_INSTANTIATE_REPLY_ID: u64 = 4;
+pub const HOST_ACTIVITY_FAILED_REPLY_ID: u64 = 5;
+/// The most evidence ids, stat entries, and escrow balances kept in a jail proposal's snapshot
+pub const SNAPSHOT_LIMIT: u32 = 30;
 
 pub struct CompetitionIndexes<'a, CompetitionExt> {
     pub status: MultiIndex<'a, String, Competition<CompetitionExt>, u128>,
@@ -108,6 +117,8 @@ pub struct CompetitionModuleContract<
     pub competition_evidence_count: Map<'static, u128, Uint128>,
     pub competition_result: Map<'static, u128, Option<Distribution<Addr>>>,
     pub competition_rules: Map<'static, u128, Vec<String>>,
+    pub host_results: Map<'static, u128, HostResult>,
+    pub jail_bonds: Map<'static, (u128, &'a Addr), JailBond>,
     pub escrows_to_competitions: Map<'static, &'a Addr, u128>,
     pub temp_competition: Item<'static, TempCompetition<CompetitionInstantiateExt>>,
     pub temp_competition_id: Item<'static, u128>,
@@ -155,6 +166,8 @@ impl<
         competition_evidence_count_key: &'static str,
         competition_result_key: &'static str,
         competition_rules_key: &'static str,
+        host_results_key: &'static str,
+        jail_bonds_key: &'static str,
         stats_key: &'static str,
         stats_key_check: &'static str,
         stats_key_change: &'static str,
@@ -183,6 +196,8 @@ impl<
             competition_evidence_count: Map::new(competition_evidence_count_key),
             competition_result: Map::new(competition_result_key),
             competition_rules: Map::new(competition_rules_key),
+            host_results: Map::new(host_results_key),
+            jail_bonds: Map::new(jail_bonds_key),
             stats: SnapshotMap::new(
                 stats_key,
                 stats_key_check,
@@ -298,6 +313,8 @@ impl<
             "competition_evidence_count",
             "competition_result",
             "competition_rules",
+            "host_results",
+            "jail_bonds",
             "stats",
             "stats__check",
             "stats__change",
@@ -380,6 +397,7 @@ impl<
                 expiration,
                 rules,
                 rulesets,
+                ruleset_versions,
                 banner,
                 group_contract,
                 instantiate_extension,
@@ -395,6 +413,7 @@ impl<
                 expiration,
                 rules,
                 rulesets,
+                ruleset_versions,
                 banner,
                 group_contract,
                 instantiate_extension,
@@ -403,6 +422,19 @@ impl<
                 competition_id,
                 distribution,
             } => self.execute_process_competition(deps, info, competition_id, distribution, None),
+            ExecuteBase::ReleaseJailBond {
+                competition_id,
+                originator,
+                amount,
+                is_rejected,
+            } => self.execute_release_jail_bond(
+                deps,
+                info,
+                competition_id,
+                originator,
+                amount,
+                is_rejected,
+            ),
             ExecuteBase::UpdateOwnership(action) => {
                 let ownership =
                     cw_ownable::update_ownership(deps, &env.block, &info.sender, action)?;
@@ -415,6 +447,10 @@ impl<
                 competition_id: id,
                 evidence,
             } => self.execute_submit_evidence(deps, env, info, id, evidence),
+            ExecuteBase::SubmitResult {
+                competition_id,
+                distribution,
+            } => self.execute_submit_result(deps, env, info, competition_id, distribution),
             ExecuteBase::AddCompetitionHook { competition_id } => {
                 self.execute_add_competition_hook(deps, info, competition_id)
             }
@@ -466,10 +502,10 @@ impl<
         escrow_code_id: u64,
         escrow_migrate_msg: arena_interface::escrow::MigrateMsg,
     ) -> Result<Response, CompetitionError> {
-        // Ensure only the DAO can call this function
-        ensure_eq!(
-            info.sender,
-            self.query_dao(deps.as_ref())?,
+        // Ensure only the DAO or the arena core upgrading this module can call this function
+        ensure!(
+            info.sender == self.query_dao(deps.as_ref())?
+                || get_ownership(deps.storage)?.owner.as_ref() == Some(&info.sender),
             CompetitionError::Unauthorized {}
         );
 
@@ -548,6 +584,217 @@ impl<
             .add_attribute("evidence_count", evidence_id))
     }
 
+    pub fn execute_submit_result(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        competition_id: Uint128,
+        distribution: Option<Distribution<String>>,
+    ) -> Result<Response, CompetitionError> {
+        let competition = self
+            .competitions
+            .may_load(deps.storage, competition_id.u128())?
+            .ok_or(CompetitionError::UnknownCompetitionId { id: competition_id })?;
+
+        // Results can only be submitted until the competition is jailed
+        if !matches!(competition.status, CompetitionStatus::Active { .. }) {
+            return Err(CompetitionError::InvalidCompetitionStatus {
+                current_status: competition.status,
+            });
+        }
+        ensure!(
+            competition.host == info.sender,
+            CompetitionError::Unauthorized {}
+        );
+
+        let distribution = distribution
+            .map(|x| x.into_checked(deps.as_ref()))
+            .transpose()?;
+        self.host_results.save(
+            deps.storage,
+            competition_id.u128(),
+            &HostResult {
+                distribution,
+                height: env.block.height,
+            },
+        )?;
+
+        Ok(Response::new()
+            .add_attribute("action", "submit_result")
+            .add_attribute("competition_id", competition_id)
+            .add_attribute("host", info.sender))
+    }
+
+    /// Captures the competition's evidence, stats, progress, escrow balances, and host result for a jail proposal
+    /// Each list keeps up to SNAPSHOT_LIMIT entries, and the rest can be queried at the snapshot's height
+    fn competition_snapshot(
+        &self,
+        deps: Deps,
+        env: &Env,
+        competition: &Competition<CompetitionExt>,
+    ) -> StdResult<CompetitionSnapshot> {
+        let limit = SNAPSHOT_LIMIT as usize;
+        let mut evidence_ids = self
+            .competition_evidence
+            .prefix(competition.id.u128())
+            .keys(deps.storage, None, None, Order::Ascending)
+            .take(limit + 1)
+            .map(|x| x.map(Uint128::new))
+            .collect::<StdResult<Vec<_>>>()?;
+        let mut escrow_balances = competition
+            .escrow
+            .as_ref()
+            .map(|escrow| {
+                deps.querier.query_wasm_smart::<Vec<MemberBalanceChecked>>(
+                    escrow.to_string(),
+                    &arena_interface::escrow::QueryMsg::Balances {
+                        start_after: None,
+                        limit: Some(SNAPSHOT_LIMIT + 1),
+                    },
+                )
+            })
+            .transpose()?;
+        let has_more_stats = self
+            .stats
+            .sub_prefix(competition.id.u128())
+            .keys_raw(deps.storage, None, None, Order::Ascending)
+            .nth(limit)
+            .is_some();
+
+        let is_truncated = has_more_stats
+            || evidence_ids.len() > limit
+            || escrow_balances.as_ref().is_some_and(|x| x.len() > limit);
+        evidence_ids.truncate(limit);
+        if let Some(escrow_balances) = escrow_balances.as_mut() {
+            escrow_balances.truncate(limit);
+        }
+
+        Ok(CompetitionSnapshot {
+            height: env.block.height,
+            evidence_ids,
+            stats: self.query_stats_table(deps, competition.id, None, Some(SNAPSHOT_LIMIT))?,
+            extension: to_json_binary(&competition.extension)?,
+            escrow_balances,
+            host_result: self
+                .host_results
+                .may_load(deps.storage, competition.id.u128())?,
+            is_truncated,
+        })
+    }
+
+    /// Settles a jailed competition's bonds against the DAO's resolution
+    /// Bonds are slashed if the resolution upholds the host's last submitted result, and refunded otherwise
+    fn settle_jail_bonds(
+        &self,
+        storage: &mut dyn Storage,
+        competition: &Competition<CompetitionExt>,
+        distribution: Option<&Distribution<Addr>>,
+    ) -> Result<Vec<CosmosMsg>, CompetitionError> {
+        let is_upheld = self
+            .host_results
+            .may_load(storage, competition.id.u128())?
+            .is_some_and(|host_result| host_result.distribution.as_ref() == distribution);
+        let bonds = self
+            .jail_bonds
+            .prefix(competition.id.u128())
+            .range(storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+
+        let mut msgs = vec![];
+        for (originator, bond) in bonds {
+            msgs.extend(self.settle_jail_bond(
+                storage,
+                competition,
+                &originator,
+                bond.amount,
+                is_upheld,
+            )?);
+        }
+
+        Ok(msgs)
+    }
+
+    /// Settles up to the given amount of an originator's jail bond
+    /// The amount is slashed to the bond's slash recipient if the host is upheld, and refunded otherwise
+    fn settle_jail_bond(
+        &self,
+        storage: &mut dyn Storage,
+        competition: &Competition<CompetitionExt>,
+        originator: &Addr,
+        amount: Coin,
+        is_upheld: bool,
+    ) -> Result<Option<CosmosMsg>, CompetitionError> {
+        let key = (competition.id.u128(), originator);
+        let Some(mut bond) = self.jail_bonds.may_load(storage, key)? else {
+            return Ok(None);
+        };
+        ensure!(
+            bond.amount.denom == amount.denom,
+            StdError::generic_err("The settled amount does not match the jail bond's denom")
+        );
+
+        let settled = amount.amount.min(bond.amount.amount);
+        bond.amount.amount -= settled;
+        if bond.amount.amount.is_zero() {
+            self.jail_bonds.remove(storage, key);
+        } else {
+            self.jail_bonds.save(storage, key, &bond)?;
+        }
+        if settled.is_zero() {
+            return Ok(None);
+        }
+
+        let recipient = match (is_upheld, bond.slash_recipient) {
+            (false, _) => originator.clone(),
+            (true, SlashRecipient::Host {}) => competition.host.clone(),
+            (true, SlashRecipient::Dao {}) => competition.admin_dao.clone(),
+        };
+
+        Ok(Some(CosmosMsg::Bank(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![Coin {
+                denom: amount.denom,
+                amount: settled,
+            }],
+        })))
+    }
+
+    /// Settles a jail bond whose proposal completed without resolving the competition
+    /// A rejected proposal upholds the host's submitted result, so its bond is slashed
+    /// The bond is refunded if the DAO never ruled on the jail or the host has no result to uphold
+    /// Bonds already settled by the DAO's resolution are skipped
+    pub fn execute_release_jail_bond(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        competition_id: Uint128,
+        originator: String,
+        amount: Coin,
+        is_rejected: bool,
+    ) -> Result<Response, CompetitionError> {
+        ensure!(
+            get_ownership(deps.storage)?.owner.as_ref() == Some(&info.sender),
+            CompetitionError::Unauthorized {}
+        );
+        let originator = deps.api.addr_validate(&originator)?;
+        let competition = self
+            .competitions
+            .may_load(deps.storage, competition_id.u128())?
+            .ok_or(CompetitionError::UnknownCompetitionId { id: competition_id })?;
+
+        let is_upheld = is_rejected && self.host_results.has(deps.storage, competition_id.u128());
+        let msg =
+            self.settle_jail_bond(deps.storage, &competition, &originator, amount, is_upheld)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "release_jail_bond")
+            .add_attribute("competition_id", competition_id)
+            .add_attribute("originator", originator)
+            .add_attribute("is_upheld", is_upheld.to_string())
+            .add_messages(msg))
+    }
+
     pub fn validate_execute_hook(
         &self,
         deps: DepsMut,
@@ -696,7 +943,9 @@ impl<
         ))?;
 
         // Update competition status
-        self.competitions
+        let mut is_newly_jailed = false;
+        let competition = self
+            .competitions
             .update(deps.storage, competition_id.u128(), |x| {
                 let mut competition =
                     x.ok_or(CompetitionError::UnknownCompetitionId { id: competition_id })?;
@@ -708,6 +957,7 @@ impl<
                             return Err(CompetitionError::CompetitionNotExpired {});
                         }
 
+                        is_newly_jailed = true;
                         Ok(activation_height)
                     }
                     CompetitionStatus::Jailed { activation_height } => Ok(activation_height),
@@ -720,6 +970,58 @@ impl<
                 Ok(competition)
             })?;
 
+        // Withhold the category's jail bond from the proposal deposit
+        let mut funds = info.funds;
+        let mut withheld = None;
+        let jail_bond = match competition.category_id {
+            Some(category_id) => deps
+                .querier
+                .query_wasm_smart::<Option<CategoryMetadata>>(
+                    arena_core.to_string(),
+                    &arena_interface::core::QueryMsg::QueryExtension {
+                        msg: arena_interface::core::QueryExt::CategoryMetadata { category_id },
+                    },
+                )?
+                .and_then(|metadata| metadata.jail_bond),
+            None => None,
+        };
+        if let Some(jail_bond) = jail_bond {
+            let coin = funds
+                .iter_mut()
+                .find(|x| x.denom == jail_bond.amount.denom && x.amount >= jail_bond.amount.amount)
+                .ok_or_else(|| {
+                    StdError::generic_err(format!(
+                        "Jailing the competition requires a bond of {}",
+                        jail_bond.amount
+                    ))
+                })?;
+            coin.amount -= jail_bond.amount.amount;
+            funds.retain(|x| !x.amount.is_zero());
+            withheld = Some(jail_bond.amount.clone());
+
+            self.jail_bonds.update(
+                deps.storage,
+                (competition_id.u128(), &info.sender),
+                |existing| -> StdResult<_> {
+                    Ok(match existing {
+                        // Repeated jail proposals add to the originator's bond
+                        Some(mut existing) => {
+                            ensure!(
+                                existing.amount.denom == jail_bond.amount.denom,
+                                StdError::generic_err("The jail bond's denom has changed")
+                            );
+                            existing.amount.amount = existing
+                                .amount
+                                .amount
+                                .checked_add(jail_bond.amount.amount)?;
+                            existing
+                        }
+                        None => jail_bond,
+                    })
+                },
+            )?;
+        }
+
         // Create the proposal
         let msg = CosmosMsg::Wasm(WasmMsg::Execute {
             contract_addr: arena_core.to_string(),
@@ -730,16 +1032,29 @@ impl<
                     description,
                     distribution,
                     originator: info.sender.to_string(),
+                    snapshot: Some(self.competition_snapshot(deps.as_ref(), &env, &competition)?),
+                    jail_bond: withheld,
                 },
             })?,
-            funds: info.funds,
+            funds,
         });
 
-        Ok(Response::new()
+        let mut response = Response::new()
             .add_attribute("action", "jail_wager")
             .add_attribute("competition_id", competition_id)
             .add_attribute("originator", info.sender)
-            .add_message(msg))
+            .add_message(msg);
+
+        // Repeated jail proposals only count against the host once
+        if is_newly_jailed {
+            response = response.add_submessage(self.trigger_host_activity(
+                deps.storage,
+                &competition.host,
+                HostActivity::Jail {},
+            )?);
+        }
+
+        Ok(response)
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -756,6 +1071,7 @@ impl<
         expiration: cw_utils::Expiration,
         rules: Option<Vec<String>>,
         rulesets: Option<Vec<Uint128>>,
+        ruleset_versions: Option<Vec<RulesetVersion>>,
         banner: Option<String>,
         group_contract: GroupContractInfo,
         extension: CompetitionInstantiateExt,
@@ -796,6 +1112,51 @@ impl<
             info.sender.clone()
         };
 
+        // Banned hosts cannot create competitions until their ban expires
+        let host_ban: Option<HostBan<Addr>> = deps.querier.query_wasm_smart(
+            arena_core.to_string(),
+            &arena_interface::core::QueryMsg::QueryExtension {
+                msg: arena_interface::core::QueryExt::HostBan {
+                    host: host.to_string(),
+                },
+            },
+        )?;
+        if let Some(host_ban) = host_ban {
+            return Err(CompetitionError::StdError(StdError::generic_err(format!(
+                "The host {} is banned: {}",
+                host, host_ban.reason
+            ))));
+        }
+
+        // Categories can restrict the competition modules hosting them and provide default rulesets
+        let rulesets = match category_id {
+            Some(category_id) => {
+                let metadata: Option<CategoryMetadata> = deps.querier.query_wasm_smart(
+                    arena_core.to_string(),
+                    &arena_interface::core::QueryMsg::QueryExtension {
+                        msg: arena_interface::core::QueryExt::CategoryMetadata { category_id },
+                    },
+                )?;
+
+                match metadata {
+                    Some(metadata) => {
+                        let key = self.config.load(deps.storage)?.key;
+                        ensure!(
+                            metadata.allows_module(&key),
+                            CompetitionError::StdError(StdError::generic_err(format!(
+                                "The category does not allow {} competitions",
+                                key
+                            )))
+                        );
+
+                        rulesets.or(metadata.default_rulesets)
+                    }
+                    None => rulesets,
+                }
+            }
+            None => rulesets,
+        };
+
         // Increment competition count
         let competition_id = self
             .competition_count
@@ -817,6 +1178,69 @@ impl<
                 })
                 .transpose()?;
 
+            // The Arena tax and additional fees cannot take more of the prize pool than the fee ceiling
+            let fee_ceiling: Option<Decimal> = deps.querier.query_wasm_smart(
+                arena_core.to_string(),
+                &arena_interface::core::QueryMsg::QueryExtension {
+                    msg: arena_interface::core::QueryExt::FeeCeiling {},
+                },
+            )?;
+            if let Some(fee_ceiling) = fee_ceiling {
+                let key = self.config.load(deps.storage)?.key;
+                let arena_tax_config =
+                    self.query_arena_tax_config(deps.as_ref(), env.block.height, category_id, key)?;
+                let rate = effective_rate(
+                    std::iter::once(arena_tax_config.tax)
+                        .chain(fees.iter().flatten().map(|fee| fee.tax)),
+                )?;
+
+                ensure!(
+                    rate <= fee_ceiling,
+                    CompetitionError::StdError(StdError::generic_err(format!(
+                        "The combined fee rate of {} exceeds the fee ceiling of {}",
+                        rate, fee_ceiling
+                    )))
+                );
+
+                // Minimum fees can take more than their rate, so they are counted against the prize pool of the escrow's dues
+                if fees
+                    .iter()
+                    .flatten()
+                    .any(|fee| fee.min_amounts.as_ref().is_some_and(|x| !x.is_empty()))
+                {
+                    let dues =
+                        from_json::<arena_interface::escrow::InstantiateMsg>(&escrow.msg)?.dues;
+                    let prize_pool = dues.into_iter().try_fold(
+                        BalanceVerified::new(),
+                        |acc, member_balance| {
+                            acc.checked_add(&member_balance.balance.into_checked(deps.as_ref())?)
+                        },
+                    )?;
+                    let arena_tax = FeeInformation {
+                        tax: arena_tax_config
+                            .tax_for_prize_pool(prize_pool.native.as_deref().unwrap_or_default()),
+                        receiver: admin_dao.clone(),
+                        cw20_msg: None,
+                        cw721_msg: None,
+                        min_amounts: None,
+                        max_amounts: None,
+                    };
+                    let layered_fees = std::iter::once(arena_tax)
+                        .chain(fees.iter().flatten().cloned())
+                        .collect::<Vec<_>>();
+
+                    for (denom, share) in native_fee_shares(&layered_fees, &prize_pool)? {
+                        ensure!(
+                            share <= fee_ceiling,
+                            CompetitionError::StdError(StdError::generic_err(format!(
+                                "The fees take {} of the {} prize pool, exceeding the fee ceiling of {}",
+                                share, denom, fee_ceiling
+                            )))
+                        );
+                    }
+                }
+            }
+
             let binding = format!("{}{}{}", info.sender, env.block.height, competition_id);
             let salt: [u8; 32] = Sha256::digest(binding.as_bytes()).into();
             let canonical_creator = deps.api.addr_canonicalize(env.contract.address.as_str())?;
@@ -856,7 +1280,7 @@ impl<
             if let Some(rulesets) = rulesets.as_ref() {
                 if !rulesets.is_empty() {
                     let is_valid: bool = deps.querier.query_wasm_smart(
-                        arena_core,
+                        arena_core.to_string(),
                         &arena_interface::core::QueryMsg::QueryExtension {
                             msg: arena_interface::core::QueryExt::IsValidCategoryAndRulesets {
                                 category_id,
@@ -874,6 +1298,19 @@ impl<
             }
         }
 
+        let ruleset_versions = rulesets
+            .as_ref()
+            .filter(|x| !x.is_empty())
+            .map(|rulesets| {
+                self.resolve_ruleset_versions(
+                    deps.as_ref(),
+                    &arena_core,
+                    rulesets,
+                    ruleset_versions,
+                )
+            })
+            .transpose()?;
+
         // Save rules
         if let Some(rules) = rules {
             self.competition_rules
@@ -891,7 +1328,12 @@ impl<
                     .map_or_else(|| "None".to_string(), |addr| addr.to_string()),
             )
             .add_attribute("host", host.to_string())
-            .add_messages(msgs);
+            .add_messages(msgs)
+            .add_submessage(self.trigger_host_activity(
+                deps.storage,
+                &host,
+                HostActivity::Create {},
+            )?);
 
         match group_contract {
             GroupContractInfo::Existing { addr } => {
@@ -909,6 +1351,7 @@ impl<
                     description,
                     expiration,
                     rulesets,
+                    ruleset_versions,
                     status,
                     extension: extension.to_competition_ext(deps.as_ref(), &group_contract)?,
                     fees,
@@ -935,6 +1378,7 @@ impl<
                         start_height: env.block.height,
                         expiration,
                         rulesets,
+                        ruleset_versions,
                         status,
                         fees,
                         banner,
@@ -956,6 +1400,65 @@ impl<
         }
     }
 
+    /// Rulesets without a pinned version are pinned to their current version
+    fn resolve_ruleset_versions(
+        &self,
+        deps: Deps,
+        arena_core: &Addr,
+        rulesets: &[Uint128],
+        ruleset_versions: Option<Vec<RulesetVersion>>,
+    ) -> Result<Vec<RulesetVersion>, CompetitionError> {
+        let Some(ruleset_versions) = ruleset_versions else {
+            return rulesets
+                .iter()
+                .map(|id| {
+                    let ruleset: Option<Ruleset> = deps.querier.query_wasm_smart(
+                        arena_core.to_string(),
+                        &arena_interface::core::QueryMsg::QueryExtension {
+                            msg: arena_interface::core::QueryExt::Ruleset { id: *id },
+                        },
+                    )?;
+                    let ruleset = ruleset.ok_or_else(|| {
+                        StdError::generic_err(format!("Could not find the ruleset {}", id))
+                    })?;
+
+                    Ok(RulesetVersion::Pinned {
+                        version: ruleset.current_version(),
+                    })
+                })
+                .collect();
+        };
+
+        ensure!(
+            ruleset_versions.len() == rulesets.len(),
+            CompetitionError::StdError(StdError::generic_err(
+                "Each ruleset must have a ruleset version"
+            ))
+        );
+        for (id, ruleset_version) in rulesets.iter().zip(&ruleset_versions) {
+            if let RulesetVersion::Pinned { version } = ruleset_version {
+                let ruleset: Option<Ruleset> = deps.querier.query_wasm_smart(
+                    arena_core.to_string(),
+                    &arena_interface::core::QueryMsg::QueryExtension {
+                        msg: arena_interface::core::QueryExt::RulesetVersion {
+                            id: *id,
+                            version: *version,
+                        },
+                    },
+                )?;
+                ensure!(
+                    ruleset.is_some(),
+                    CompetitionError::StdError(StdError::generic_err(format!(
+                        "Could not find version {} of the ruleset {}",
+                        version, id
+                    )))
+                );
+            }
+        }
+
+        Ok(ruleset_versions)
+    }
+
     #[allow(clippy::type_complexity)]
     pub fn execute_process_competition(
         &self,
@@ -985,9 +1488,17 @@ impl<
             .map(|some| some.into_checked(deps.as_ref()))
             .transpose()?;
 
+        // The DAO's resolution of a jailed competition settles its jail bonds
+        let bond_msgs = if matches!(competition.status, CompetitionStatus::Jailed { .. }) {
+            self.settle_jail_bonds(deps.storage, &competition, validated_distribution.as_ref())?
+        } else {
+            vec![]
+        };
+
         // Process the competition
-        let mut response =
-            self.inner_process(deps.branch(), &competition, validated_distribution)?;
+        let mut response = self
+            .inner_process(deps.branch(), &competition, validated_distribution)?
+            .add_messages(bond_msgs);
 
         // Post-processing
         if let Some(post_processing) = post_processing {
@@ -1071,22 +1582,58 @@ impl<
                 }))
             })
             .collect();
+        let mut earnings = vec![];
 
         // If there's an escrow, handle distribution, tax, and fees
         if let Some(escrow) = &competition.escrow {
             // Get Arena Tax config
-            let arena_tax_config =
-                self.query_arena_tax_config(deps.as_ref(), competition.start_height)?;
+            let key = self.config.load(deps.storage)?.key;
+            let arena_tax_config = self.query_arena_tax_config(
+                deps.as_ref(),
+                competition.start_height,
+                competition.category_id,
+                key,
+            )?;
+
+            // The prize pool is only needed for volume tiers and the host's fee earnings
+            let has_host_fees = competition
+                .fees
+                .iter()
+                .flatten()
+                .any(|fee| fee.receiver == competition.host);
+            let prize_pool: Option<BalanceVerified> =
+                if arena_tax_config.volume_tiers.is_some() || has_host_fees {
+                    deps.querier.query_wasm_smart(
+                        escrow.to_string(),
+                        &arena_interface::escrow::QueryMsg::TotalBalance {},
+                    )?
+                } else {
+                    None
+                };
+
+            // Volume tiers are resolved against the escrow's native prize pool
+            let tax = if arena_tax_config.volume_tiers.is_some() {
+                arena_tax_config.tax_for_prize_pool(
+                    prize_pool
+                        .as_ref()
+                        .and_then(|x| x.native.as_deref())
+                        .unwrap_or_default(),
+                )
+            } else {
+                arena_tax_config.tax
+            };
 
             let mut layered_fees = vec![];
 
             // Apply Arena Tax
-            if !arena_tax_config.tax.is_zero() {
+            if !tax.is_zero() {
                 layered_fees.push(FeeInformation {
-                    tax: arena_tax_config.tax,
+                    tax,
                     receiver: competition.admin_dao.to_string(),
                     cw20_msg: arena_tax_config.cw20_msg.clone(),
                     cw721_msg: arena_tax_config.cw721_msg.clone(),
+                    min_amounts: None,
+                    max_amounts: None,
                 });
             }
 
@@ -1097,9 +1644,24 @@ impl<
                     receiver: x.receiver.to_string(),
                     cw20_msg: x.cw20_msg.clone(),
                     cw721_msg: x.cw721_msg.clone(),
+                    min_amounts: x.min_amounts.clone(),
+                    max_amounts: x.max_amounts.clone(),
                 }));
             }
 
+            // Simulate the escrow's layered fees to find the host's share
+            if let Some(prize_pool) = prize_pool.filter(|_| has_host_fees) {
+                let mut remaining = prize_pool;
+                for fee in &layered_fees {
+                    let fee_amounts = fee.fee_amounts(&remaining)?;
+                    remaining = remaining.checked_sub(&fee_amounts)?;
+
+                    if fee.receiver == competition.host.as_str() {
+                        earnings.extend(fee_amounts.native.unwrap_or_default());
+                    }
+                }
+            }
+
             let layered_fees = if layered_fees.is_empty() {
                 None
             } else {
@@ -1138,6 +1700,12 @@ impl<
             }?;
         }
 
+        msgs.push(self.trigger_host_activity(
+            deps.storage,
+            &competition.host,
+            HostActivity::Process { earnings },
+        )?);
+
         // Tax info is displayed in the escrow response
         Ok(Response::new()
             .add_attribute("action", "process_competition")
@@ -1150,12 +1718,42 @@ impl<
             .add_submessages(msgs))
     }
 
+    // Reports a host's competition activity to the arena core without blocking the competition if it fails
+    pub fn trigger_host_activity(
+        &self,
+        storage: &dyn Storage,
+        host: &Addr,
+        activity: HostActivity,
+    ) -> Result<SubMsg, CompetitionError> {
+        // Ensure Module has an owner
+        let ownership = get_ownership(storage)?;
+        let arena_core = ownership.owner.ok_or(CompetitionError::OwnershipError(
+            cw_ownable::OwnershipError::NoOwner,
+        ))?;
+
+        Ok(SubMsg::reply_on_error(
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: arena_core.to_string(),
+                msg: to_json_binary(&arena_interface::core::ExecuteMsg::Extension {
+                    msg: arena_interface::core::ExecuteExt::RecordHostActivity {
+                        host: host.to_string(),
+                        activity,
+                    },
+                })?,
+                funds: vec![],
+            }),
+            HOST_ACTIVITY_FAILED_REPLY_ID,
+        ))
+    }
+
     // This method is meant to be called when the competition between 2 competitors is processed to trigger a rating adjustment on the arena core for the competition's category
     pub fn trigger_rating_adjustment(
         &self,
-        storage: &mut dyn Storage,
+        storage: &dyn Storage,
         category_id: Uint128,
+        competition_id: Uint128,
         member_results: Vec<(MemberResult<Addr>, MemberResult<Addr>)>,
+        match_numbers: Option<Vec<Uint128>>,
     ) -> Result<SubMsg, CompetitionError> {
         // Ensure Module has an owner
         let ownership = get_ownership(storage)?;
@@ -1175,6 +1773,104 @@ impl<
                                 (member_result_1.into(), member_result_2.into())
                             })
                             .collect(),
+                        competition_id: Some(competition_id),
+                        match_numbers,
+                    },
+                })?,
+                funds: vec![],
+            }),
+            UPDATE_RATING_FAILED_REPLY_ID,
+        ))
+    }
+
+    // Like trigger_rating_adjustment, but competitors with a roster in the group contract are rated as teams, so the result is applied to each of the roster's players
+    pub fn trigger_team_rating_adjustment(
+        &self,
+        deps: Deps,
+        group_contract: &Addr,
+        category_id: Uint128,
+        competition_id: Uint128,
+        member_results: Vec<(MemberResult<Addr>, MemberResult<Addr>)>,
+        match_numbers: Option<Vec<Uint128>>,
+    ) -> Result<SubMsg, CompetitionError> {
+        let addrs: BTreeSet<String> = member_results
+            .iter()
+            .flat_map(|(member_result_1, member_result_2)| {
+                [
+                    member_result_1.addr.to_string(),
+                    member_result_2.addr.to_string(),
+                ]
+            })
+            .collect();
+        // Group contracts from before rosters existed rate members individually
+        let rosters: Vec<RosterMsg<Addr>> = deps
+            .querier
+            .query_wasm_smart(
+                group_contract.to_string(),
+                &group::QueryMsg::Rosters {
+                    addrs: addrs.into_iter().collect(),
+                },
+            )
+            .unwrap_or_default();
+
+        if rosters.is_empty() {
+            return self.trigger_rating_adjustment(
+                deps.storage,
+                category_id,
+                competition_id,
+                member_results,
+                match_numbers,
+            );
+        }
+
+        let rosters: HashMap<Addr, Vec<Addr>> = rosters
+            .into_iter()
+            .map(|roster| (roster.addr, roster.players))
+            .collect();
+        let to_team = |member_result: &MemberResult<Addr>, placement: u32| TeamResult {
+            members: rosters
+                .get(&member_result.addr)
+                .cloned()
+                .unwrap_or_else(|| vec![member_result.addr.clone()]),
+            placement,
+        };
+
+        let matches: Vec<RatedMatch<String>> = member_results
+            .iter()
+            .enumerate()
+            .map(|(i, (member_result_1, member_result_2))| {
+                let (placement_1, placement_2) =
+                    match member_result_1.result.cmp(&member_result_2.result) {
+                        Ordering::Greater => (1, 2),
+                        Ordering::Equal => (1, 1),
+                        Ordering::Less => (2, 1),
+                    };
+
+                RatedMatch {
+                    teams: vec![
+                        to_team(member_result_1, placement_1),
+                        to_team(member_result_2, placement_2),
+                    ],
+                    match_number: match_numbers.as_ref().map(|x| x[i]),
+                }
+                .into()
+            })
+            .collect();
+
+        // Ensure Module has an owner
+        let ownership = get_ownership(deps.storage)?;
+        let arena_core = ownership.owner.ok_or(CompetitionError::OwnershipError(
+            cw_ownable::OwnershipError::NoOwner,
+        ))?;
+
+        Ok(SubMsg::reply_on_error(
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: arena_core.to_string(),
+                msg: to_json_binary(&arena_interface::core::ExecuteMsg::Extension {
+                    msg: arena_interface::core::ExecuteExt::AdjustTeamRatings {
+                        category_id,
+                        matches,
+                        competition_id: Some(competition_id),
                     },
                 })?,
                 funds: vec![],
@@ -1309,6 +2005,18 @@ impl<
             QueryBase::Result { competition_id } => {
                 to_json_binary(&self.query_result(deps, competition_id)?)
             }
+            QueryBase::HostResult { competition_id } => to_json_binary(
+                &self
+                    .host_results
+                    .may_load(deps.storage, competition_id.u128())?,
+            ),
+            QueryBase::JailBond {
+                competition_id,
+                addr,
+            } => to_json_binary(&self.jail_bonds.may_load(
+                deps.storage,
+                (competition_id.u128(), &deps.api.addr_validate(&addr)?),
+            )?),
             QueryBase::Evidence {
                 competition_id,
                 start_after,
@@ -1652,6 +2360,8 @@ impl<
         &self,
         deps: Deps,
         height: u64,
+        category_id: Option<Uint128>,
+        key: String,
     ) -> Result<TaxConfigurationResponse, CompetitionError> {
         let owner = get_ownership(deps.storage)?
             .owner
@@ -1663,7 +2373,11 @@ impl<
             .query_wasm_smart(
                 owner,
                 &arena_interface::core::QueryMsg::QueryExtension {
-                    msg: arena_interface::core::QueryExt::TaxConfig { height },
+                    msg: arena_interface::core::QueryExt::TaxConfig {
+                        height,
+                        category_id,
+                        module: Some(key),
+                    },
                 },
             )
             .map_err(Into::into)
@@ -1775,6 +2489,7 @@ impl<
             UPDATE_RATING_FAILED_REPLY_ID => self.reply_update_rating_failed(deps, msg),
             MIGRATE_ESCROW_ERROR_REPLY_ID => self.reply_migrate_escrow_error(deps, msg),
             GROUP_INSTANTIATE_REPLY_ID => self.reply_group_instantiate_reply(deps, msg),
+            HOST_ACTIVITY_FAILED_REPLY_ID => self.reply_host_activity_failed(deps, msg),
             _ => Err(CompetitionError::UnknownReplyId { id: msg.id }),
         }
     }
@@ -1807,6 +2522,15 @@ impl<
         Ok(Response::new().add_attribute("action", "update_rating_failed"))
     }
 
+    pub fn reply_host_activity_failed(
+        &self,
+        _deps: DepsMut,
+        _msg: Reply,
+    ) -> Result<Response, CompetitionError> {
+        // Host stats are informational, so they should not block the competition
+        Ok(Response::new().add_attribute("action", "host_activity_failed"))
+    }
+
     pub fn reply_migrate_escrow_error(
         &self,
         _deps: DepsMut,
@@ -1862,6 +2586,7 @@ impl<
                 start_height: temp_competition.start_height,
                 expiration: temp_competition.expiration,
                 rulesets: temp_competition.rulesets,
+                ruleset_versions: temp_competition.ruleset_versions,
                 status: temp_competition.status,
                 extension,
                 fees: temp_competition.fees,
@@ -3,6 +3,7 @@ use cosmwasm_std::{
     CheckedFromRatioError, DecimalRangeExceeded, Instantiate2AddressError, OverflowError, StdError,
     Uint128,
 };
+use cw_balance::BalanceError;
 use cw_ownable::OwnershipError;
 use cw_utils::ParseReplyError;
 use thiserror::Error;
@@ -30,6 +31,9 @@ pub enum CompetitionError {
     #[error("{0}")]
     Instantiate2AddressError(#[from] Instantiate2AddressError),
 
+    #[error("{0}")]
+    BalanceError(#[from] BalanceError),
+
     #[error("Unauthorized")]
     Unauthorized {},
 
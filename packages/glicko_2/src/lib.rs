@@ -1,7 +1,7 @@
 mod rating;
-mod util;
+pub mod util;
 
 #[cfg(test)]
 mod tests;
 
-pub use rating::update_rating;
+pub use rating::{update_rating, update_rating_period, Glicko2, Glicko2Config};
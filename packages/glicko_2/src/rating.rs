@@ -1,12 +1,12 @@
 use std::f64::consts::PI;
 
-use arena_interface::ratings::Rating;
+use arena_interface::ratings::{Rating, RatingCalculator, RatingConfig, RatingState};
 use cosmwasm_std::{BlockInfo, Decimal as StdDecimal, Env};
 use cw_utils::Duration;
 use rust_decimal::prelude::*;
 use rust_decimal_macros::dec;
 
-use super::util::{rust_to_std, std_to_rust};
+use super::util::{logistic, rust_to_signed, rust_to_std, signed_to_rust, std_to_rust};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct RatingInternal {
@@ -25,27 +25,78 @@ impl RatingInternal {
             last_block: None,
         }
     }
+
+    /// Ratings coming from another system start from the initial deviation and volatility
+    pub fn from_rating(rating: Rating, config: &Glicko2Config) -> Self {
+        let (phi, sigma) = match rating.state {
+            RatingState::Glicko2 { phi, sigma } => (std_to_rust(phi), std_to_rust(sigma)),
+            _ => (config.initial_phi, config.initial_sigma),
+        };
+
+        RatingInternal {
+            value: signed_to_rust(rating.value),
+            phi,
+            sigma,
+            last_block: rating.last_block,
+        }
+    }
 }
 
 pub const DEFAULT_RATING: Decimal = dec!(1500);
-#[allow(dead_code)]
+/// The deviation given to unrated members and to ratings coming from another system
+pub const INITIAL_PHI: Decimal = dec!(300);
+/// The highest deviation reached through inactivity
 pub const PHI: Decimal = dec!(350);
-#[allow(dead_code)]
 pub const SIGMA: Decimal = dec!(0.06);
 pub const TAU: Decimal = Decimal::ONE;
 pub const EPSILON: Decimal = dec!(0.000001);
 pub const SCALING_FACTOR: Decimal = dec!(173.7178);
 
+/// The parameters of the rating system, defaulting to the constants above
+#[derive(Clone, Debug, PartialEq)]
+pub struct Glicko2Config {
+    /// Constrains the change in volatility over time
+    pub tau: Decimal,
+    /// The rating of unrated members, which is also the center of the Glicko-2 scale
+    pub initial_rating: Decimal,
+    pub initial_phi: Decimal,
+    pub initial_sigma: Decimal,
+    pub max_phi: Decimal,
+}
+
+impl Default for Glicko2Config {
+    fn default() -> Self {
+        Self {
+            tau: TAU,
+            initial_rating: DEFAULT_RATING,
+            initial_phi: INITIAL_PHI,
+            initial_sigma: SIGMA,
+            max_phi: PHI,
+        }
+    }
+}
+
+impl From<&RatingConfig> for Glicko2Config {
+    fn from(config: &RatingConfig) -> Self {
+        Self {
+            tau: std_to_rust(config.tau),
+            initial_rating: signed_to_rust(config.initial_rating),
+            initial_phi: std_to_rust(config.initial_deviation),
+            initial_sigma: std_to_rust(config.initial_volatility),
+            max_phi: std_to_rust(config.max_deviation),
+        }
+    }
+}
+
 pub fn reduce_impact(phi: Decimal) -> Decimal {
     Decimal::ONE
-        / (Decimal::ONE
-            + (dec!(3) * phi * phi / Decimal::from_f64(PI * PI).unwrap())
-                .sqrt()
-                .unwrap())
+        / (Decimal::ONE + dec!(3) * phi * phi / Decimal::from_f64(PI * PI).unwrap())
+            .sqrt()
+            .unwrap()
 }
 
 pub fn expect_score(mu: Decimal, other_mu: Decimal, impact: Decimal) -> Decimal {
-    Decimal::ONE / (Decimal::ONE + ((other_mu - mu) * impact).exp())
+    logistic((other_mu - mu) * impact)
 }
 
 /// Calculates the periods based on the previous and current block info.
@@ -67,35 +118,50 @@ pub fn calculate_periods(
     }
 }
 
-/// Adjusts the rating deviation (phi) for the periods of inactivity.
-pub fn adjust_phi_for_periods(phi: Decimal, sigma: Decimal, periods: Decimal) -> Decimal {
-    (phi.powu(2) + sigma.powu(2) * periods).sqrt().unwrap()
+/// Adjusts the rating deviation (phi) for the periods of inactivity, up to the configured cap.
+///
+/// The deviation is on the rating scale while the volatility is on the Glicko-2 scale, so the
+/// volatility is scaled up first.
+pub fn adjust_phi_for_periods(
+    config: &Glicko2Config,
+    phi: Decimal,
+    sigma: Decimal,
+    periods: Decimal,
+) -> Decimal {
+    let adjusted = (phi.powu(2) + (sigma * SCALING_FACTOR).powu(2) * periods)
+        .sqrt()
+        .unwrap();
+
+    // A deviation already above the cap is not lowered by inactivity
+    adjusted.min(config.max_phi.max(phi))
 }
 
 /// Calculates the new volatility (sigma) of the rating.
 pub fn calculate_new_sigma(
+    config: &Glicko2Config,
     sigma: Decimal,
     phi: Decimal,
     variance: Decimal,
     difference: Decimal,
 ) -> Decimal {
-    let alpha = sigma.ln();
+    let tau = config.tau;
+    let alpha = (sigma * sigma).ln();
     let mut a = alpha;
     let mut b: Decimal;
     let f = |x: Decimal| {
         let tmp = phi.powu(2) + variance + x.exp();
         (x.exp() * (difference.powu(2) - tmp) / (Decimal::TWO * tmp.powu(2)))
-            - ((x - alpha) / TAU.powu(2))
+            - ((x - alpha) / tau.powu(2))
     };
 
     if difference.powu(2) > phi.powu(2) + variance {
         b = (difference.powu(2) - phi.powu(2) - variance).ln();
     } else {
         let mut k = Decimal::ONE;
-        while f(alpha - k * TAU) < Decimal::ZERO {
+        while f(alpha - k * tau) < Decimal::ZERO {
             k += Decimal::ONE;
         }
-        b = a - k * TAU;
+        b = a - k * tau;
     }
 
     let mut f_a = f(a);
@@ -117,82 +183,104 @@ pub fn calculate_new_sigma(
     (a / Decimal::TWO).exp()
 }
 
-/// Updates the ratings of two players based on the match results and the periods of inactivity.
-pub fn update_rating_internal(
+/// Updates the rating of a player from all the results of a rating period at once.
+///
+/// Every opponent is evaluated at their rating from before the period, so the order of the
+/// results does not matter.
+pub fn update_rating_period_internal(
+    config: &Glicko2Config,
     env: &Env,
-    rating1: &mut RatingInternal,
-    rating2: &mut RatingInternal,
-    result1: Decimal,
-    result2: Decimal,
+    player: &mut RatingInternal,
+    results: &[(RatingInternal, Decimal)],
     period: &Duration,
 ) {
-    if let Some(last_block1) = &rating1.last_block {
-        let periods1 = calculate_periods(env, last_block1, period);
-        rating1.phi = adjust_phi_for_periods(rating1.phi, rating1.sigma, periods1);
+    if results.is_empty() {
+        return;
     }
 
-    if let Some(last_block2) = &rating2.last_block {
-        let periods2 = calculate_periods(env, last_block2, period);
-        rating2.phi = adjust_phi_for_periods(rating2.phi, rating2.sigma, periods2);
+    // The current period's volatility is added through phi_star, so only the periods before it count
+    let inactive_periods = |last_block: &BlockInfo| {
+        (calculate_periods(env, last_block, period) - Decimal::ONE).max(Decimal::ZERO)
+    };
+
+    if let Some(last_block) = &player.last_block {
+        let periods = inactive_periods(last_block);
+        player.phi = adjust_phi_for_periods(config, player.phi, player.sigma, periods);
     }
 
     // Scaling down
-    let mu1 = (rating1.value - DEFAULT_RATING) / SCALING_FACTOR;
-    let phi1 = rating1.phi / SCALING_FACTOR;
-
-    let mu2 = (rating2.value - DEFAULT_RATING) / SCALING_FACTOR;
-    let phi2 = rating2.phi / SCALING_FACTOR;
-
-    // Expected scores
-    let impact1 = reduce_impact(phi2);
-    let expected_score1 = expect_score(mu1, mu2, impact1);
-
-    let impact2 = reduce_impact(phi1);
-    let expected_score2 = expect_score(mu2, mu1, impact2);
-
-    // Variance and difference
-    let variance_inv1 = impact1 * impact1 * expected_score1 * (Decimal::ONE - expected_score1);
-    let difference1 = impact1 * (result1 - expected_score1);
-    let variance1 = Decimal::ONE / variance_inv1;
-
-    let variance_inv2 = impact2 * impact2 * expected_score2 * (Decimal::ONE - expected_score2);
-    let difference2 = impact2 * (result2 - expected_score2);
-    let variance2 = Decimal::ONE / variance_inv2;
-
-    // New volatility
-    let new_sigma1 = calculate_new_sigma(rating1.sigma, phi1, variance1, difference1);
-    let new_sigma2 = calculate_new_sigma(rating2.sigma, phi2, variance2, difference2);
+    let mu = (player.value - config.initial_rating) / SCALING_FACTOR;
+    let phi = player.phi / SCALING_FACTOR;
+
+    // Variance and difference aggregated over every opponent
+    let mut variance_inv = Decimal::ZERO;
+    let mut difference = Decimal::ZERO;
+    for (opponent, score) in results {
+        let opponent_phi = match &opponent.last_block {
+            Some(last_block) => {
+                let periods = inactive_periods(last_block);
+                adjust_phi_for_periods(config, opponent.phi, opponent.sigma, periods)
+            }
+            None => opponent.phi,
+        };
+        let opponent_mu = (opponent.value - config.initial_rating) / SCALING_FACTOR;
+
+        let impact = reduce_impact(opponent_phi / SCALING_FACTOR);
+        let expected_score = expect_score(mu, opponent_mu, impact);
+
+        variance_inv += impact * impact * expected_score * (Decimal::ONE - expected_score);
+        difference += impact * (score - expected_score);
+    }
 
-    // Update rating and deviation
-    let phi1_star = (phi1 * phi1 + new_sigma1 * new_sigma1).sqrt().unwrap();
-    let phi2_star = (phi2 * phi2 + new_sigma2 * new_sigma2).sqrt().unwrap();
+    // Expected scores rounded to 0 or 1 leave an unbounded variance, where the volatility is kept
+    // and the deviation only grows by it
+    let (new_sigma, phi_new) = if variance_inv.is_zero() {
+        let phi_star = (phi * phi + player.sigma * player.sigma).sqrt().unwrap();
+        (player.sigma, phi_star)
+    } else {
+        let variance = Decimal::ONE / variance_inv;
 
-    let phi1_new = Decimal::ONE
-        / ((Decimal::ONE / phi1_star / phi1_star) + (Decimal::ONE / variance1))
-            .sqrt()
-            .unwrap();
-    let mu1_new = mu1 + phi1_new * phi1_new * (difference1 / variance1);
+        // New volatility from the estimated improvement
+        let delta = variance * difference;
+        let new_sigma = calculate_new_sigma(config, player.sigma, phi, variance, delta);
 
-    let phi2_new = Decimal::ONE
-        / ((Decimal::ONE / phi2_star / phi2_star) + (Decimal::ONE / variance2))
-            .sqrt()
-            .unwrap();
-    let mu2_new = mu2 + phi2_new * phi2_new * (difference2 / variance2);
+        // Update rating and deviation
+        let phi_star = (phi * phi + new_sigma * new_sigma).sqrt().unwrap();
+        let phi_new = Decimal::ONE
+            / ((Decimal::ONE / phi_star / phi_star) + variance_inv)
+                .sqrt()
+                .unwrap();
+        (new_sigma, phi_new)
+    };
+    let mu_new = mu + phi_new * phi_new * difference;
 
     // Scaling up
-    rating1.value = mu1_new * SCALING_FACTOR + DEFAULT_RATING;
-    rating1.phi = phi1_new * SCALING_FACTOR;
-    rating1.sigma = new_sigma1;
+    player.value = mu_new * SCALING_FACTOR + config.initial_rating;
+    player.phi = phi_new * SCALING_FACTOR;
+    player.sigma = new_sigma;
+
+    player.last_block = Some(env.block.clone());
+}
 
-    rating2.value = mu2_new * SCALING_FACTOR + DEFAULT_RATING;
-    rating2.phi = phi2_new * SCALING_FACTOR;
-    rating2.sigma = new_sigma2;
+/// Updates the ratings of two players based on the match results and the periods of inactivity.
+pub fn update_rating_internal(
+    config: &Glicko2Config,
+    env: &Env,
+    rating1: &mut RatingInternal,
+    rating2: &mut RatingInternal,
+    result1: Decimal,
+    result2: Decimal,
+    period: &Duration,
+) {
+    let previous1 = rating1.clone();
+    let previous2 = rating2.clone();
 
-    rating1.last_block = Some(env.block.clone());
-    rating2.last_block = Some(env.block.clone());
+    update_rating_period_internal(config, env, rating1, &[(previous2, result1)], period);
+    update_rating_period_internal(config, env, rating2, &[(previous1, result2)], period);
 }
 
 pub fn update_rating(
+    config: &Glicko2Config,
     env: &Env,
     rating1: &mut Rating,
     rating2: &mut Rating,
@@ -201,11 +289,12 @@ pub fn update_rating(
     period: &Duration,
 ) {
     // Convert Rating to internal representation
-    let mut rating1_internal: RatingInternal = rating1.clone().into();
-    let mut rating2_internal: RatingInternal = rating2.clone().into();
+    let mut rating1_internal = RatingInternal::from_rating(rating1.clone(), config);
+    let mut rating2_internal = RatingInternal::from_rating(rating2.clone(), config);
 
     // Update the internal ratings
     update_rating_internal(
+        config,
         env,
         &mut rating1_internal,
         &mut rating2_internal,
@@ -219,15 +308,72 @@ pub fn update_rating(
     *rating2 = rating2_internal.into();
 }
 
-// Conversion from Rating to RatingInternal
-impl From<Rating> for RatingInternal {
-    fn from(r: Rating) -> Self {
-        RatingInternal {
-            value: std_to_rust(r.value),
-            phi: std_to_rust(r.phi),
-            sigma: std_to_rust(r.sigma),
-            last_block: r.last_block,
-        }
+/// Updates the rating of a player with every (opponent, score) result of a rating period.
+pub fn update_rating_period(
+    config: &Glicko2Config,
+    env: &Env,
+    player: &mut Rating,
+    results: &[(Rating, StdDecimal)],
+    period: &Duration,
+) {
+    let mut player_internal = RatingInternal::from_rating(player.clone(), config);
+    let results_internal: Vec<(RatingInternal, Decimal)> = results
+        .iter()
+        .map(|(opponent, score)| {
+            (
+                RatingInternal::from_rating(opponent.clone(), config),
+                std_to_rust(*score),
+            )
+        })
+        .collect();
+
+    update_rating_period_internal(config, env, &mut player_internal, &results_internal, period);
+
+    *player = player_internal.into();
+}
+
+/// Glicko-2 as a [`RatingCalculator`]
+#[derive(Default)]
+pub struct Glicko2 {
+    pub config: Glicko2Config,
+}
+
+impl RatingCalculator for Glicko2 {
+    fn initial_rating(&self) -> Rating {
+        RatingInternal::new(
+            self.config.initial_rating,
+            self.config.initial_phi,
+            self.config.initial_sigma,
+        )
+        .into()
+    }
+
+    fn update_rating_period(
+        &self,
+        env: &Env,
+        player: &mut Rating,
+        results: &[(Rating, StdDecimal)],
+        period: &Duration,
+    ) {
+        update_rating_period(&self.config, env, player, results, period)
+    }
+
+    fn team_rating(&self, members: &[Rating]) -> Rating {
+        let members: Vec<RatingInternal> = members
+            .iter()
+            .map(|x| RatingInternal::from_rating(x.clone(), &self.config))
+            .collect();
+        let count = Decimal::from(members.len());
+
+        // The average rating with the root mean square of the deviations
+        RatingInternal::new(
+            members.iter().map(|x| x.value).sum::<Decimal>() / count,
+            (members.iter().map(|x| x.phi.powu(2)).sum::<Decimal>() / count)
+                .sqrt()
+                .unwrap(),
+            members.iter().map(|x| x.sigma).sum::<Decimal>() / count,
+        )
+        .into()
     }
 }
 
@@ -235,9 +381,11 @@ impl From<Rating> for RatingInternal {
 impl From<RatingInternal> for Rating {
     fn from(r: RatingInternal) -> Self {
         Rating {
-            value: rust_to_std(r.value),
-            phi: rust_to_std(r.phi),
-            sigma: rust_to_std(r.sigma),
+            value: rust_to_signed(r.value),
+            state: RatingState::Glicko2 {
+                phi: rust_to_std(r.phi),
+                sigma: rust_to_std(r.sigma),
+            },
             last_block: r.last_block,
         }
     }
@@ -1,24 +1,32 @@
 use std::str::FromStr;
 
-use arena_interface::ratings::Rating;
-use cosmwasm_std::{testing::mock_env, Decimal};
+use arena_interface::ratings::{Rating, RatingState};
+use cosmwasm_std::{testing::mock_env, Decimal, SignedDecimal};
 use cw_utils::Duration;
+use rust_decimal::MathematicalOps;
 use rust_decimal_macros::dec;
 
-use crate::rating::{
-    calculate_new_sigma, expect_score, reduce_impact, update_rating_internal, RatingInternal, PHI,
-    SIGMA,
+use crate::{
+    rating::{
+        adjust_phi_for_periods, calculate_new_sigma, expect_score, reduce_impact,
+        update_rating_internal, update_rating_period_internal, Glicko2Config, RatingInternal, PHI,
+        SCALING_FACTOR, SIGMA,
+    },
+    update_rating_period,
+    util::{rust_to_signed, signed_to_rust},
 };
 
 #[test]
 fn test_conversion_from_rating_to_internal() {
     let rating = Rating::new(
-        Decimal::from_str("1500").unwrap(),
-        Decimal::from_str("350").unwrap(),
-        Decimal::from_str("0.06").unwrap(),
+        SignedDecimal::from_str("1500").unwrap(),
+        RatingState::Glicko2 {
+            phi: Decimal::from_str("350").unwrap(),
+            sigma: Decimal::from_str("0.06").unwrap(),
+        },
     );
 
-    let rating_internal: RatingInternal = rating.into();
+    let rating_internal = RatingInternal::from_rating(rating, &Glicko2Config::default());
 
     assert_eq!(rating_internal.value, dec!(1500));
     assert_eq!(rating_internal.phi, dec!(350));
@@ -31,15 +39,34 @@ fn test_conversion_from_internal_to_rating() {
 
     let rating: Rating = rating_internal.into();
 
-    assert_eq!(rating.value, Decimal::from_str("1500").unwrap());
-    assert_eq!(rating.phi, Decimal::from_str("350").unwrap());
-    assert_eq!(rating.sigma, Decimal::from_str("0.06").unwrap());
+    assert_eq!(rating.value, SignedDecimal::from_str("1500").unwrap());
+    assert_eq!(
+        rating.state,
+        RatingState::Glicko2 {
+            phi: Decimal::from_str("350").unwrap(),
+            sigma: Decimal::from_str("0.06").unwrap(),
+        }
+    );
+}
+
+#[test]
+fn test_conversion_from_other_system_to_internal() {
+    let rating = Rating::new(
+        SignedDecimal::from_str("1620").unwrap(),
+        RatingState::Elo {},
+    );
+
+    let rating_internal = RatingInternal::from_rating(rating, &Glicko2Config::default());
+
+    assert_eq!(rating_internal.value, dec!(1620));
+    assert_eq!(rating_internal.phi, dec!(300));
+    assert_eq!(rating_internal.sigma, dec!(0.06));
 }
 
 #[test]
 fn test_reduce_impact() {
-    let impact = reduce_impact(PHI);
-    assert_eq!(impact.round_dp(6), dec!(0.005156));
+    let impact = reduce_impact(dec!(30) / SCALING_FACTOR);
+    assert_eq!(impact.round_dp(4), dec!(0.9955));
 }
 
 #[test]
@@ -53,15 +80,20 @@ fn test_expect_score() {
 
 #[test]
 fn test_calculate_new_sigma() {
-    let variance = dec!(0.9);
-    let difference = dec!(0.5);
-    let new_sigma = calculate_new_sigma(SIGMA, PHI, variance, difference);
-    assert_eq!(new_sigma.round_dp(6), dec!(0.244949));
+    let config = Glicko2Config {
+        tau: dec!(0.5),
+        ..Glicko2Config::default()
+    };
+    let variance = dec!(1.7785);
+    let difference = dec!(-0.4834);
+    let new_sigma = calculate_new_sigma(&config, SIGMA, dec!(1.1513), variance, difference);
+    assert_eq!(new_sigma.round_dp(6), dec!(0.059996));
 }
 
 #[test]
 fn test_update_rating() {
     let mut env = mock_env();
+    let config = Glicko2Config::default();
     let period = Duration::Height(10u64);
     let mut player1 = RatingInternal::new(dec!(1500), PHI, SIGMA);
     let mut player2 = RatingInternal::new(dec!(1500), PHI, SIGMA);
@@ -71,39 +103,384 @@ fn test_update_rating() {
     let draw = dec!(0.5);
     let loss = dec!(0);
 
-    update_rating_internal(&env, &mut player1, &mut player2, win, loss, &period);
+    update_rating_internal(
+        &config,
+        &env,
+        &mut player1,
+        &mut player2,
+        win,
+        loss,
+        &period,
+    );
     env.block.height += 10;
 
-    assert_eq!(player1.value.round_dp(6), dec!(1507.725420));
-    assert_eq!(player1.phi.round_dp(6), dec!(317.758916));
-    assert_eq!(player1.sigma.round_dp(6), dec!(0.244782));
+    assert_eq!(player1.value.round_dp(6), dec!(1662.310891));
+    assert_eq!(player1.phi.round_dp(6), dec!(290.318961));
+    assert_eq!(player1.sigma.round_dp(6), dec!(0.059999));
 
-    assert_eq!(player2.value.round_dp(6), dec!(1492.274580));
-    assert_eq!(player2.phi.round_dp(6), dec!(317.758916));
-    assert_eq!(player2.sigma.round_dp(6), dec!(0.244782));
+    assert_eq!(player2.value.round_dp(6), dec!(1337.689109));
+    assert_eq!(player2.phi.round_dp(6), dec!(290.318961));
+    assert_eq!(player2.sigma.round_dp(6), dec!(0.059999));
 
     // Run another round where player1 beats player 3
-    update_rating_internal(&env, &mut player1, &mut player3, win, loss, &period);
+    update_rating_internal(
+        &config,
+        &env,
+        &mut player1,
+        &mut player3,
+        win,
+        loss,
+        &period,
+    );
     env.block.height += 10;
 
     // Expected values after the second match
-    assert_eq!(player1.value.round_dp(6), dec!(1514.550284));
-    assert_eq!(player1.phi.round_dp(6), dec!(300.266781));
-    assert_eq!(player1.sigma.round_dp(6), dec!(0.493354));
+    assert_eq!(player1.value.round_dp(6), dec!(1750.542098));
+    assert_eq!(player1.phi.round_dp(6), dec!(256.345156));
+    assert_eq!(player1.sigma.round_dp(6), dec!(0.059996));
 
-    assert_eq!(player3.value.round_dp(6), dec!(1491.303415));
-    assert_eq!(player3.phi.round_dp(6), dec!(314.691122));
-    assert_eq!(player3.sigma.round_dp(6), dec!(0.244769));
+    assert_eq!(player3.value.round_dp(6), dec!(1383.358083));
+    assert_eq!(player3.phi.round_dp(6), dec!(286.927200));
+    assert_eq!(player3.sigma.round_dp(6), dec!(0.059997));
 
     // Run a round between player2 and player 3 - draw to observe the impact of period adjustments
-    update_rating_internal(&env, &mut player2, &mut player3, draw, draw, &period);
+    update_rating_internal(
+        &config,
+        &env,
+        &mut player2,
+        &mut player3,
+        draw,
+        draw,
+        &period,
+    );
 
     // Expected values after the third match
-    assert_eq!(player2.value.round_dp(6), dec!(1492.263433));
-    assert_eq!(player2.phi.round_dp(6), dec!(297.399847));
-    assert_eq!(player2.sigma.round_dp(6), dec!(0.493220));
+    assert_eq!(player2.value.round_dp(6), dec!(1350.322266));
+    assert_eq!(player2.phi.round_dp(6), dec!(247.534805));
+    assert_eq!(player2.sigma.round_dp(6), dec!(0.059993));
+
+    assert_eq!(player3.value.round_dp(6), dec!(1371.052597));
+    assert_eq!(player3.phi.round_dp(6), dec!(245.685215));
+    assert_eq!(player3.sigma.round_dp(6), dec!(0.059992));
+}
+
+#[test]
+fn test_update_rating_period_single_result_matches_pairwise() {
+    let env = mock_env();
+    let config = Glicko2Config::default();
+    let period = Duration::Height(10u64);
+    let mut player1 = RatingInternal::new(dec!(1500), PHI, SIGMA);
+    let mut player2 = RatingInternal::new(dec!(1500), PHI, SIGMA);
+
+    let mut batched = player1.clone();
+    update_rating_period_internal(
+        &config,
+        &env,
+        &mut batched,
+        &[(player2.clone(), dec!(1))],
+        &period,
+    );
+
+    update_rating_internal(
+        &config,
+        &env,
+        &mut player1,
+        &mut player2,
+        dec!(1),
+        dec!(0),
+        &period,
+    );
+
+    assert_eq!(batched, player1);
+    assert_eq!(batched.value.round_dp(6), dec!(1662.310891));
+}
+
+#[test]
+fn test_update_rating_period() {
+    let env = mock_env();
+    let config = Glicko2Config::default();
+    let period = Duration::Height(10u64);
+    let player = RatingInternal::new(dec!(1500), dec!(200), SIGMA);
+    let opponent1 = RatingInternal::new(dec!(1400), dec!(30), SIGMA);
+    let opponent2 = RatingInternal::new(dec!(1550), dec!(100), SIGMA);
+    let opponent3 = RatingInternal::new(dec!(1700), dec!(300), SIGMA);
+
+    let mut batched = player.clone();
+    update_rating_period_internal(
+        &config,
+        &env,
+        &mut batched,
+        &[
+            (opponent1.clone(), dec!(1)),
+            (opponent2.clone(), dec!(0)),
+            (opponent3.clone(), dec!(0)),
+        ],
+        &period,
+    );
+
+    // The order of the results within a period does not matter
+    let mut reordered = player.clone();
+    update_rating_period_internal(
+        &config,
+        &env,
+        &mut reordered,
+        &[
+            (opponent3.clone(), dec!(0)),
+            (opponent1.clone(), dec!(1)),
+            (opponent2.clone(), dec!(0)),
+        ],
+        &period,
+    );
+    assert_eq!(batched.value.round_dp(6), reordered.value.round_dp(6));
+    assert_eq!(batched.phi.round_dp(6), reordered.phi.round_dp(6));
+    assert_eq!(batched.sigma.round_dp(6), reordered.sigma.round_dp(6));
+
+    // A period with a win and two losses against stronger opponents lowers the rating
+    assert!(batched.value < player.value);
+    assert!(batched.phi < player.phi);
+    assert_eq!(batched.last_block, Some(env.block.clone()));
+
+    // Applying the same games pairwise rates later games against shifted ratings
+    let mut sequential = player.clone();
+    for (mut opponent, score) in [
+        (opponent1, dec!(1)),
+        (opponent2, dec!(0)),
+        (opponent3, dec!(0)),
+    ] {
+        update_rating_internal(
+            &config,
+            &env,
+            &mut sequential,
+            &mut opponent,
+            score,
+            dec!(1) - score,
+            &period,
+        );
+    }
+    assert_ne!(batched.value.round_dp(6), sequential.value.round_dp(6));
+}
+
+#[test]
+fn test_glickman_example() {
+    // The example from Glickman's "Example of the Glicko-2 system"
+    let env = mock_env();
+    let config = Glicko2Config {
+        tau: dec!(0.5),
+        ..Glicko2Config::default()
+    };
+    let period = Duration::Height(10u64);
+    let mut player = RatingInternal::new(dec!(1500), dec!(200), SIGMA);
+
+    update_rating_period_internal(
+        &config,
+        &env,
+        &mut player,
+        &[
+            (RatingInternal::new(dec!(1400), dec!(30), SIGMA), dec!(1)),
+            (RatingInternal::new(dec!(1550), dec!(100), SIGMA), dec!(0)),
+            (RatingInternal::new(dec!(1700), dec!(300), SIGMA), dec!(0)),
+        ],
+        &period,
+    );
+
+    // The paper rounds its intermediate steps, so the results match to its precision
+    assert!((player.value - dec!(1464.06)).abs() < dec!(0.01));
+    assert!((player.phi - dec!(151.52)).abs() < dec!(0.01));
+    assert!((player.sigma - dec!(0.05999)).abs() < dec!(0.00001));
+}
+
+#[test]
+fn test_update_rating_period_without_results() {
+    let env = mock_env();
+    let config = Glicko2Config::default();
+    let period = Duration::Height(10u64);
+    let player = RatingInternal::new(dec!(1500), PHI, SIGMA);
 
-    assert_eq!(player3.value.round_dp(6), dec!(1491.314205));
-    assert_eq!(player3.phi.round_dp(6), dec!(295.467494));
-    assert_eq!(player3.sigma.round_dp(6), dec!(0.493213));
+    let mut updated = player.clone();
+    update_rating_period_internal(&config, &env, &mut updated, &[], &period);
+
+    assert_eq!(updated, player);
+}
+
+#[test]
+fn test_config() {
+    let env = mock_env();
+    let period = Duration::Height(10u64);
+    let config = Glicko2Config {
+        tau: dec!(0.5),
+        initial_rating: dec!(1000),
+        initial_phi: dec!(200),
+        initial_sigma: dec!(0.05),
+        max_phi: dec!(250),
+    };
+
+    // Ratings from another system start from the configured deviation and volatility
+    let rating = Rating::new(
+        SignedDecimal::from_str("1000").unwrap(),
+        RatingState::Elo {},
+    );
+    let rating_internal = RatingInternal::from_rating(rating, &config);
+    assert_eq!(rating_internal.phi, dec!(200));
+    assert_eq!(rating_internal.sigma, dec!(0.05));
+
+    // The scale is centered on the initial rating, so shifting every rating shifts the results
+    let mut player1 = RatingInternal::new(dec!(1000), dec!(200), dec!(0.05));
+    let mut player2 = RatingInternal::new(dec!(1000), dec!(200), dec!(0.05));
+    update_rating_internal(
+        &config,
+        &env,
+        &mut player1,
+        &mut player2,
+        dec!(1),
+        dec!(0),
+        &period,
+    );
+
+    let shifted = Glicko2Config {
+        initial_rating: dec!(1500),
+        ..config.clone()
+    };
+    let mut player3 = RatingInternal::new(dec!(1500), dec!(200), dec!(0.05));
+    let mut player4 = RatingInternal::new(dec!(1500), dec!(200), dec!(0.05));
+    update_rating_internal(
+        &shifted,
+        &env,
+        &mut player3,
+        &mut player4,
+        dec!(1),
+        dec!(0),
+        &period,
+    );
+
+    assert!(player1.value > dec!(1000));
+    assert_eq!((player3.value - player1.value).round_dp(6), dec!(500));
+    assert_eq!(player1.phi, player3.phi);
+
+    // Inactivity raises the deviation by the volatility on the rating scale, up to the cap
+    let adjusted = adjust_phi_for_periods(&config, dec!(100), SIGMA, dec!(1));
+    assert_eq!(
+        adjusted.round_dp(6),
+        (dec!(10000) + (SIGMA * SCALING_FACTOR).powu(2))
+            .sqrt()
+            .unwrap()
+            .round_dp(6)
+    );
+    assert!(adjusted > dec!(100.5));
+    assert!(adjust_phi_for_periods(&config, dec!(100), SIGMA, dec!(100)) > dec!(140));
+    assert_eq!(
+        adjust_phi_for_periods(&config, dec!(240), SIGMA, dec!(50)),
+        dec!(250)
+    );
+    assert_eq!(
+        adjust_phi_for_periods(&config, dec!(300), SIGMA, dec!(50)),
+        dec!(300)
+    );
+}
+
+#[test]
+fn test_inactive_periods() {
+    let env = mock_env();
+    let period = Duration::Height(10u64);
+    let config = Glicko2Config::default();
+    let opponent = RatingInternal::new(dec!(1500), dec!(200), SIGMA);
+    let rated_at = |periods: u64| {
+        let mut block = env.block.clone();
+        block.height -= periods * 10;
+        let mut player = RatingInternal::new(dec!(1500), dec!(200), SIGMA);
+        player.last_block = Some(block);
+        update_rating_period_internal(
+            &config,
+            &env,
+            &mut player,
+            &[(opponent.clone(), dec!(1))],
+            &period,
+        );
+        player
+    };
+
+    // A rating updated at the end of the previous period is not inflated before the current one
+    let mut unrated = RatingInternal::new(dec!(1500), dec!(200), SIGMA);
+    update_rating_period_internal(
+        &config,
+        &env,
+        &mut unrated,
+        &[(opponent.clone(), dec!(1))],
+        &period,
+    );
+    assert_eq!(rated_at(1).phi, unrated.phi);
+    assert_eq!(rated_at(1).value, unrated.value);
+
+    // Each skipped period raises the deviation, so the rating moves further
+    let inactive = rated_at(20);
+    assert!(inactive.phi > unrated.phi);
+    assert!(inactive.value > unrated.value);
+}
+
+#[test]
+fn test_signed_conversions() {
+    // Negative values convert exactly
+    assert_eq!(
+        rust_to_signed(dec!(-12.5)),
+        SignedDecimal::from_str("-12.5").unwrap()
+    );
+    assert_eq!(
+        signed_to_rust(SignedDecimal::from_str("-12.5").unwrap()),
+        dec!(-12.5)
+    );
+
+    // Values beyond the bounds of SignedDecimal saturate
+    assert_eq!(
+        rust_to_signed(rust_decimal::Decimal::MAX),
+        SignedDecimal::MAX
+    );
+    assert_eq!(
+        rust_to_signed(-rust_decimal::Decimal::MAX),
+        SignedDecimal::MIN
+    );
+
+    // Values beyond the precision of RustDecimal keep their integer part
+    assert_eq!(
+        signed_to_rust(SignedDecimal::MAX).trunc(),
+        dec!(170141183460469231731)
+    );
+    assert_eq!(
+        signed_to_rust(SignedDecimal::MIN).trunc(),
+        dec!(-170141183460469231731)
+    );
+}
+
+#[test]
+fn test_negative_ratings() {
+    let env = mock_env();
+    let config = Glicko2Config::default();
+    let period = Duration::Height(10u64);
+
+    // A loss at a rating far below the scale's center keeps lowering it past 0
+    let rating = Rating::new(
+        SignedDecimal::from_str("5").unwrap(),
+        RatingState::Glicko2 {
+            phi: Decimal::from_str("300").unwrap(),
+            sigma: Decimal::from_str("0.06").unwrap(),
+        },
+    );
+    let mut player = rating.clone();
+    update_rating_period(
+        &config,
+        &env,
+        &mut player,
+        &[(rating.clone(), Decimal::zero())],
+        &period,
+    );
+    assert!(player.value < SignedDecimal::zero());
+
+    // Negative ratings are rated like any other
+    let mut winner = player.clone();
+    update_rating_period(
+        &config,
+        &env,
+        &mut winner,
+        &[(player.clone(), Decimal::one())],
+        &period,
+    );
+    assert!(winner.value > player.value);
 }
@@ -1,7 +1,7 @@
 use std::{cmp::min, str::FromStr};
 
-use cosmwasm_std::Decimal as StdDecimal;
-use rust_decimal::{prelude::ToPrimitive, Decimal as RustDecimal};
+use cosmwasm_std::{Decimal as StdDecimal, SignedDecimal};
+use rust_decimal::{prelude::ToPrimitive, Decimal as RustDecimal, MathematicalOps};
 
 pub fn rust_to_std(x: RustDecimal) -> StdDecimal {
     // Determine the scale to preserve precision, up to 9 decimal places
@@ -22,3 +22,44 @@ pub fn std_to_rust(x: StdDecimal) -> RustDecimal {
     // Convert StdDecimal to string and then parse it into RustDecimal
     RustDecimal::from_str(&x.to_string()).unwrap()
 }
+
+/// Converts a rating value, saturating at the bounds of SignedDecimal
+pub fn rust_to_signed(x: RustDecimal) -> SignedDecimal {
+    // Determine the scale to preserve precision, up to 9 decimal places
+    let digits = min(x.scale(), 9);
+    let multiplier = 10i128.pow(digits);
+
+    x.checked_mul(RustDecimal::new(multiplier as i64, 0))
+        .and_then(|x| x.round().to_i128())
+        .and_then(|numerator| SignedDecimal::checked_from_ratio(numerator, multiplier).ok())
+        .unwrap_or(if x.is_sign_negative() {
+            SignedDecimal::MIN
+        } else {
+            SignedDecimal::MAX
+        })
+}
+
+/// Converts a rating value, dropping the least significant digits of values beyond the precision of RustDecimal
+pub fn signed_to_rust(x: SignedDecimal) -> RustDecimal {
+    let mut atomics = x.atomics().i128();
+    let mut scale = SignedDecimal::DECIMAL_PLACES;
+
+    loop {
+        match RustDecimal::try_from_i128_with_scale(atomics, scale) {
+            Ok(x) => return x.normalize(),
+            Err(_) => {
+                atomics /= 10;
+                scale -= 1;
+            }
+        }
+    }
+}
+
+/// The logistic curve 1 / (1 + e^x), saturating at 0 or 1 where the exponential is out of range
+pub fn logistic(x: RustDecimal) -> RustDecimal {
+    match x.checked_exp() {
+        Some(exp) => RustDecimal::ONE / (RustDecimal::ONE + exp),
+        None if x.is_sign_negative() => RustDecimal::ONE,
+        None => RustDecimal::ZERO,
+    }
+}
@@ -0,0 +1,29 @@
+use arena_matchmaking_module::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use cw_orch::interface;
+use cw_orch::prelude::*;
+
+pub const CONTRACT_ID: &str = "arena_matchmaking_module";
+
+#[interface(InstantiateMsg, ExecuteMsg, QueryMsg, MigrateMsg, id = CONTRACT_ID)]
+pub struct ArenaMatchmakingModuleContract;
+
+impl<Chain> Uploadable for ArenaMatchmakingModuleContract<Chain> {
+    /// Return the path to the wasm file corresponding to the contract
+    fn wasm(_chain: &ChainInfoOwned) -> WasmPath {
+        artifacts_dir_from_workspace!()
+            .find_wasm_path(CONTRACT_ID)
+            .unwrap()
+    }
+    /// Returns a CosmWasm contract wrapper
+    fn wrapper() -> Box<dyn MockContract<Empty>> {
+        Box::new(
+            ContractWrapper::new_with_empty(
+                arena_matchmaking_module::contract::execute,
+                arena_matchmaking_module::contract::instantiate,
+                arena_matchmaking_module::contract::query,
+            )
+            .with_migrate(arena_matchmaking_module::contract::migrate)
+            .with_reply(arena_matchmaking_module::contract::reply),
+        )
+    }
+}
@@ -11,6 +11,8 @@ pub mod arena_group;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod arena_league_module;
 #[cfg(not(target_arch = "wasm32"))]
+pub mod arena_matchmaking_module;
+#[cfg(not(target_arch = "wasm32"))]
 pub mod arena_payment_registry;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod arena_token_gateway;
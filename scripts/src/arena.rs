@@ -4,6 +4,7 @@ use orch_interface::{
     arena_competition_enrollment::ArenaCompetitionEnrollmentContract,
     arena_core::ArenaCoreContract, arena_escrow::ArenaEscrowContract,
     arena_group::ArenaGroupContract, arena_league_module::ArenaLeagueModuleContract,
+    arena_matchmaking_module::ArenaMatchmakingModuleContract,
     arena_payment_registry::ArenaPaymentRegistryContract,
     arena_token_gateway::ArenaTokenGatewayContract,
     arena_tournament_module::ArenaTournamentModuleContract,
@@ -22,6 +23,7 @@ pub struct Arena<Chain> {
     pub arena_token_gateway: ArenaTokenGatewayContract<Chain>,
     pub arena_payment_registry: ArenaPaymentRegistryContract<Chain>,
     pub arena_group: ArenaGroupContract<Chain>,
+    pub arena_matchmaking_module: ArenaMatchmakingModuleContract<Chain>,
     pub dao_dao: DaoDao<Chain>,
     pub cw4_group: Cw4Group<Chain>,
 }
@@ -38,6 +40,7 @@ impl<Chain: CwEnv> Arena<Chain> {
             arena_token_gateway: ArenaTokenGatewayContract::new(chain.clone()),
             arena_payment_registry: ArenaPaymentRegistryContract::new(chain.clone()),
             arena_group: ArenaGroupContract::new(chain.clone()),
+            arena_matchmaking_module: ArenaMatchmakingModuleContract::new(chain.clone()),
             dao_dao: DaoDao::new(chain.clone()),
             cw4_group: Cw4Group::new(chain.clone()),
         }
@@ -53,6 +56,7 @@ impl<Chain: CwEnv> Arena<Chain> {
         self.arena_token_gateway.upload()?;
         self.arena_payment_registry.upload()?;
         self.arena_group.upload()?;
+        self.arena_matchmaking_module.upload()?;
 
         if with_dao_dao {
             self.dao_dao.upload()?;
@@ -4,6 +4,7 @@ use orch_interface::{
     arena_competition_enrollment::ArenaCompetitionEnrollmentContract,
     arena_core::ArenaCoreContract, arena_discord_identity::ArenaDiscordIdentityContract,
     arena_group::ArenaGroupContract, arena_league_module::ArenaLeagueModuleContract,
+    arena_matchmaking_module::ArenaMatchmakingModuleContract,
     arena_payment_registry::ArenaPaymentRegistryContract,
     arena_token_gateway::ArenaTokenGatewayContract,
     arena_tournament_module::ArenaTournamentModuleContract,
@@ -51,6 +52,7 @@ enum DeployComponent {
     Identity,
     DaoCore,
     Registry,
+    Matchmaking,
 }
 
 fn parse_command(args: &[String]) -> Command {
@@ -75,6 +77,7 @@ fn parse_command(args: &[String]) -> Command {
         "group" => DeployComponent::Group,
         "identity" => DeployComponent::Identity,
         "registry" => DeployComponent::Registry,
+        "matchmaking" => DeployComponent::Matchmaking,
         _ => return Command::Unknown,
     };
 
@@ -98,6 +101,7 @@ fn deploy(network: Network, component: DeployComponent) -> anyhow::Result<()> {
         DeployComponent::Group => deploy_group(daemon)?,
         DeployComponent::Identity => deploy_identity(daemon)?,
         DeployComponent::Registry => deploy_registry(daemon)?,
+        DeployComponent::Matchmaking => deploy_matchmaking(daemon)?,
     }
 
     Ok(())
@@ -167,6 +171,12 @@ fn deploy_registry(daemon: Daemon) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn deploy_matchmaking(daemon: Daemon) -> anyhow::Result<()> {
+    let matchmaking = ArenaMatchmakingModuleContract::new(daemon);
+    matchmaking.upload()?;
+    Ok(())
+}
+
 mod arena;
 mod dao_dao;
 #[cfg(test)]
@@ -1,15 +1,21 @@
 use arena_competition_enrollment::msg::{
     CompetitionInfoMsg, ExecuteMsg, ExecuteMsgFns as _, MigrateMsg, QueryMsgFns as _,
 };
-use arena_competition_enrollment::state::CompetitionType;
+use arena_competition_enrollment::state::{
+    CompetitionType, EligibilityRequirement, EnrollmentTemplateInfo,
+};
 use arena_interface::competition::msg::QueryBaseFns as _;
+use arena_interface::core::{EditCompetitionCategory, ExecuteExt};
 use arena_interface::escrow::ExecuteMsgFns as _;
 use arena_interface::group::{self, QueryMsgFns as _};
+use arena_interface::ratings::RatingConfig;
 use arena_tournament_module::state::EliminationType;
-use cosmwasm_std::{coins, to_json_binary, CosmosMsg, Decimal, Uint128, Uint64, WasmMsg};
+use cosmwasm_std::{
+    coins, to_json_binary, CosmosMsg, Decimal, SignedDecimal, Uint128, Uint64, WasmMsg,
+};
 use cw_orch::{anyhow, prelude::*};
 use cw_orch_clone_testing::CloneTesting;
-use cw_utils::Expiration;
+use cw_utils::{Duration, Expiration};
 use dao_interface::state::ModuleInstantiateInfo;
 use dao_proposal_sudo::msg::ExecuteMsgFns as _;
 use networks::PION_1;
@@ -76,6 +82,11 @@ fn test_competition_enrollment() -> anyhow::Result<()> {
             funds: vec![],
             label: "Arena Group".to_string(),
         },
+        waitlist_size: None,
+        eligibility_requirements: None,
+        approval_required: None,
+        escrow_id: None,
+        trigger_bounty: None,
     };
 
     let res = arena
@@ -144,11 +155,14 @@ fn test_competition_enrollment() -> anyhow::Result<()> {
             .iter()
             .any(|attr| attr.key == "action" && attr.value == "trigger_expiration")));
 
-    // The enrollment didn't succeed, so we should be able to withdraw
+    // The enrollment didn't succeed, so the member was refunded automatically
+    assert_eq!(
+        mock.query_balance(&teams[0], DENOM)?,
+        Uint128::new(100_000u128)
+    );
     arena.arena_competition_enrollment.set_sender(&teams[0]);
-    arena
-        .arena_competition_enrollment
-        .withdraw(Uint128::one())?;
+    let result = arena.arena_competition_enrollment.withdraw(Uint128::one());
+    assert!(result.is_err());
 
     Ok(())
 }
@@ -195,6 +209,11 @@ fn test_invalid_enrollment() -> anyhow::Result<()> {
             funds: vec![],
             label: "Arena Group".to_string(),
         },
+        waitlist_size: None,
+        eligibility_requirements: None,
+        approval_required: None,
+        escrow_id: None,
+        trigger_bounty: None,
     };
 
     let result = arena
@@ -263,6 +282,11 @@ fn test_enrollment_capacity() -> anyhow::Result<()> {
             funds: vec![],
             label: "Arena Group".to_string(),
         },
+        waitlist_size: None,
+        eligibility_requirements: None,
+        approval_required: None,
+        escrow_id: None,
+        trigger_bounty: None,
     };
 
     arena
@@ -345,6 +369,11 @@ fn test_successful_tournament_creation() -> anyhow::Result<()> {
             funds: vec![],
             label: "Arena Group".to_string(),
         },
+        waitlist_size: None,
+        eligibility_requirements: None,
+        approval_required: None,
+        escrow_id: None,
+        trigger_bounty: None,
     };
 
     arena
@@ -428,6 +457,11 @@ fn test_successful_wager_creation() -> anyhow::Result<()> {
             funds: vec![],
             label: "Arena Group".to_string(),
         },
+        waitlist_size: None,
+        eligibility_requirements: None,
+        approval_required: None,
+        escrow_id: None,
+        trigger_bounty: None,
     };
 
     arena
@@ -524,6 +558,11 @@ fn test_successful_league_creation() -> anyhow::Result<()> {
             funds: vec![],
             label: "Arena Group".to_string(),
         },
+        waitlist_size: None,
+        eligibility_requirements: None,
+        approval_required: None,
+        escrow_id: None,
+        trigger_bounty: None,
     };
 
     arena
@@ -603,6 +642,11 @@ fn test_trigger_expiration_without_escrow() -> anyhow::Result<()> {
             funds: vec![],
             label: "Arena Group".to_string(),
         },
+        waitlist_size: None,
+        eligibility_requirements: None,
+        approval_required: None,
+        escrow_id: None,
+        trigger_bounty: None,
     };
 
     arena
@@ -692,6 +736,11 @@ fn test_trigger_expiration_before_min_members() -> anyhow::Result<()> {
             funds: vec![],
             label: "Arena Group".to_string(),
         },
+        waitlist_size: None,
+        eligibility_requirements: None,
+        approval_required: None,
+        escrow_id: None,
+        trigger_bounty: None,
     };
 
     arena
@@ -723,19 +772,16 @@ fn test_trigger_expiration_before_min_members() -> anyhow::Result<()> {
             .iter()
             .any(|attr| attr.key == "result" && attr.value == "expired_insufficient_members")));
 
-    // Attempt to withdraw for each enrolled team
+    // Each enrolled team was refunded without withdrawing
     for team in teams {
-        arena.arena_competition_enrollment.set_sender(&team);
-        let withdraw_res = arena
-            .arena_competition_enrollment
-            .withdraw(Uint128::one())?;
-
-        // Check that the withdrawal was successful
-        assert!(withdraw_res.events.iter().any(|e| e.ty == "wasm"
-            && e.attributes
-                .iter()
-                .any(|attr| attr.key == "action" && attr.value == "withdraw")));
+        assert_eq!(mock.query_balance(&team, DENOM)?, Uint128::new(100_000u128));
     }
+    let enrollment = arena
+        .arena_competition_enrollment
+        .enrollment(Uint128::one())?;
+    arena.arena_group.set_address(&enrollment.group_contract);
+    let members = arena.arena_group.members(None, None)?;
+    assert!(members.is_empty());
 
     Ok(())
 }
@@ -775,6 +821,11 @@ fn test_unregistered_competition_enrollment() -> anyhow::Result<()> {
             funds: vec![],
             label: "Arena Group".to_string(),
         },
+        waitlist_size: None,
+        eligibility_requirements: None,
+        approval_required: None,
+        escrow_id: None,
+        trigger_bounty: None,
     };
 
     arena
@@ -880,6 +931,11 @@ fn test_huge_tournament() -> anyhow::Result<()> {
             funds: vec![],
             label: "Arena Group".to_string(),
         },
+        waitlist_size: None,
+        eligibility_requirements: None,
+        approval_required: None,
+        escrow_id: None,
+        trigger_bounty: None,
     };
 
     arena
@@ -923,6 +979,688 @@ fn test_huge_tournament() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_enrollment_waitlist() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    // Set teams
+    let mut teams = vec![];
+    for i in 0..5 {
+        teams.push(mock.addr_make_with_balance(format!("team {}", i), coins(100_000u128, DENOM))?);
+    }
+
+    // Register the enrollment module
+    arena.dao_dao.dao_proposal_sudo.set_sender(&admin);
+    arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&arena_interface::core::ExecuteMsg::Extension {
+                msg: arena_interface::core::ExecuteExt::UpdateEnrollmentModules {
+                    to_add: Some(vec![arena.arena_competition_enrollment.addr_str()?]),
+                    to_remove: None,
+                },
+            })?,
+            funds: vec![],
+        })])?;
+
+    // Create a wager enrollment with a waitlist of 2
+    arena.arena_competition_enrollment.set_sender(&admin);
+    let create_enrollment_msg = ExecuteMsg::CreateEnrollment {
+        min_members: Some(Uint64::new(2)),
+        max_members: Uint64::new(2),
+        entry_fee: Some(coins(1000, DENOM)[0].clone()),
+        expiration: Expiration::AtHeight(1000000),
+        category_id: Some(Uint128::new(1)),
+        competition_info: CompetitionInfoMsg {
+            name: "Test Wager".to_string(),
+            description: "A test wager".to_string(),
+            expiration: Expiration::AtHeight(2000000),
+            rules: Some(vec!["Wager Rule".to_string()]),
+            rulesets: None,
+            banner: None,
+            additional_layered_fees: None,
+        },
+        competition_type: CompetitionType::Wager {},
+        group_contract_info: ModuleInstantiateInfo {
+            code_id: arena.arena_group.code_id()?,
+            msg: to_json_binary(&group::InstantiateMsg { members: None })?,
+            admin: None,
+            funds: vec![],
+            label: "Arena Group".to_string(),
+        },
+        waitlist_size: Some(Uint64::new(2)),
+        eligibility_requirements: None,
+        approval_required: None,
+        escrow_id: None,
+        trigger_bounty: None,
+    };
+
+    arena
+        .arena_competition_enrollment
+        .execute(&create_enrollment_msg, None)?;
+
+    // Fill the enrollment and the waitlist
+    for team in teams.iter().take(4) {
+        arena.arena_competition_enrollment.set_sender(team);
+        arena
+            .arena_competition_enrollment
+            .enroll(Uint128::one(), &coins(1000, DENOM))?;
+    }
+
+    let waitlist = arena
+        .arena_competition_enrollment
+        .waitlist(Uint128::one(), None, None)?;
+    assert_eq!(waitlist, vec![teams[2].clone(), teams[3].clone()]);
+    assert_eq!(
+        mock.query_balance(&teams[2], DENOM)?,
+        Uint128::new(99_000u128)
+    );
+
+    // The waitlist is full
+    arena.arena_competition_enrollment.set_sender(&teams[4]);
+    let result = arena
+        .arena_competition_enrollment
+        .enroll(Uint128::one(), &coins(1000, DENOM));
+    assert!(result.is_err());
+
+    // A withdrawal promotes the first waitlisted applicant
+    arena.arena_competition_enrollment.set_sender(&teams[0]);
+    arena
+        .arena_competition_enrollment
+        .withdraw(Uint128::one())?;
+
+    assert!(arena
+        .arena_competition_enrollment
+        .is_member(teams[2].to_string(), Uint128::one())?);
+    let waitlist = arena
+        .arena_competition_enrollment
+        .waitlist(Uint128::one(), None, None)?;
+    assert_eq!(waitlist, vec![teams[3].clone()]);
+
+    // Triggering refunds the remaining waitlisted applicants
+    arena.arena_competition_enrollment.set_sender(&admin);
+    let res = arena
+        .arena_competition_enrollment
+        .trigger_expiration(arena.arena_escrow.code_id()?, Uint128::one())?;
+    assert!(res.events.iter().any(|e| e.ty == "wasm"
+        && e.attributes
+            .iter()
+            .any(|attr| attr.key == "result" && attr.value == "competition_created")));
+
+    assert_eq!(
+        mock.query_balance(&teams[3], DENOM)?,
+        Uint128::new(100_000u128)
+    );
+    let waitlist = arena
+        .arena_competition_enrollment
+        .waitlist(Uint128::one(), None, None)?;
+    assert!(waitlist.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_enrollment_eligibility() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    // Set teams
+    let mut teams = vec![];
+    for i in 0..3 {
+        teams.push(mock.addr_make_with_balance(format!("team {}", i), coins(100_000u128, DENOM))?);
+    }
+
+    // Create a wager enrollment restricted to an allowlist
+    arena.arena_competition_enrollment.set_sender(&admin);
+    let create_enrollment_msg = ExecuteMsg::CreateEnrollment {
+        min_members: Some(Uint64::new(2)),
+        max_members: Uint64::new(2),
+        entry_fee: Some(coins(1000, DENOM)[0].clone()),
+        expiration: Expiration::AtHeight(1000000),
+        category_id: Some(Uint128::new(1)),
+        competition_info: CompetitionInfoMsg {
+            name: "Test Wager".to_string(),
+            description: "A test wager".to_string(),
+            expiration: Expiration::AtHeight(2000000),
+            rules: Some(vec!["Wager Rule".to_string()]),
+            rulesets: None,
+            banner: None,
+            additional_layered_fees: None,
+        },
+        competition_type: CompetitionType::Wager {},
+        group_contract_info: ModuleInstantiateInfo {
+            code_id: arena.arena_group.code_id()?,
+            msg: to_json_binary(&group::InstantiateMsg { members: None })?,
+            admin: None,
+            funds: vec![],
+            label: "Arena Group".to_string(),
+        },
+        waitlist_size: None,
+        eligibility_requirements: Some(vec![
+            EligibilityRequirement::Allowlist {
+                addrs: vec![teams[0].to_string(), teams[1].to_string()],
+            },
+            EligibilityRequirement::Rating {
+                category_id: Uint128::one(),
+                min: Some(SignedDecimal::from_ratio(1400, 1)),
+                max: None,
+            },
+        ]),
+        approval_required: None,
+        escrow_id: None,
+        trigger_bounty: None,
+    };
+
+    arena
+        .arena_competition_enrollment
+        .execute(&create_enrollment_msg, None)?;
+
+    // Unrated members are evaluated with the category's initial rating
+    let eligibility = arena
+        .arena_competition_enrollment
+        .check_eligibility(teams[0].to_string(), Uint128::one())?;
+    assert!(eligibility.is_eligible);
+
+    arena.dao_dao.dao_proposal_sudo.set_sender(&admin);
+    arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&arena_interface::core::ExecuteMsg::from(
+                ExecuteExt::UpdateCategories {
+                    to_add: None,
+                    to_edit: Some(vec![EditCompetitionCategory::SetRatingConfig {
+                        category_id: Uint128::one(),
+                        rating_config: RatingConfig {
+                            initial_rating: SignedDecimal::from_ratio(1300, 1),
+                            ..RatingConfig::default()
+                        },
+                    }]),
+                },
+            ))?,
+            funds: vec![],
+        })])?;
+
+    let eligibility = arena
+        .arena_competition_enrollment
+        .check_eligibility(teams[0].to_string(), Uint128::one())?;
+    assert!(!eligibility.is_eligible);
+    assert!(matches!(
+        eligibility.unmet_requirements[..],
+        [EligibilityRequirement::Rating { .. }]
+    ));
+
+    arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&arena_interface::core::ExecuteMsg::from(
+                ExecuteExt::UpdateCategories {
+                    to_add: None,
+                    to_edit: Some(vec![EditCompetitionCategory::SetRatingConfig {
+                        category_id: Uint128::one(),
+                        rating_config: RatingConfig::default(),
+                    }]),
+                },
+            ))?,
+            funds: vec![],
+        })])?;
+
+    let eligibility = arena
+        .arena_competition_enrollment
+        .check_eligibility(teams[2].to_string(), Uint128::one())?;
+    assert!(!eligibility.is_eligible);
+    assert_eq!(eligibility.unmet_requirements.len(), 1);
+
+    // Members outside of the allowlist cannot enroll
+    arena.arena_competition_enrollment.set_sender(&teams[2]);
+    let result = arena
+        .arena_competition_enrollment
+        .enroll(Uint128::one(), &coins(1000, DENOM));
+    assert!(result.is_err());
+
+    arena.arena_competition_enrollment.set_sender(&teams[0]);
+    arena
+        .arena_competition_enrollment
+        .enroll(Uint128::one(), &coins(1000, DENOM))?;
+
+    Ok(())
+}
+
+#[test]
+fn test_enrollment_approval() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    // Set teams
+    let mut teams = vec![];
+    for i in 0..3 {
+        teams.push(mock.addr_make_with_balance(format!("team {}", i), coins(100_000u128, DENOM))?);
+    }
+
+    // Create a wager enrollment requiring the host's approval
+    arena.arena_competition_enrollment.set_sender(&admin);
+    let create_enrollment_msg = ExecuteMsg::CreateEnrollment {
+        min_members: Some(Uint64::new(2)),
+        max_members: Uint64::new(2),
+        entry_fee: Some(coins(1000, DENOM)[0].clone()),
+        expiration: Expiration::AtHeight(1000000),
+        category_id: Some(Uint128::new(1)),
+        competition_info: CompetitionInfoMsg {
+            name: "Test Wager".to_string(),
+            description: "A test wager".to_string(),
+            expiration: Expiration::AtHeight(2000000),
+            rules: Some(vec!["Wager Rule".to_string()]),
+            rulesets: None,
+            banner: None,
+            additional_layered_fees: None,
+        },
+        competition_type: CompetitionType::Wager {},
+        group_contract_info: ModuleInstantiateInfo {
+            code_id: arena.arena_group.code_id()?,
+            msg: to_json_binary(&group::InstantiateMsg { members: None })?,
+            admin: None,
+            funds: vec![],
+            label: "Arena Group".to_string(),
+        },
+        waitlist_size: None,
+        eligibility_requirements: None,
+        approval_required: Some(true),
+        escrow_id: None,
+        trigger_bounty: None,
+    };
+
+    arena
+        .arena_competition_enrollment
+        .execute(&create_enrollment_msg, None)?;
+
+    // Applications are held until the host decides
+    for team in teams.iter() {
+        arena.arena_competition_enrollment.set_sender(team);
+        let res = arena
+            .arena_competition_enrollment
+            .enroll(Uint128::one(), &coins(1000, DENOM))?;
+        assert!(res.events.iter().any(|e| e.ty == "wasm"
+            && e.attributes
+                .iter()
+                .any(|attr| attr.key == "result" && attr.value == "pending_approval")));
+    }
+
+    let applications =
+        arena
+            .arena_competition_enrollment
+            .applications(Uint128::one(), None, None)?;
+    assert_eq!(applications.len(), 3);
+    assert!(!arena
+        .arena_competition_enrollment
+        .is_member(teams[0].to_string(), Uint128::one())?);
+
+    // Only the host can approve applicants
+    let result = arena
+        .arena_competition_enrollment
+        .approve_applicants(vec![teams[0].to_string()], Uint128::one());
+    assert!(result.is_err());
+
+    arena.arena_competition_enrollment.set_sender(&admin);
+    arena.arena_competition_enrollment.approve_applicants(
+        vec![teams[0].to_string(), teams[1].to_string()],
+        Uint128::one(),
+    )?;
+    arena
+        .arena_competition_enrollment
+        .reject_applicants(vec![teams[2].to_string()], Uint128::one())?;
+
+    assert!(arena
+        .arena_competition_enrollment
+        .is_member(teams[1].to_string(), Uint128::one())?);
+    assert_eq!(
+        mock.query_balance(&teams[2], DENOM)?,
+        Uint128::new(100_000u128)
+    );
+    let applications =
+        arena
+            .arena_competition_enrollment
+            .applications(Uint128::one(), None, None)?;
+    assert!(applications.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_permissionless_trigger() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    // Set teams
+    let team1 = mock.addr_make_with_balance("team 1", coins(100_000u128, DENOM))?;
+    let team2 = mock.addr_make_with_balance("team 2", coins(100_000u128, DENOM))?;
+    let keeper = mock.addr_make("keeper");
+
+    // Register the enrollment module
+    arena.dao_dao.dao_proposal_sudo.set_sender(&admin);
+    arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&arena_interface::core::ExecuteMsg::Extension {
+                msg: arena_interface::core::ExecuteExt::UpdateEnrollmentModules {
+                    to_add: Some(vec![arena.arena_competition_enrollment.addr_str()?]),
+                    to_remove: None,
+                },
+            })?,
+            funds: vec![],
+        })])?;
+
+    // Create a wager enrollment with a 1% trigger bounty
+    arena.arena_competition_enrollment.set_sender(&admin);
+    let create_enrollment_msg = ExecuteMsg::CreateEnrollment {
+        min_members: Some(Uint64::new(2)),
+        max_members: Uint64::new(2),
+        entry_fee: Some(coins(1000, DENOM)[0].clone()),
+        expiration: Expiration::AtHeight(1000000),
+        category_id: Some(Uint128::new(1)),
+        competition_info: CompetitionInfoMsg {
+            name: "Test Wager".to_string(),
+            description: "A test wager".to_string(),
+            expiration: Expiration::AtHeight(2000000),
+            rules: Some(vec!["Wager Rule".to_string()]),
+            rulesets: None,
+            banner: None,
+            additional_layered_fees: None,
+        },
+        competition_type: CompetitionType::Wager {},
+        group_contract_info: ModuleInstantiateInfo {
+            code_id: arena.arena_group.code_id()?,
+            msg: to_json_binary(&group::InstantiateMsg { members: None })?,
+            admin: None,
+            funds: vec![],
+            label: "Arena Group".to_string(),
+        },
+        waitlist_size: None,
+        eligibility_requirements: None,
+        approval_required: None,
+        escrow_id: Some(arena.arena_escrow.code_id()?),
+        trigger_bounty: Some(Decimal::percent(1)),
+    };
+
+    arena
+        .arena_competition_enrollment
+        .execute(&create_enrollment_msg, None)?;
+
+    arena.arena_competition_enrollment.set_sender(&team1);
+    arena
+        .arena_competition_enrollment
+        .enroll(Uint128::one(), &coins(1000, DENOM))?;
+
+    // Cannot trigger before the enrollment is full or expired
+    arena.arena_competition_enrollment.set_sender(&keeper);
+    let result = arena
+        .arena_competition_enrollment
+        .trigger_expiration(arena.arena_escrow.code_id()?, Uint128::one());
+    assert!(result.is_err());
+
+    arena.arena_competition_enrollment.set_sender(&team2);
+    arena
+        .arena_competition_enrollment
+        .enroll(Uint128::one(), &coins(1000, DENOM))?;
+
+    // Non-hosts cannot choose a different escrow
+    arena.arena_competition_enrollment.set_sender(&keeper);
+    let result = arena
+        .arena_competition_enrollment
+        .trigger_expiration(arena.arena_escrow.code_id()? + 1, Uint128::one());
+    assert!(result.is_err());
+
+    let res = arena
+        .arena_competition_enrollment
+        .trigger_expiration(arena.arena_escrow.code_id()?, Uint128::one())?;
+    assert!(res.events.iter().any(|e| e.ty == "wasm"
+        && e.attributes
+            .iter()
+            .any(|attr| attr.key == "result" && attr.value == "competition_created")));
+
+    // The keeper receives 1% of the entry fees
+    assert_eq!(mock.query_balance(&keeper, DENOM)?, Uint128::new(20u128));
+
+    Ok(())
+}
+
+#[test]
+fn test_migrated_enrollment_trigger() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    // Set teams
+    let team1 = mock.addr_make_with_balance("team 1", coins(100_000u128, DENOM))?;
+    let team2 = mock.addr_make_with_balance("team 2", coins(100_000u128, DENOM))?;
+    let keeper = mock.addr_make("keeper");
+
+    // Register the enrollment module
+    arena.dao_dao.dao_proposal_sudo.set_sender(&admin);
+    arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&arena_interface::core::ExecuteMsg::Extension {
+                msg: arena_interface::core::ExecuteExt::UpdateEnrollmentModules {
+                    to_add: Some(vec![arena.arena_competition_enrollment.addr_str()?]),
+                    to_remove: None,
+                },
+            })?,
+            funds: vec![],
+        })])?;
+
+    // Enrollments created before the escrow was stored have no escrow id
+    arena.arena_competition_enrollment.set_sender(&admin);
+    let create_enrollment_msg = ExecuteMsg::CreateEnrollment {
+        min_members: Some(Uint64::new(2)),
+        max_members: Uint64::new(2),
+        entry_fee: Some(coins(1000, DENOM)[0].clone()),
+        expiration: Expiration::AtHeight(1000000),
+        category_id: Some(Uint128::new(1)),
+        competition_info: CompetitionInfoMsg {
+            name: "Test Wager".to_string(),
+            description: "A test wager".to_string(),
+            expiration: Expiration::AtHeight(2000000),
+            rules: Some(vec!["Wager Rule".to_string()]),
+            rulesets: None,
+            banner: None,
+            additional_layered_fees: None,
+        },
+        competition_type: CompetitionType::Wager {},
+        group_contract_info: ModuleInstantiateInfo {
+            code_id: arena.arena_group.code_id()?,
+            msg: to_json_binary(&group::InstantiateMsg { members: None })?,
+            admin: None,
+            funds: vec![],
+            label: "Arena Group".to_string(),
+        },
+        waitlist_size: None,
+        eligibility_requirements: None,
+        approval_required: None,
+        escrow_id: None,
+        trigger_bounty: None,
+    };
+
+    arena
+        .arena_competition_enrollment
+        .execute(&create_enrollment_msg, None)?;
+
+    for team in [&team1, &team2] {
+        arena.arena_competition_enrollment.set_sender(team);
+        arena
+            .arena_competition_enrollment
+            .enroll(Uint128::one(), &coins(1000, DENOM))?;
+    }
+
+    // Only the host can trigger the enrollment
+    arena.arena_competition_enrollment.set_sender(&keeper);
+    let result = arena
+        .arena_competition_enrollment
+        .trigger_expiration(arena.arena_escrow.code_id()?, Uint128::one());
+    assert!(result.is_err());
+
+    // The migration backfills the escrow id
+    arena
+        .arena_competition_enrollment
+        .call_as(&arena.dao_dao.dao_core.address()?)
+        .migrate(
+            &MigrateMsg::WithEscrowId {
+                escrow_id: arena.arena_escrow.code_id()?,
+            },
+            arena.arena_competition_enrollment.code_id()?,
+        )?;
+
+    let enrollment = arena
+        .arena_competition_enrollment
+        .enrollment(Uint128::one())?;
+    assert_eq!(enrollment.escrow_id, Some(arena.arena_escrow.code_id()?));
+
+    // Non-hosts still cannot choose a different escrow
+    let result = arena
+        .arena_competition_enrollment
+        .trigger_expiration(arena.arena_escrow.code_id()? + 1, Uint128::one());
+    assert!(result.is_err());
+
+    let res = arena
+        .arena_competition_enrollment
+        .trigger_expiration(arena.arena_escrow.code_id()?, Uint128::one())?;
+    assert!(res.events.iter().any(|e| e.ty == "wasm"
+        && e.attributes
+            .iter()
+            .any(|attr| attr.key == "result" && attr.value == "competition_created")));
+
+    Ok(())
+}
+
+#[test]
+fn test_recurring_template() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    // Set teams
+    let team1 = mock.addr_make_with_balance("team 1", coins(100_000u128, DENOM))?;
+    let team2 = mock.addr_make_with_balance("team 2", coins(100_000u128, DENOM))?;
+
+    // Register the enrollment module
+    arena.dao_dao.dao_proposal_sudo.set_sender(&admin);
+    arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&arena_interface::core::ExecuteMsg::Extension {
+                msg: arena_interface::core::ExecuteExt::UpdateEnrollmentModules {
+                    to_add: Some(vec![arena.arena_competition_enrollment.addr_str()?]),
+                    to_remove: None,
+                },
+            })?,
+            funds: vec![],
+        })])?;
+
+    // Create a recurring wager template
+    arena.arena_competition_enrollment.set_sender(&admin);
+    arena
+        .arena_competition_enrollment
+        .create_template(EnrollmentTemplateInfo {
+            min_members: Some(Uint64::new(2)),
+            max_members: Uint64::new(2),
+            entry_fee: Some(coins(1000, DENOM)[0].clone()),
+            enrollment_duration: Duration::Height(1000),
+            competition_duration: Duration::Height(1000),
+            category_id: Some(Uint128::new(1)),
+            name: "Weekly Cup".to_string(),
+            description: "A recurring wager".to_string(),
+            rules: Some(vec!["Wager Rule".to_string()]),
+            rulesets: None,
+            banner: None,
+            additional_layered_fees: None,
+            competition_type: CompetitionType::Wager {},
+            group_contract_info: ModuleInstantiateInfo {
+                code_id: arena.arena_group.code_id()?,
+                msg: to_json_binary(&group::InstantiateMsg { members: None })?,
+                admin: None,
+                funds: vec![],
+                label: "Arena Group".to_string(),
+            },
+            waitlist_size: None,
+            eligibility_requirements: None,
+            approval_required: None,
+            escrow_id: None,
+            trigger_bounty: None,
+            is_recurring: true,
+        })?;
+
+    // Only the host can create editions
+    arena.arena_competition_enrollment.set_sender(&team1);
+    let result = arena
+        .arena_competition_enrollment
+        .create_from_template(Uint128::one(), None);
+    assert!(result.is_err());
+
+    arena.arena_competition_enrollment.set_sender(&admin);
+    arena
+        .arena_competition_enrollment
+        .create_from_template(Uint128::one(), None)?;
+
+    let enrollment = arena
+        .arena_competition_enrollment
+        .enrollment(Uint128::one())?;
+    assert_eq!(enrollment.template_id, Some(Uint128::one()));
+    assert_eq!(enrollment.host, admin);
+
+    for team in [&team1, &team2] {
+        arena.arena_competition_enrollment.set_sender(team);
+        arena
+            .arena_competition_enrollment
+            .enroll(Uint128::one(), &coins(1000, DENOM))?;
+    }
+
+    // Triggering the edition schedules the next one
+    arena.arena_competition_enrollment.set_sender(&admin);
+    let res = arena
+        .arena_competition_enrollment
+        .trigger_expiration(arena.arena_escrow.code_id()?, Uint128::one())?;
+    assert!(res.events.iter().any(|e| e.ty == "wasm"
+        && e.attributes
+            .iter()
+            .any(|attr| attr.key == "result" && attr.value == "competition_created")));
+
+    assert_eq!(
+        arena.arena_competition_enrollment.enrollment_count()?,
+        Uint128::new(2)
+    );
+    let next_enrollment = arena
+        .arena_competition_enrollment
+        .enrollment(Uint128::new(2))?;
+    assert_eq!(next_enrollment.template_id, Some(Uint128::one()));
+    assert!(!next_enrollment.has_triggered_expiration);
+
+    // An edition expiring without enough members does not schedule another one
+    mock.wait_blocks(1001)?;
+    let res = arena
+        .arena_competition_enrollment
+        .trigger_expiration(arena.arena_escrow.code_id()?, Uint128::new(2))?;
+    assert!(res.events.iter().any(|e| e.ty == "wasm"
+        && e.attributes
+            .iter()
+            .any(|attr| attr.key == "result" && attr.value == "expired_insufficient_members")));
+
+    assert_eq!(
+        arena.arena_competition_enrollment.enrollment_count()?,
+        Uint128::new(2)
+    );
+
+    Ok(())
+}
+
 #[test]
 #[ignore = "RPC blocks"]
 fn test_migration_v2_v2_1() -> anyhow::Result<()> {
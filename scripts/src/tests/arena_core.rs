@@ -1,14 +1,36 @@
-use arena_interface::core::{
-    ExecuteExt, ExecuteMsg, NewCompetitionCategory, NewRuleset, QueryExtFns,
+use arena_competition_enrollment::{
+    msg::{CompetitionInfoMsg, ExecuteMsg as EnrollmentExecuteMsg},
+    state::CompetitionType,
 };
-use cosmwasm_std::{to_json_binary, CosmosMsg, Decimal, Uint128, WasmMsg};
+use arena_interface::{
+    competition::msg::{ExecuteBaseFns as _, QueryBaseFns as _},
+    core::{
+        CategoryMetadata, EditCompetitionCategory, EditRuleset, EscrowMigration, ExecuteExt,
+        ExecuteMsg, NewCompetitionCategory, NewRuleset, QueryExtFns, RulesetVersion,
+    },
+    fees::TaxTier,
+    group::{self, GroupContractInfo},
+    hosts::HostBan,
+    ratings::{RatingConfig, RatingSystem},
+};
+use arena_wager_module::msg::WagerInstantiateExt;
+use cosmwasm_std::{
+    coins, to_json_binary, Coin, CosmosMsg, Decimal, SignedDecimal, Uint128, Uint64, WasmMsg,
+};
+use cw_balance::{Distribution, MemberPercentage};
+use cw_denom::UncheckedDenom;
 use cw_orch::{anyhow, prelude::*};
-use cw_utils::Duration;
+use cw_utils::{Duration, Expiration};
+use dao_interface::{state::ModuleInstantiateInfo, CoreQueryMsgFns};
 use dao_proposal_sudo::msg::ExecuteMsgFns as _;
+use dao_voting::{
+    deposit::{DepositRefundPolicy, DepositToken, UncheckedDepositInfo},
+    voting::Vote,
+};
 
-use crate::tests::helpers::setup_arena;
+use crate::tests::helpers::{setup_arena, setup_voting_module, teams_to_members};
 
-use super::PREFIX;
+use super::{DENOM, PREFIX};
 
 #[test]
 fn test_create_category() -> anyhow::Result<()> {
@@ -26,6 +48,10 @@ fn test_create_category() -> anyhow::Result<()> {
                 msg: ExecuteExt::UpdateCategories {
                     to_add: Some(vec![NewCompetitionCategory {
                         name: "New Category".to_string(),
+                        rating_system: None,
+                        rating_config: None,
+                        parent_id: None,
+                        metadata: None,
                     }]),
                     to_edit: None,
                 },
@@ -50,6 +76,10 @@ fn test_create_category() -> anyhow::Result<()> {
                 msg: ExecuteExt::UpdateCategories {
                     to_add: Some(vec![NewCompetitionCategory {
                         name: "".to_string(),
+                        rating_system: None,
+                        rating_config: None,
+                        parent_id: None,
+                        metadata: None,
                     }]),
                     to_edit: None,
                 },
@@ -92,6 +122,187 @@ fn test_update_category() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_category_rating_system() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    // Categories default to Glicko-2
+    let category = arena.arena_core.category(Uint128::one())?;
+    assert_eq!(category.rating_system, None);
+
+    // Create an Elo category
+    arena.dao_dao.dao_proposal_sudo.set_sender(&admin);
+    arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&ExecuteMsg::Extension {
+                msg: ExecuteExt::UpdateCategories {
+                    to_add: Some(vec![NewCompetitionCategory {
+                        name: "Casual".to_string(),
+                        rating_system: Some(RatingSystem::Elo {
+                            k_factor: Decimal::from_ratio(32u128, 1u128),
+                        }),
+                        rating_config: None,
+                        parent_id: None,
+                        metadata: None,
+                    }]),
+                    to_edit: None,
+                },
+            })?,
+            funds: vec![],
+        })])?;
+
+    let category = arena.arena_core.category(Uint128::new(3))?;
+    assert_eq!(
+        category.rating_system,
+        Some(RatingSystem::Elo {
+            k_factor: Decimal::from_ratio(32u128, 1u128),
+        })
+    );
+
+    // Switch a category to the Gaussian system
+    arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&ExecuteMsg::Extension {
+                msg: ExecuteExt::UpdateCategories {
+                    to_add: None,
+                    to_edit: Some(vec![EditCompetitionCategory::SetRatingSystem {
+                        category_id: Uint128::one(),
+                        rating_system: RatingSystem::Gaussian {
+                            beta: Decimal::from_ratio(250u128, 1u128),
+                            tau: Decimal::from_ratio(5u128, 1u128),
+                        },
+                    }]),
+                },
+            })?,
+            funds: vec![],
+        })])?;
+
+    let category = arena.arena_core.category(Uint128::one())?;
+    assert_eq!(
+        category.rating_system,
+        Some(RatingSystem::Gaussian {
+            beta: Decimal::from_ratio(250u128, 1u128),
+            tau: Decimal::from_ratio(5u128, 1u128),
+        })
+    );
+
+    // A k-factor of 0 is invalid
+    let result = arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&ExecuteMsg::Extension {
+                msg: ExecuteExt::UpdateCategories {
+                    to_add: None,
+                    to_edit: Some(vec![EditCompetitionCategory::SetRatingSystem {
+                        category_id: Uint128::one(),
+                        rating_system: RatingSystem::Elo {
+                            k_factor: Decimal::zero(),
+                        },
+                    }]),
+                },
+            })?,
+            funds: vec![],
+        })]);
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_category_rating_config() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    // Categories default to the standard parameters and the global rating period
+    let category = arena.arena_core.category(Uint128::one())?;
+    assert_eq!(category.rating_config, None);
+
+    let rating_config = RatingConfig {
+        initial_rating: SignedDecimal::from_ratio(1000, 1),
+        rating_period: Some(Duration::Time(86400)),
+        ..RatingConfig::default()
+    };
+
+    arena.dao_dao.dao_proposal_sudo.set_sender(&admin);
+    arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&ExecuteMsg::Extension {
+                msg: ExecuteExt::UpdateCategories {
+                    to_add: None,
+                    to_edit: Some(vec![EditCompetitionCategory::SetRatingConfig {
+                        category_id: Uint128::one(),
+                        rating_config: rating_config.clone(),
+                    }]),
+                },
+            })?,
+            funds: vec![],
+        })])?;
+
+    let category = arena.arena_core.category(Uint128::one())?;
+    assert_eq!(category.rating_config, Some(rating_config));
+
+    // A max deviation below the initial deviation is invalid
+    let result = arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&ExecuteMsg::Extension {
+                msg: ExecuteExt::UpdateCategories {
+                    to_add: Some(vec![NewCompetitionCategory {
+                        name: "Invalid".to_string(),
+                        rating_system: None,
+                        rating_config: Some(RatingConfig {
+                            max_deviation: Decimal::from_ratio(100u128, 1u128),
+                            ..RatingConfig::default()
+                        }),
+                        parent_id: None,
+                        metadata: None,
+                    }]),
+                    to_edit: None,
+                },
+            })?,
+            funds: vec![],
+        })]);
+    assert!(result.is_err());
+
+    // A rating period of 0 is invalid
+    let result = arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&ExecuteMsg::Extension {
+                msg: ExecuteExt::UpdateCategories {
+                    to_add: None,
+                    to_edit: Some(vec![EditCompetitionCategory::SetRatingConfig {
+                        category_id: Uint128::one(),
+                        rating_config: RatingConfig {
+                            rating_period: Some(Duration::Height(0)),
+                            ..RatingConfig::default()
+                        },
+                    }]),
+                },
+            })?,
+            funds: vec![],
+        })]);
+    assert!(result.is_err());
+
+    Ok(())
+}
+
 #[test]
 fn test_disable_category() -> anyhow::Result<()> {
     let mock = MockBech32::new(PREFIX);
@@ -171,6 +382,124 @@ fn test_update_tax() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_update_tax_schedule() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    // Override the tax for category 1 and the wager module, with a lower tier for large prize pools
+    let volume_tiers = vec![TaxTier {
+        threshold: Coin::new(10000, DENOM),
+        tax: Decimal::percent(1),
+    }];
+    arena.dao_dao.dao_proposal_sudo.set_sender(&admin);
+    arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&ExecuteMsg::Extension {
+                msg: ExecuteExt::UpdateTaxSchedule {
+                    category_taxes: Some(vec![(Uint128::one(), Some(Decimal::percent(10)))]),
+                    module_taxes: Some(vec![("Wagers".to_string(), Some(Decimal::percent(2)))]),
+                    volume_tiers: Some(volume_tiers.clone()),
+                },
+            })?,
+            funds: vec![],
+        })])?;
+    mock.next_block()?;
+
+    // Category overrides take precedence over module overrides, which take precedence over the global tax
+    let height = mock.block_info()?.height;
+    let tax_config =
+        arena
+            .arena_core
+            .tax_config(height, Some(Uint128::one()), Some("Wagers".to_string()))?;
+    assert_eq!(tax_config.tax, Decimal::percent(10));
+    assert_eq!(tax_config.volume_tiers, Some(volume_tiers));
+    assert_eq!(
+        tax_config.tax_for_prize_pool(&[Coin::new(20000, DENOM)]),
+        Decimal::percent(1)
+    );
+    assert_eq!(
+        tax_config.tax_for_prize_pool(&[Coin::new(5000, DENOM)]),
+        Decimal::percent(10)
+    );
+    assert_eq!(
+        arena
+            .arena_core
+            .tax_config(height, Some(Uint128::new(2)), Some("Wagers".to_string()))?
+            .tax,
+        Decimal::percent(2)
+    );
+    assert_eq!(
+        arena.arena_core.tax_config(height, None, None)?.tax,
+        arena.arena_core.tax(None)?
+    );
+
+    // Removing the category override falls back to the module override from then on
+    arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&ExecuteMsg::Extension {
+                msg: ExecuteExt::UpdateTaxSchedule {
+                    category_taxes: Some(vec![(Uint128::one(), None)]),
+                    module_taxes: None,
+                    volume_tiers: None,
+                },
+            })?,
+            funds: vec![],
+        })])?;
+    mock.next_block()?;
+
+    assert_eq!(
+        arena
+            .arena_core
+            .tax_config(
+                mock.block_info()?.height,
+                Some(Uint128::one()),
+                Some("Wagers".to_string())
+            )?
+            .tax,
+        Decimal::percent(2)
+    );
+    assert_eq!(
+        arena
+            .arena_core
+            .tax_config(height, Some(Uint128::one()), Some("Wagers".to_string()))?
+            .tax,
+        Decimal::percent(10)
+    );
+
+    // Overrides must be below 100% and target an existing competition module
+    for msg in [
+        ExecuteExt::UpdateTaxSchedule {
+            category_taxes: Some(vec![(Uint128::one(), Some(Decimal::one()))]),
+            module_taxes: None,
+            volume_tiers: None,
+        },
+        ExecuteExt::UpdateTaxSchedule {
+            category_taxes: None,
+            module_taxes: Some(vec![("Unknown".to_string(), Some(Decimal::percent(2)))]),
+            volume_tiers: None,
+        },
+    ] {
+        let result = arena
+            .dao_dao
+            .dao_proposal_sudo
+            .proposal_execute(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: arena.arena_core.addr_str()?,
+                msg: to_json_binary(&ExecuteMsg::Extension { msg })?,
+                funds: vec![],
+            })]);
+        assert!(result.is_err());
+    }
+
+    Ok(())
+}
+
 #[test]
 fn test_competition_modules() -> anyhow::Result<()> {
     let mock = MockBech32::new(PREFIX);
@@ -242,6 +571,7 @@ fn test_update_rulesets() -> anyhow::Result<()> {
                         description: "New Ruleset".to_string(),
                     }]),
                     to_disable: None,
+                    to_edit: None,
                 },
             })?,
             funds: vec![],
@@ -265,6 +595,7 @@ fn test_update_rulesets() -> anyhow::Result<()> {
                 msg: ExecuteExt::UpdateRulesets {
                     to_add: None,
                     to_disable: Some(vec![Uint128::one()]),
+                    to_edit: None,
                 },
             })?,
             funds: vec![],
@@ -279,6 +610,149 @@ fn test_update_rulesets() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_ruleset_versions() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let host = mock.addr_make("host");
+    arena.dao_dao.dao_proposal_sudo.set_sender(&admin);
+
+    let update_rulesets = |to_add: Option<Vec<NewRuleset>>,
+                           to_disable: Option<Vec<Uint128>>,
+                           to_edit: Option<Vec<EditRuleset>>|
+     -> anyhow::Result<_> {
+        Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&ExecuteMsg::Extension {
+                msg: ExecuteExt::UpdateRulesets {
+                    to_add,
+                    to_disable,
+                    to_edit,
+                },
+            })?,
+            funds: vec![],
+        }))
+    };
+    let create_wager = |ruleset_versions: Option<Vec<RulesetVersion>>| -> anyhow::Result<_> {
+        Ok(arena.arena_wager_module.call_as(&host).create_competition(
+            "A test wager".to_string(),
+            Expiration::AtHeight(mock.block_info()?.height + 100),
+            GroupContractInfo::New {
+                info: ModuleInstantiateInfo {
+                    code_id: arena.arena_group.code_id()?,
+                    msg: to_json_binary(&group::InstantiateMsg { members: None })?,
+                    admin: None,
+                    funds: vec![],
+                    label: "Arena Group".to_string(),
+                },
+            },
+            WagerInstantiateExt {},
+            "Test Wager".to_string(),
+            None,
+            Some(Uint128::one()),
+            None,
+            None,
+            None,
+            ruleset_versions,
+            Some(vec![Uint128::one()]),
+        )?)
+    };
+
+    arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![update_rulesets(
+            Some(vec![NewRuleset {
+                category_id: Uint128::one(),
+                rules: vec!["Best of 3".to_string()],
+                description: "Game Ruleset".to_string(),
+            }]),
+            None,
+            None,
+        )?])?;
+    let ruleset = arena.arena_core.ruleset(Uint128::one())?;
+    assert_eq!(ruleset.current_version(), 1);
+
+    // Competitions are pinned to the current version by default
+    create_wager(None)?;
+    let wager = arena.arena_wager_module.competition(Uint128::one())?;
+    assert_eq!(
+        wager.ruleset_versions,
+        Some(vec![RulesetVersion::Pinned { version: 1 }])
+    );
+
+    // Editing a ruleset keeps its previous version
+    arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![update_rulesets(
+            None,
+            None,
+            Some(vec![EditRuleset {
+                id: Uint128::one(),
+                rules: vec!["Best of 5".to_string()],
+                description: "Game Ruleset".to_string(),
+            }]),
+        )?])?;
+    let ruleset = arena.arena_core.ruleset(Uint128::one())?;
+    assert_eq!(ruleset.current_version(), 2);
+    assert_eq!(ruleset.rules, vec!["Best of 5".to_string()]);
+
+    let history = arena
+        .arena_core
+        .ruleset_history(Uint128::one(), None, None)?;
+    assert_eq!(history.len(), 2);
+    let pinned = arena
+        .arena_core
+        .ruleset_version(Uint128::one(), 1)?
+        .unwrap();
+    assert_eq!(pinned.rules, vec!["Best of 3".to_string()]);
+    assert!(arena
+        .arena_core
+        .ruleset_version(Uint128::one(), 3)?
+        .is_none());
+
+    // Competitions can follow the latest version or pin an existing one
+    create_wager(Some(vec![RulesetVersion::Latest {}]))?;
+    let wager = arena.arena_wager_module.competition(Uint128::new(2))?;
+    assert_eq!(
+        wager.ruleset_versions,
+        Some(vec![RulesetVersion::Latest {}])
+    );
+
+    create_wager(Some(vec![RulesetVersion::Pinned { version: 1 }]))?;
+    let result = create_wager(Some(vec![RulesetVersion::Pinned { version: 3 }]));
+    assert!(result.is_err());
+    let result = create_wager(Some(vec![]));
+    assert!(result.is_err());
+
+    // Disabled rulesets cannot be edited
+    arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![update_rulesets(
+            None,
+            Some(vec![Uint128::one()]),
+            None,
+        )?])?;
+    let result = arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![update_rulesets(
+            None,
+            None,
+            Some(vec![EditRuleset {
+                id: Uint128::one(),
+                rules: vec!["Best of 7".to_string()],
+                description: "Game Ruleset".to_string(),
+            }]),
+        )?]);
+    assert!(result.is_err());
+
+    Ok(())
+}
+
 #[test]
 fn test_update_rating_period() -> anyhow::Result<()> {
     let mock = MockBech32::new(PREFIX);
@@ -318,6 +792,15 @@ fn test_update_rating_period() -> anyhow::Result<()> {
         })]);
     assert!(result.is_err());
 
+    // A fresh deployment has no ratings migration to continue
+    let result = arena.arena_core.call_as(&admin).execute(
+        &ExecuteMsg::Extension {
+            msg: ExecuteExt::ContinueRatingsMigration { limit: None },
+        },
+        None,
+    );
+    assert!(result.is_err());
+
     Ok(())
 }
 
@@ -387,3 +870,670 @@ fn test_update_enrollment_modules() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_host_denylist() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let host = mock.addr_make_with_balance("host", coins(10000, DENOM))?;
+    arena.dao_dao.dao_proposal_sudo.set_sender(&admin);
+
+    let update_host_denylist = |to_add: Option<Vec<HostBan<String>>>,
+                                to_remove: Option<Vec<String>>|
+     -> anyhow::Result<_> {
+        Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&ExecuteMsg::Extension {
+                msg: ExecuteExt::UpdateHostDenylist { to_add, to_remove },
+            })?,
+            funds: vec![],
+        }))
+    };
+    let create_wager = || -> anyhow::Result<_> {
+        Ok(arena.arena_wager_module.call_as(&host).create_competition(
+            "A test wager".to_string(),
+            Expiration::AtHeight(mock.block_info()?.height + 100),
+            GroupContractInfo::New {
+                info: ModuleInstantiateInfo {
+                    code_id: arena.arena_group.code_id()?,
+                    msg: to_json_binary(&group::InstantiateMsg { members: None })?,
+                    admin: None,
+                    funds: vec![],
+                    label: "Arena Group".to_string(),
+                },
+            },
+            WagerInstantiateExt {},
+            "Test Wager".to_string(),
+            None,
+            Some(Uint128::one()),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )?)
+    };
+
+    // Only the DAO can ban hosts
+    let ban = HostBan {
+        host: host.to_string(),
+        reason: "Abandoned competitions".to_string(),
+        expiration: Some(Expiration::AtHeight(mock.block_info()?.height + 10)),
+    };
+    let result = arena.arena_core.call_as(&host).execute(
+        &ExecuteExt::UpdateHostDenylist {
+            to_add: Some(vec![ban.clone()]),
+            to_remove: None,
+        }
+        .into(),
+        None,
+    );
+    assert!(result.is_err());
+
+    // Bans cannot already be expired
+    let result = arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![update_host_denylist(
+            Some(vec![HostBan {
+                expiration: Some(Expiration::AtHeight(mock.block_info()?.height)),
+                ..ban.clone()
+            }]),
+            None,
+        )?]);
+    assert!(result.is_err());
+
+    arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![update_host_denylist(Some(vec![ban.clone()]), None)?])?;
+    let host_ban = arena.arena_core.host_ban(host.to_string())?.unwrap();
+    assert_eq!(host_ban.host, host);
+    assert_eq!(host_ban.reason, ban.reason);
+    assert_eq!(arena.arena_core.host_denylist(None, None)?, vec![host_ban]);
+
+    // Banned hosts cannot create competitions or enrollments
+    assert!(create_wager().is_err());
+
+    arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&ExecuteMsg::Extension {
+                msg: ExecuteExt::UpdateEnrollmentModules {
+                    to_add: Some(vec![arena.arena_competition_enrollment.addr_str()?]),
+                    to_remove: None,
+                },
+            })?,
+            funds: vec![],
+        })])?;
+    let result = arena.arena_competition_enrollment.call_as(&host).execute(
+        &EnrollmentExecuteMsg::CreateEnrollment {
+            min_members: None,
+            max_members: Uint64::new(2),
+            entry_fee: None,
+            expiration: Expiration::AtHeight(mock.block_info()?.height + 100),
+            category_id: Some(Uint128::one()),
+            competition_info: CompetitionInfoMsg {
+                name: "Test Wager".to_string(),
+                description: "A test wager".to_string(),
+                expiration: Expiration::AtHeight(mock.block_info()?.height + 200),
+                rules: None,
+                rulesets: None,
+                banner: None,
+                additional_layered_fees: None,
+            },
+            competition_type: CompetitionType::Wager {},
+            group_contract_info: ModuleInstantiateInfo {
+                code_id: arena.arena_group.code_id()?,
+                msg: to_json_binary(&group::InstantiateMsg { members: None })?,
+                admin: None,
+                funds: vec![],
+                label: "Arena Group".to_string(),
+            },
+            waitlist_size: None,
+            eligibility_requirements: None,
+            approval_required: None,
+            escrow_id: None,
+            trigger_bounty: None,
+        },
+        None,
+    );
+    assert!(result.is_err());
+
+    // The ban lifts once it expires
+    mock.wait_blocks(10)?;
+    assert!(arena.arena_core.host_ban(host.to_string())?.is_none());
+    assert!(arena.arena_core.host_denylist(None, None)?.is_empty());
+    create_wager()?;
+
+    // Permanent bans last until the DAO removes them
+    arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![update_host_denylist(
+            Some(vec![HostBan {
+                expiration: None,
+                ..ban
+            }]),
+            None,
+        )?])?;
+    mock.wait_blocks(100)?;
+    assert!(create_wager().is_err());
+
+    arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![update_host_denylist(
+            None,
+            Some(vec![host.to_string()]),
+        )?])?;
+    assert!(arena.arena_core.host_ban(host.to_string())?.is_none());
+    create_wager()?;
+
+    Ok(())
+}
+
+#[test]
+fn test_upgrade_competition_module() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    // Modules registered on deployment have their first version recorded
+    let versions = arena
+        .arena_core
+        .module_versions("Wagers".to_string(), None, None)?;
+    assert_eq!(versions.len(), 1);
+    assert_eq!(versions[0].addr, arena.arena_wager_module.address()?);
+    assert_eq!(versions[0].code_id, arena.arena_wager_module.code_id()?);
+
+    arena.arena_wager_module.upload()?;
+    let code_id = arena.arena_wager_module.code_id()?;
+    let upgrade_msg = ExecuteMsg::Extension {
+        msg: ExecuteExt::UpgradeCompetitionModule {
+            key: "Wagers".to_string(),
+            code_id,
+            migrate_msg: to_json_binary(&arena_wager_module::msg::MigrateMsg::FromCompatible {})?,
+            escrow_migration: Some(EscrowMigration {
+                escrow_code_id: arena.arena_escrow.code_id()?,
+                escrow_migrate_msg: arena_interface::escrow::MigrateMsg::FromCompatible {},
+            }),
+        },
+    };
+
+    // Only the DAO can upgrade modules
+    let result = arena.arena_core.call_as(&admin).execute(&upgrade_msg, None);
+    assert!(result.is_err());
+
+    // Unknown keys cannot be upgraded
+    arena.dao_dao.dao_proposal_sudo.set_sender(&admin);
+    let result = arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&ExecuteMsg::Extension {
+                msg: ExecuteExt::UpgradeCompetitionModule {
+                    key: "Unknown".to_string(),
+                    code_id,
+                    migrate_msg: to_json_binary(
+                        &arena_wager_module::msg::MigrateMsg::FromCompatible {},
+                    )?,
+                    escrow_migration: None,
+                },
+            })?,
+            funds: vec![],
+        })]);
+    assert!(result.is_err());
+
+    arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&upgrade_msg)?,
+            funds: vec![],
+        })])?;
+
+    let versions = arena
+        .arena_core
+        .module_versions("Wagers".to_string(), None, None)?;
+    assert_eq!(versions.len(), 2);
+    assert_eq!(versions[1].code_id, code_id);
+    assert_eq!(versions[1].contract, versions[0].contract);
+    assert_eq!(versions[1].addr, arena.arena_wager_module.address()?);
+
+    // The module's competitions fit in the page migrated with the upgrade
+    let result = arena.arena_core.call_as(&admin).execute(
+        &ExecuteMsg::Extension {
+            msg: ExecuteExt::ContinueEscrowMigration {
+                key: "Wagers".to_string(),
+                limit: None,
+            },
+        },
+        None,
+    );
+    assert!(result.is_err());
+
+    // The module cannot be migrated to the code it already uses
+    let result = arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&upgrade_msg)?,
+            funds: vec![],
+        })]);
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_category_hierarchy() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+    setup_voting_module(
+        &mock,
+        &arena,
+        vec![cw4::Member {
+            addr: admin.to_string(),
+            weight: 1u64,
+        }],
+    )?;
+
+    let user1 = mock.addr_make("user1");
+    let user2 = mock.addr_make("user2");
+    arena.dao_dao.dao_proposal_sudo.set_sender(&admin);
+
+    let update_categories = |to_add: Option<Vec<NewCompetitionCategory>>,
+                             to_edit: Option<Vec<EditCompetitionCategory>>|
+     -> anyhow::Result<_> {
+        Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&ExecuteMsg::Extension {
+                msg: ExecuteExt::UpdateCategories { to_add, to_edit },
+            })?,
+            funds: vec![],
+        }))
+    };
+    let new_category =
+        |name: &str, parent_id: u128, metadata: Option<CategoryMetadata>| NewCompetitionCategory {
+            name: name.to_string(),
+            rating_system: None,
+            rating_config: None,
+            parent_id: Some(Uint128::new(parent_id)),
+            metadata,
+        };
+
+    // Nest a game mode and a region under the first category
+    arena.dao_dao.dao_proposal_sudo.proposal_execute(vec![
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&ExecuteMsg::Extension {
+                msg: ExecuteExt::UpdateRulesets {
+                    to_add: Some(vec![NewRuleset {
+                        category_id: Uint128::one(),
+                        rules: vec!["Best of 3".to_string()],
+                        description: "Game Ruleset".to_string(),
+                    }]),
+                    to_disable: None,
+                    to_edit: None,
+                },
+            })?,
+            funds: vec![],
+        }),
+        update_categories(
+            Some(vec![
+                new_category(
+                    "Ranked",
+                    1,
+                    Some(CategoryMetadata {
+                        description: Some("Ranked matches".to_string()),
+                        competition_modules: Some(vec!["Leagues".to_string()]),
+                        ..CategoryMetadata::default()
+                    }),
+                ),
+                new_category(
+                    "Europe",
+                    3,
+                    Some(CategoryMetadata {
+                        image: Some("https://arena.io/europe.png".to_string()),
+                        default_rulesets: Some(vec![Uint128::one()]),
+                        ..CategoryMetadata::default()
+                    }),
+                ),
+            ]),
+            None,
+        )?,
+    ])?;
+
+    // Categories can only be nested 3 levels deep under existing categories
+    let result = arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![update_categories(
+            Some(vec![new_category("Paris", 4, None)]),
+            None,
+        )?]);
+    assert!(result.is_err());
+    let result = arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![update_categories(
+            Some(vec![new_category("Unknown", 10, None)]),
+            None,
+        )?]);
+    assert!(result.is_err());
+
+    // Default rulesets must belong to the category or its parents
+    let result = arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![update_categories(
+            Some(vec![new_category(
+                "Other Region",
+                2,
+                Some(CategoryMetadata {
+                    default_rulesets: Some(vec![Uint128::one()]),
+                    ..CategoryMetadata::default()
+                }),
+            )]),
+            None,
+        )?]);
+    assert!(result.is_err());
+
+    let subcategories = arena.arena_core.subcategories(Uint128::one(), None, None)?;
+    assert_eq!(subcategories.len(), 1);
+    assert_eq!(subcategories[0].name, "Ranked");
+    let path = arena.arena_core.category_path(Uint128::new(4))?;
+    assert_eq!(
+        path.iter().map(|x| x.id.u128()).collect::<Vec<_>>(),
+        vec![1, 3, 4]
+    );
+    assert!(arena
+        .arena_core
+        .is_valid_category_and_rulesets(Uint128::new(4), vec![Uint128::one()])?);
+    assert!(!arena
+        .arena_core
+        .is_valid_category_and_rulesets(Uint128::new(2), vec![Uint128::one()])?);
+
+    // Unset metadata is inherited from the parent categories
+    let metadata = arena
+        .arena_core
+        .category_metadata(Uint128::new(4))?
+        .unwrap();
+    assert_eq!(metadata.description, Some("Ranked matches".to_string()));
+    assert_eq!(
+        metadata.image,
+        Some("https://arena.io/europe.png".to_string())
+    );
+    assert_eq!(
+        metadata.competition_modules,
+        Some(vec!["Leagues".to_string()])
+    );
+
+    let create_wager = || -> anyhow::Result<_> {
+        Ok(arena
+            .arena_wager_module
+            .call_as(&admin)
+            .create_competition(
+                "A test wager".to_string(),
+                Expiration::AtHeight(1000000),
+                GroupContractInfo::New {
+                    info: ModuleInstantiateInfo {
+                        code_id: arena.arena_group.code_id()?,
+                        msg: to_json_binary(&group::InstantiateMsg {
+                            members: teams_to_members(&[user1.clone(), user2.clone()]),
+                        })?,
+                        admin: None,
+                        funds: vec![],
+                        label: "Arena Group".to_string(),
+                    },
+                },
+                WagerInstantiateExt {},
+                "Test Wager".to_string(),
+                None,
+                Some(Uint128::new(4)),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )?)
+    };
+
+    // The region only allows the competition modules of its game mode
+    assert!(create_wager().is_err());
+    arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![update_categories(
+            None,
+            Some(vec![EditCompetitionCategory::SetMetadata {
+                category_id: Uint128::new(3),
+                metadata: CategoryMetadata {
+                    description: Some("Ranked matches".to_string()),
+                    competition_modules: Some(vec!["Wagers".to_string()]),
+                    ..CategoryMetadata::default()
+                },
+            }]),
+        )?])?;
+    create_wager()?;
+
+    // Competitions without rulesets use the category's default rulesets
+    let wager = arena.arena_wager_module.competition(Uint128::one())?;
+    assert_eq!(wager.rulesets, Some(vec![Uint128::one()]));
+
+    arena
+        .arena_wager_module
+        .call_as(&admin)
+        .process_competition(
+            Uint128::one(),
+            Some(Distribution {
+                member_percentages: vec![MemberPercentage {
+                    addr: user1.to_string(),
+                    percentage: Decimal::one(),
+                }],
+                remainder_addr: user1.to_string(),
+            }),
+        )?;
+
+    // Results are rated in the region and each of its parent categories
+    mock.wait_seconds(604800)?;
+    for category_id in [1u128, 3, 4] {
+        arena.arena_core.execute(
+            &ExecuteExt::CloseRatingPeriod {
+                category_id: Uint128::new(category_id),
+                start_after: None,
+                limit: None,
+            }
+            .into(),
+            None,
+        )?;
+
+        let leaderboard =
+            arena
+                .arena_core
+                .rating_leaderboard(Uint128::new(category_id), None, None)?;
+        assert_eq!(leaderboard.len(), 2);
+        assert_eq!(leaderboard[0].addr, user1);
+        assert_eq!(
+            Some(leaderboard[0].rating.clone()),
+            arena
+                .arena_core
+                .rating(user1.to_string(), Uint128::new(category_id), None)?
+        );
+    }
+    assert!(arena
+        .arena_core
+        .rating(user1.to_string(), Uint128::new(2), None)?
+        .is_none());
+
+    // Leaderboards only list the ratings of their own category
+    assert!(arena
+        .arena_core
+        .rating_leaderboard(Uint128::new(2), None, None)?
+        .is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_community_proposals() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+    setup_voting_module(
+        &mock,
+        &arena,
+        vec![cw4::Member {
+            addr: admin.to_string(),
+            weight: 1u64,
+        }],
+    )?;
+
+    let user = mock.addr_make_with_balance("user", coins(10000, DENOM))?;
+    let proposal_module = arena
+        .dao_dao
+        .dao_core
+        .proposal_modules(None, None)?
+        .into_iter()
+        .find(|x| x.prefix == "B")
+        .expect("Could not find the Arena Core's proposal module")
+        .address;
+    arena.dao_dao.dao_proposal_sudo.set_sender(&admin);
+
+    let ruleset = NewRuleset {
+        category_id: Uint128::one(),
+        rules: vec!["Best of 3".to_string()],
+        description: "Community Ruleset".to_string(),
+    };
+    let propose_ruleset = || {
+        arena.arena_core.call_as(&user).execute(
+            &ExecuteExt::ProposeRuleset {
+                title: "Add a community ruleset".to_string(),
+                description: "Matches are played as a best of 3".to_string(),
+                ruleset: ruleset.clone(),
+            }
+            .into(),
+            Some(&coins(100, DENOM)),
+        )
+    };
+
+    // Community proposals are closed until the DAO sets a proposal deposit
+    assert!(propose_ruleset().is_err());
+
+    arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&ExecuteMsg::UpdateConfig {
+                deposit_info: Some(UncheckedDepositInfo {
+                    denom: DepositToken::Token {
+                        denom: UncheckedDenom::Native(DENOM.to_string()),
+                    },
+                    amount: Uint128::new(100),
+                    refund_policy: DepositRefundPolicy::OnlyPassed,
+                }),
+                submission_policy: None,
+            })?,
+            funds: vec![],
+        })])?;
+
+    // The deposit must be paid
+    let result = arena.arena_core.call_as(&user).execute(
+        &ExecuteExt::ProposeRuleset {
+            title: "Add a community ruleset".to_string(),
+            description: "Matches are played as a best of 3".to_string(),
+            ruleset: ruleset.clone(),
+        }
+        .into(),
+        None,
+    );
+    assert!(result.is_err());
+
+    // Rulesets must be proposed for an existing category
+    let result = arena.arena_core.call_as(&user).execute(
+        &ExecuteExt::ProposeRuleset {
+            title: "Add a community ruleset".to_string(),
+            description: "Matches are played as a best of 3".to_string(),
+            ruleset: NewRuleset {
+                category_id: Uint128::new(100),
+                ..ruleset.clone()
+            },
+        }
+        .into(),
+        Some(&coins(100, DENOM)),
+    );
+    assert!(result.is_err());
+
+    // A passed proposal adds the ruleset and refunds the deposit
+    propose_ruleset()?;
+    assert_eq!(mock.query_balance(&user, DENOM)?, Uint128::new(9900));
+
+    mock.call_as(&admin).execute(
+        &dao_proposal_single::msg::ExecuteMsg::Vote {
+            proposal_id: 1,
+            vote: Vote::Yes,
+            rationale: None,
+        },
+        &[],
+        &proposal_module,
+    )?;
+    mock.call_as(&admin).execute(
+        &dao_proposal_single::msg::ExecuteMsg::Execute { proposal_id: 1 },
+        &[],
+        &proposal_module,
+    )?;
+
+    let rulesets = arena
+        .arena_core
+        .rulesets(Uint128::one(), None, None, None)?;
+    assert_eq!(rulesets.len(), 1);
+    assert_eq!(rulesets[0].description, "Community Ruleset");
+    assert_eq!(mock.query_balance(&user, DENOM)?, Uint128::new(10000));
+
+    // A rejected proposal keeps the deposit under the refund policy
+    let category_count = arena.arena_core.categories(None, None, None)?.len();
+    arena.arena_core.call_as(&user).execute(
+        &ExecuteExt::ProposeCategory {
+            title: "Add a community category".to_string(),
+            description: "A category for casual matches".to_string(),
+            category: NewCompetitionCategory {
+                name: "Casual".to_string(),
+                rating_system: None,
+                rating_config: None,
+                parent_id: Some(Uint128::one()),
+                metadata: None,
+            },
+        }
+        .into(),
+        Some(&coins(100, DENOM)),
+    )?;
+
+    mock.call_as(&admin).execute(
+        &dao_proposal_single::msg::ExecuteMsg::Vote {
+            proposal_id: 2,
+            vote: Vote::No,
+            rationale: None,
+        },
+        &[],
+        &proposal_module,
+    )?;
+    mock.call_as(&admin).execute(
+        &dao_proposal_single::msg::ExecuteMsg::Close { proposal_id: 2 },
+        &[],
+        &proposal_module,
+    )?;
+
+    assert_eq!(
+        arena.arena_core.categories(None, None, None)?.len(),
+        category_count
+    );
+    assert_eq!(mock.query_balance(&user, DENOM)?, Uint128::new(9900));
+
+    Ok(())
+}
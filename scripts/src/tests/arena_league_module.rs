@@ -6,15 +6,16 @@ use arena_interface::competition::msg::{
 use arena_interface::competition::stats::{
     MemberStatsMsg, StatAggregationType, StatMsg, StatType, StatValue, StatValueType,
 };
-use arena_interface::core::QueryExtFns as _;
+use arena_interface::core::{ExecuteExt, QueryExtFns as _};
 use arena_interface::escrow::{ExecuteMsgFns as _, QueryMsgFns as _};
-use arena_interface::group::{self, GroupContractInfo};
+use arena_interface::group::{self, GroupContractInfo, RosterMsg};
 use arena_league_module::msg::{
     ExecuteExtFns as _, LeagueInstantiateExt, LeagueQueryExtFns as _, MatchResultMsg, MigrateMsg,
 };
 use arena_league_module::state::{MatchResult, PointAdjustment};
 use cosmwasm_std::{
-    coins, to_json_binary, Addr, Coin, CosmosMsg, Decimal, Int128, Uint128, Uint64, WasmMsg,
+    coins, to_json_binary, Addr, Coin, CosmosMsg, Decimal, Int128, SignedDecimal, Uint128, Uint64,
+    WasmMsg,
 };
 use cw_balance::{BalanceUnchecked, BalanceVerified, MemberBalanceUnchecked};
 use cw_orch::{anyhow, prelude::*};
@@ -89,6 +90,7 @@ fn test_create_league() -> anyhow::Result<()> {
         None,
         Some(vec!["League Rule".to_string()]),
         None,
+        None,
     )?;
 
     assert!(res.events.iter().any(|e| e.ty == "wasm"
@@ -128,6 +130,7 @@ fn test_create_league() -> anyhow::Result<()> {
         None,
         Some(vec!["Invalid League Rule".to_string()]),
         None,
+        None,
     );
 
     assert!(result.is_err());
@@ -203,6 +206,7 @@ fn test_process_league_matches() -> anyhow::Result<()> {
         None,
         Some(vec!["League Rule".to_string()]),
         None,
+        None,
     )?;
 
     let escrow_addr = res
@@ -310,22 +314,34 @@ fn test_process_league_matches() -> anyhow::Result<()> {
         assert_eq!(balance, expected_balances[i], "Mismatch for team {}", i);
     }
 
+    // Apply the rating period
+    mock.wait_seconds(604800)?;
+    arena.arena_core.execute(
+        &ExecuteExt::CloseRatingPeriod {
+            category_id: Uint128::one(),
+            start_after: None,
+            limit: None,
+        }
+        .into(),
+        None,
+    )?;
+
     // Check that the winner has the highest ELO
     let winner_rating = arena
         .arena_core
-        .rating(leaderboard[0].member.to_string(), Uint128::one())?
+        .rating(leaderboard[0].member.to_string(), Uint128::one(), None)?
         .unwrap();
     let second_place_rating = arena
         .arena_core
-        .rating(leaderboard[1].member.to_string(), Uint128::one())?
+        .rating(leaderboard[1].member.to_string(), Uint128::one(), None)?
         .unwrap();
     let third_place_rating = arena
         .arena_core
-        .rating(leaderboard[2].member.to_string(), Uint128::one())?
+        .rating(leaderboard[2].member.to_string(), Uint128::one(), None)?
         .unwrap();
     let fourth_place_rating = arena
         .arena_core
-        .rating(leaderboard[3].member.to_string(), Uint128::one())?
+        .rating(leaderboard[3].member.to_string(), Uint128::one(), None)?
         .unwrap();
 
     assert!(winner_rating.value > second_place_rating.value);
@@ -335,6 +351,138 @@ fn test_process_league_matches() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_league_team_ratings() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+    setup_voting_module(
+        &mock,
+        &arena,
+        vec![cw4::Member {
+            addr: admin.to_string(),
+            weight: 1u64,
+        }],
+    )?;
+
+    let teams = vec![mock.addr_make("team0"), mock.addr_make("team1")];
+    let players: Vec<_> = (0..4)
+        .map(|i| mock.addr_make(format!("player{}", i)))
+        .collect();
+
+    // Create a group with a roster for each team
+    arena.arena_group.set_sender(&admin);
+    arena.arena_group.instantiate(
+        &group::InstantiateMsg {
+            members: teams_to_members(&teams),
+        },
+        Some(&admin),
+        None,
+    )?;
+    arena.arena_group.execute(
+        &group::ExecuteMsg::UpdateRosters {
+            rosters: vec![
+                RosterMsg {
+                    addr: teams[0].to_string(),
+                    players: vec![players[0].to_string(), players[1].to_string()],
+                },
+                RosterMsg {
+                    addr: teams[1].to_string(),
+                    players: vec![players[2].to_string(), players[3].to_string()],
+                },
+            ],
+        },
+        None,
+    )?;
+
+    // Rosters can only be set for members
+    let result = arena.arena_group.execute(
+        &group::ExecuteMsg::UpdateRosters {
+            rosters: vec![RosterMsg {
+                addr: players[0].to_string(),
+                players: vec![players[1].to_string()],
+            }],
+        },
+        None,
+    );
+    assert!(result.is_err());
+
+    // Create a league using the group
+    arena.arena_league_module.set_sender(&admin);
+    arena.arena_league_module.create_competition(
+        "A team league".to_string(),
+        Expiration::AtHeight(1000000),
+        GroupContractInfo::Existing {
+            addr: arena.arena_group.addr_str()?,
+        },
+        LeagueInstantiateExt {
+            match_win_points: Uint64::new(3),
+            match_draw_points: Uint64::new(1),
+            match_lose_points: Uint64::zero(),
+            distribution: vec![Decimal::percent(100)],
+        },
+        "Team League".to_string(),
+        None,
+        Some(Uint128::one()),
+        None,
+        None,
+        Some(vec!["League Rule".to_string()]),
+        None,
+        None,
+    )?;
+
+    arena.arena_league_module.process_match(
+        Uint128::one(),
+        vec![MatchResultMsg {
+            match_number: Uint128::one(),
+            match_result: MatchResult::Team1,
+        }],
+        Uint64::one(),
+    )?;
+
+    // Apply the rating period
+    mock.wait_seconds(604800)?;
+    arena.arena_core.execute(
+        &ExecuteExt::CloseRatingPeriod {
+            category_id: Uint128::one(),
+            start_after: None,
+            limit: None,
+        }
+        .into(),
+        None,
+    )?;
+
+    // The roster players are rated instead of the teams
+    assert!(arena
+        .arena_core
+        .rating(teams[0].to_string(), Uint128::one(), None)?
+        .is_none());
+
+    let round = arena
+        .arena_league_module
+        .round(Uint128::one(), Uint64::one())?;
+    let (winners, losers) = if round.matches[0].team_1 == teams[0] {
+        (&players[0..2], &players[2..4])
+    } else {
+        (&players[2..4], &players[0..2])
+    };
+    for player in winners {
+        let rating = arena
+            .arena_core
+            .rating(player.to_string(), Uint128::one(), None)?
+            .unwrap();
+        assert!(rating.value > SignedDecimal::from_ratio(1500, 1));
+    }
+    for player in losers {
+        let rating = arena
+            .arena_core
+            .rating(player.to_string(), Uint128::one(), None)?
+            .unwrap();
+        assert!(rating.value < SignedDecimal::from_ratio(1500, 1));
+    }
+
+    Ok(())
+}
+
 #[test]
 fn test_add_point_adjustments() -> anyhow::Result<()> {
     let mock = MockBech32::new(PREFIX);
@@ -395,6 +543,7 @@ fn test_add_point_adjustments() -> anyhow::Result<()> {
         None,
         Some(vec!["League Rule".to_string()]),
         None,
+        None,
     )?;
 
     let escrow_addr = res
@@ -520,6 +669,7 @@ fn test_create_league_with_odd_number_of_teams() -> anyhow::Result<()> {
         None,
         Some(vec!["Odd League Rule".to_string()]),
         None,
+        None,
     )?;
 
     assert!(res.events.iter().any(|e| e.ty == "wasm"
@@ -598,6 +748,7 @@ fn test_process_league_with_ties() -> anyhow::Result<()> {
         None,
         Some(vec!["Tie League Rule".to_string()]),
         None,
+        None,
     )?;
 
     let escrow_addr = res
@@ -790,6 +941,7 @@ fn test_update_distribution() -> anyhow::Result<()> {
         None,
         Some(vec!["Distribution League Rule".to_string()]),
         None,
+        None,
     )?;
 
     // Update distribution
@@ -862,6 +1014,7 @@ fn test_create_huge_league() -> anyhow::Result<()> {
         None,
         None,
         None,
+        None,
     );
 
     assert!(result.is_ok());
@@ -929,6 +1082,7 @@ fn test_process_matches_out_of_order() -> anyhow::Result<()> {
         None,
         Some(vec!["Out of Order League Rule".to_string()]),
         None,
+        None,
     )?;
 
     // Attempt to process matches from round 2 before round 1
@@ -1012,6 +1166,7 @@ fn test_multiple_point_adjustments() -> anyhow::Result<()> {
         None,
         Some(vec!["Multiple Adjustments League Rule".to_string()]),
         None,
+        None,
     )?;
 
     let escrow_addr = res
@@ -1180,6 +1335,7 @@ fn test_league_tiebreaking_logic() -> anyhow::Result<()> {
         None,
         Some(vec!["League Rule".to_string()]),
         None,
+        None,
     )?;
     let league_id = Uint128::one();
 
@@ -1605,6 +1761,7 @@ fn test_league_tiebreaking_logic_with_aggregates() -> anyhow::Result<()> {
         None,
         Some(vec!["League Rule".to_string()]),
         None,
+        None,
     )?;
     let league_id = Uint128::one();
 
@@ -0,0 +1,325 @@
+use arena_interface::competition::msg::{ExecuteBaseFns as _, QueryBaseFns as _};
+use arena_interface::competition::state::CompetitionStatus;
+use arena_interface::core::{ExecuteExt, QueryExtFns as _};
+use arena_interface::escrow::ExecuteMsgFns as _;
+use arena_matchmaking_module::msg::{ExecuteMsg, ExecuteMsgFns as _, QueryMsgFns as _};
+use arena_matchmaking_module::state::MatchmakingConfig;
+use cosmwasm_std::{coins, to_json_binary, CosmosMsg, Decimal, Uint128, WasmMsg};
+use cw_balance::{Distribution, MemberPercentage};
+use cw_orch::{anyhow, prelude::*};
+use cw_utils::Duration;
+use dao_proposal_sudo::msg::ExecuteMsgFns as _;
+
+use crate::tests::helpers::{setup_arena, setup_voting_module};
+
+use super::{DENOM, PREFIX};
+
+#[test]
+fn test_matchmaking() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+    setup_voting_module(
+        &mock,
+        &arena,
+        vec![cw4::Member {
+            addr: admin.to_string(),
+            weight: 1u64,
+        }],
+    )?;
+
+    let user1 = mock.addr_make_with_balance("user1", coins(10000, DENOM))?;
+    let user2 = mock.addr_make_with_balance("user2", coins(10000, DENOM))?;
+    let user3 = mock.addr_make_with_balance("user3", coins(10000, DENOM))?;
+
+    // Matched wagers are hosted by a DAO member, so their results are rated
+    let config = MatchmakingConfig {
+        escrow_id: arena.arena_escrow.code_id()?,
+        group_id: arena.arena_group.code_id()?,
+        host: Some(admin.to_string()),
+        rating_window: Decimal::from_ratio(100u128, 1u128),
+        window_increase: Decimal::from_ratio(50u128, 1u128),
+        window_interval: Duration::Height(10u64),
+        max_window: None,
+        wager_duration: Duration::Time(86400u64),
+    };
+    let result = arena
+        .arena_matchmaking_module
+        .call_as(&admin)
+        .update_config(config.clone());
+    assert!(result.is_err());
+
+    arena.dao_dao.dao_proposal_sudo.set_sender(&admin);
+    arena.dao_dao.dao_proposal_sudo.proposal_execute(vec![
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&arena_interface::core::ExecuteMsg::Extension {
+                msg: ExecuteExt::UpdateEnrollmentModules {
+                    to_add: Some(vec![arena.arena_matchmaking_module.addr_str()?]),
+                    to_remove: None,
+                },
+            })?,
+            funds: vec![],
+        }),
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_matchmaking_module.addr_str()?,
+            msg: to_json_binary(&ExecuteMsg::UpdateConfig { config })?,
+            funds: vec![],
+        }),
+    ])?;
+    assert_eq!(
+        arena.arena_matchmaking_module.config()?.host,
+        Some(admin.clone())
+    );
+
+    // Players are only matched with the same stake
+    arena
+        .arena_matchmaking_module
+        .call_as(&user1)
+        .join_queue(Uint128::one(), &coins(1000, DENOM))?;
+    arena
+        .arena_matchmaking_module
+        .call_as(&user3)
+        .join_queue(Uint128::one(), &coins(500, DENOM))?;
+    let result = arena
+        .arena_matchmaking_module
+        .call_as(&user1)
+        .join_queue(Uint128::one(), &coins(1000, DENOM));
+    assert!(result.is_err());
+    let result = arena
+        .arena_matchmaking_module
+        .match_players(Uint128::one(), None);
+    assert!(result.is_err());
+
+    arena
+        .arena_matchmaking_module
+        .call_as(&user3)
+        .leave_queue(Uint128::one())?;
+    assert_eq!(mock.query_balance(&user3, DENOM)?, Uint128::new(10000));
+
+    // Unrated players share the initial rating
+    arena
+        .arena_matchmaking_module
+        .call_as(&user2)
+        .join_queue(Uint128::one(), &coins(1000, DENOM))?;
+    let queue = arena
+        .arena_matchmaking_module
+        .queue(Uint128::one(), None, None)?;
+    assert_eq!(queue.len(), 2);
+    assert_eq!(queue[0].addr, user1);
+    assert_eq!(queue[1].addr, user2);
+    assert_eq!(queue[0].rating, queue[1].rating);
+
+    // Anyone can match the queue into a wager funded by the stakes
+    arena
+        .arena_matchmaking_module
+        .call_as(&user3)
+        .match_players(Uint128::one(), None)?;
+    assert!(arena
+        .arena_matchmaking_module
+        .queue(Uint128::one(), None, None)?
+        .is_empty());
+
+    let wager = arena.arena_wager_module.competition(Uint128::one())?;
+    assert_eq!(wager.host, admin);
+    assert_eq!(wager.category_id, Some(Uint128::one()));
+    assert!(matches!(wager.status, CompetitionStatus::Active { .. }));
+
+    arena.arena_wager_module.set_sender(&admin);
+    arena.arena_wager_module.process_competition(
+        Uint128::one(),
+        Some(Distribution {
+            member_percentages: vec![MemberPercentage {
+                addr: user1.to_string(),
+                percentage: Decimal::one(),
+            }],
+            remainder_addr: user1.to_string(),
+        }),
+    )?;
+
+    arena.arena_escrow.set_address(&wager.escrow.unwrap());
+    arena.arena_escrow.call_as(&user1).withdraw(None, None)?;
+    assert_eq!(mock.query_balance(&user1, DENOM)?, Uint128::new(10900)); // Initial 10000 - 1000 stake + 1900 winnings (after 5% tax)
+    assert_eq!(mock.query_balance(&user2, DENOM)?, Uint128::new(9000));
+
+    mock.wait_seconds(604800)?;
+    arena.arena_core.execute(
+        &ExecuteExt::CloseRatingPeriod {
+            category_id: Uint128::one(),
+            start_after: None,
+            limit: None,
+        }
+        .into(),
+        None,
+    )?;
+    let user1_rating = arena
+        .arena_core
+        .rating(user1.to_string(), Uint128::one(), None)?
+        .unwrap();
+    let user2_rating = arena
+        .arena_core
+        .rating(user2.to_string(), Uint128::one(), None)?
+        .unwrap();
+    assert!(user1_rating.value.abs_diff(user2_rating.value) > Decimal::from_ratio(100u128, 1u128));
+
+    // The rating window widens the longer players are queued
+    arena
+        .arena_matchmaking_module
+        .call_as(&user1)
+        .join_queue(Uint128::one(), &[])?;
+    arena
+        .arena_matchmaking_module
+        .call_as(&user2)
+        .join_queue(Uint128::one(), &[])?;
+    let result = arena
+        .arena_matchmaking_module
+        .match_players(Uint128::one(), None);
+    assert!(result.is_err());
+
+    mock.wait_blocks(100)?;
+    let entry = arena
+        .arena_matchmaking_module
+        .queue_entry(user1.to_string(), Uint128::one())?
+        .unwrap();
+    assert_eq!(entry.rating, user1_rating.value);
+    assert_eq!(entry.rating_window, Decimal::from_ratio(600u128, 1u128));
+
+    arena
+        .arena_matchmaking_module
+        .match_players(Uint128::one(), None)?;
+    let wager = arena.arena_wager_module.competition(Uint128::new(2))?;
+    assert!(wager.escrow.is_none());
+    assert!(matches!(wager.status, CompetitionStatus::Active { .. }));
+
+    // Each player's stake is paid on their behalf, so a wager without a winner refunds the players
+    let user4 = mock.addr_make_with_balance("user4", coins(10000, DENOM))?;
+    for user in [&user3, &user4] {
+        arena
+            .arena_matchmaking_module
+            .call_as(user)
+            .join_queue(Uint128::one(), &coins(500, DENOM))?;
+    }
+    arena
+        .arena_matchmaking_module
+        .match_players(Uint128::one(), None)?;
+    let wager = arena.arena_wager_module.competition(Uint128::new(3))?;
+    assert!(matches!(wager.status, CompetitionStatus::Active { .. }));
+
+    arena
+        .arena_wager_module
+        .process_competition(Uint128::new(3), None)?;
+    arena.arena_escrow.set_address(&wager.escrow.unwrap());
+    for user in [&user3, &user4] {
+        arena.arena_escrow.call_as(user).withdraw(None, None)?;
+    }
+    assert_eq!(
+        mock.query_balance(&user3, DENOM)?,
+        mock.query_balance(&user4, DENOM)?
+    );
+    assert!(mock.query_balance(&user3, DENOM)? > Uint128::new(9500));
+    assert!(mock
+        .query_balance(&arena.arena_matchmaking_module.address()?, DENOM)?
+        .is_zero());
+
+    Ok(())
+}
+
+#[test]
+fn test_matchmaking_queue_pages() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let config = MatchmakingConfig {
+        escrow_id: arena.arena_escrow.code_id()?,
+        group_id: arena.arena_group.code_id()?,
+        host: None,
+        rating_window: Decimal::from_ratio(100u128, 1u128),
+        window_increase: Decimal::zero(),
+        window_interval: Duration::Height(10u64),
+        max_window: None,
+        wager_duration: Duration::Time(86400u64),
+    };
+    arena.dao_dao.dao_proposal_sudo.set_sender(&admin);
+    arena.dao_dao.dao_proposal_sudo.proposal_execute(vec![
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&arena_interface::core::ExecuteMsg::Extension {
+                msg: ExecuteExt::UpdateEnrollmentModules {
+                    to_add: Some(vec![arena.arena_matchmaking_module.addr_str()?]),
+                    to_remove: None,
+                },
+            })?,
+            funds: vec![],
+        }),
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_matchmaking_module.addr_str()?,
+            msg: to_json_binary(&ExecuteMsg::UpdateConfig { config })?,
+            funds: vec![],
+        }),
+    ])?;
+
+    // Fill more than a page of the queue with players who have different stakes
+    let mut players = vec![];
+    for i in 0..31u128 {
+        let player = mock.addr_make_with_balance(format!("player{i}"), coins(10000, DENOM))?;
+        arena
+            .arena_matchmaking_module
+            .call_as(&player)
+            .join_queue(Uint128::one(), &coins(i + 1, DENOM))?;
+        players.push(player);
+    }
+
+    // A player beyond the first page is found by their stake and rating
+    let late = mock.addr_make_with_balance("late", coins(10000, DENOM))?;
+    arena
+        .arena_matchmaking_module
+        .call_as(&late)
+        .join_queue(Uint128::one(), &coins(1, DENOM))?;
+    arena
+        .arena_matchmaking_module
+        .match_players(Uint128::one(), None)?;
+    for addr in [&players[0], &late] {
+        assert!(arena
+            .arena_matchmaking_module
+            .queue_entry(addr.to_string(), Uint128::one())?
+            .is_none());
+    }
+
+    // Players queued after the first page are matched by paging the queue
+    let pair = [
+        mock.addr_make_with_balance("pair1", coins(10000, DENOM))?,
+        mock.addr_make_with_balance("pair2", coins(10000, DENOM))?,
+    ];
+    for player in &pair {
+        arena
+            .arena_matchmaking_module
+            .call_as(player)
+            .join_queue(Uint128::one(), &coins(100, DENOM))?;
+    }
+    let result = arena
+        .arena_matchmaking_module
+        .match_players(Uint128::one(), None);
+    assert!(result.is_err());
+
+    let page = arena
+        .arena_matchmaking_module
+        .queue(Uint128::one(), None, None)?;
+    assert_eq!(page.len(), 30);
+    arena
+        .arena_matchmaking_module
+        .match_players(Uint128::one(), Some(page[29].position))?;
+    for addr in &pair {
+        assert!(arena
+            .arena_matchmaking_module
+            .queue_entry(addr.to_string(), Uint128::one())?
+            .is_none());
+    }
+    assert_eq!(
+        arena
+            .arena_matchmaking_module
+            .queue(Uint128::one(), None, None)?
+            .len(),
+        30
+    );
+
+    Ok(())
+}
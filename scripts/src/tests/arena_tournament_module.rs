@@ -2,7 +2,7 @@ use std::str::FromStr;
 
 use arena_interface::{
     competition::msg::EscrowInstantiateInfo,
-    core::QueryExtFns as _,
+    core::{ExecuteExt as CoreExecuteExt, QueryExtFns as _},
     escrow::{ExecuteMsgFns as _, QueryMsgFns as _},
     group::{self, AddMemberMsg, GroupContractInfo},
 };
@@ -13,7 +13,7 @@ use arena_tournament_module::{
     },
     state::{EliminationType, MatchResult},
 };
-use cosmwasm_std::{coins, to_json_binary, Decimal, Uint128};
+use cosmwasm_std::{coins, to_json_binary, Decimal, SignedDecimal, Uint128};
 use cw_balance::{BalanceUnchecked, MemberBalanceUnchecked};
 use cw_orch::{anyhow, environment::ChainState, prelude::*};
 use cw_orch_clone_testing::CloneTesting;
@@ -362,6 +362,16 @@ pub fn test_ratings() -> Result<(), CwOrchError> {
     mock.next_block()?;
 
     // Check ratings after first round
+    mock.wait_seconds(604800)?;
+    arena.arena_core.execute(
+        &CoreExecuteExt::CloseRatingPeriod {
+            category_id: Uint128::one(),
+            start_after: None,
+            limit: None,
+        }
+        .into(),
+        None,
+    )?;
     let ratings_after_first_round =
         arena
             .arena_core
@@ -385,6 +395,16 @@ pub fn test_ratings() -> Result<(), CwOrchError> {
     mock.next_block()?;
 
     // Check ratings after second round
+    mock.wait_seconds(604800)?;
+    arena.arena_core.execute(
+        &CoreExecuteExt::CloseRatingPeriod {
+            category_id: Uint128::one(),
+            start_after: None,
+            limit: None,
+        }
+        .into(),
+        None,
+    )?;
     let ratings_after_second_round =
         arena
             .arena_core
@@ -418,21 +438,46 @@ pub fn test_ratings() -> Result<(), CwOrchError> {
     mock.next_block()?;
 
     // Check final ratings
+    mock.wait_seconds(604800)?;
+    arena.arena_core.execute(
+        &CoreExecuteExt::CloseRatingPeriod {
+            category_id: Uint128::one(),
+            start_after: None,
+            limit: None,
+        }
+        .into(),
+        None,
+    )?;
     let final_ratings = arena
         .arena_core
         .rating_leaderboard(Uint128::one(), None, None)?;
     assert_eq!(final_ratings.len(), 10);
 
+    // The leaderboard is paged from the highest rating down
+    let first_page = arena
+        .arena_core
+        .rating_leaderboard(Uint128::one(), None, Some(4))?;
+    let last = first_page.last().unwrap();
+    let second_page = arena.arena_core.rating_leaderboard(
+        Uint128::one(),
+        Some((last.rating.value, last.addr.to_string())),
+        Some(4),
+    )?;
+    assert_eq!(
+        [first_page, second_page].concat(),
+        final_ratings[..8].to_vec()
+    );
+
     // Verify that ratings have changed
     assert_ne!(ratings_after_first_round, final_ratings);
 
     // Verify winner has the highest rating
     let winner_rating = final_ratings[0].rating.clone();
-    assert!(winner_rating.value > Decimal::from_str("1500")?);
+    assert!(winner_rating.value > SignedDecimal::from_str("1500")?);
 
     // Verify loser has the lowest rating
     let loser_rating = final_ratings.last().unwrap().rating.clone();
-    assert!(loser_rating.value < Decimal::from_str("1500")?);
+    assert!(loser_rating.value < SignedDecimal::from_str("1500")?);
 
     Ok(())
 }
@@ -1436,6 +1481,7 @@ fn create_competition_msg<Chain: ChainState>(
         expiration: cw_utils::Expiration::Never {},
         rules: None,
         rulesets: None,
+        ruleset_versions: None,
         banner: None,
         instantiate_extension: TournamentInstantiateExt {
             elimination_type,
@@ -5,20 +5,31 @@ use arena_interface::competition::state::CompetitionStatus;
 use arena_interface::competition::stats::{
     MemberStatsMsg, StatAggregationType, StatMsg, StatType, StatValue, StatValueType,
 };
-use arena_interface::core::QueryExtFns;
+use arena_interface::core::{
+    CategoryMetadata, EditCompetitionCategory, ExecuteExt, JailBond, QueryExtFns, SlashRecipient,
+};
 use arena_interface::escrow::{ExecuteMsgFns as _, QueryMsgFns as _};
+use arena_interface::fees::FeeInformation;
 use arena_interface::group::{self, GroupContractInfo};
+use arena_interface::hosts::{HostActivity, HostStats, HostStatsResponse};
+use arena_interface::ratings::{
+    IntegrityConfig, PendingRatingResult, RatingConfig, RatingSnapshot, RatingSource, Season,
+    SeasonReward, SeasonStanding, SoftReset,
+};
 use arena_interface::registry::ExecuteMsgFns as _;
 use arena_wager_module::msg::{MigrateMsg, WagerInstantiateExt};
-use cosmwasm_std::{coins, to_json_binary, Addr, Coin, Decimal, Uint128};
+use cosmwasm_std::{
+    coins, to_json_binary, Addr, Coin, CosmosMsg, Decimal, SignedDecimal, Uint128, WasmMsg,
+};
 use cw_balance::{
     BalanceUnchecked, BalanceVerified, Distribution, MemberBalanceUnchecked, MemberPercentage,
 };
 use cw_orch::{anyhow, prelude::*};
 use cw_orch_clone_testing::CloneTesting;
-use cw_utils::Expiration;
+use cw_utils::{Duration, Expiration};
 use dao_interface::state::ModuleInstantiateInfo;
 use dao_interface::CoreQueryMsgFns;
+use dao_proposal_sudo::msg::ExecuteMsgFns as _;
 use networks::PION_1;
 
 use crate::arena::Arena;
@@ -83,6 +94,7 @@ fn test_create_wager() -> anyhow::Result<()> {
         None,
         Some(vec!["Wager Rule".to_string()]),
         None,
+        None,
     )?;
 
     assert!(res.events.iter().any(|e| e.ty == "wasm"
@@ -162,6 +174,7 @@ fn test_process_wager() -> anyhow::Result<()> {
         None,
         Some(vec!["Wager Rule".to_string()]),
         None,
+        None,
     )?;
 
     let escrow_addr = res
@@ -211,23 +224,209 @@ fn test_process_wager() -> anyhow::Result<()> {
     assert_eq!(user1_balance, Uint128::new(10900)); // Initial 10000 - 1000 stake + 1900 winnings (after 5% tax)
     assert_eq!(user2_balance, Uint128::new(9000)); // Initial 10000 - 1000 stake
 
+    // Results are buffered until the rating period closes, along with the competition behind them
+    let source = RatingSource {
+        module: arena.arena_wager_module.address()?,
+        competition_id: Uint128::one(),
+        match_number: None,
+    };
+    assert!(arena
+        .arena_core
+        .rating(user1.to_string(), Uint128::one(), None)?
+        .is_none());
+    assert_eq!(
+        arena
+            .arena_core
+            .pending_rating_results(user1.to_string(), Uint128::one())?,
+        vec![PendingRatingResult {
+            opponents: vec![user2.clone()],
+            score: Decimal::one(),
+            source: Some(source.clone()),
+            weight: None,
+        }]
+    );
+
+    // The rating period cannot be closed early
+    let result = arena.arena_core.execute(
+        &ExecuteExt::CloseRatingPeriod {
+            category_id: Uint128::one(),
+            start_after: None,
+            limit: None,
+        }
+        .into(),
+        None,
+    );
+    assert!(result.is_err());
+
+    // The ended period is applied in pages
+    mock.wait_seconds(604800)?;
+    arena.arena_core.execute(
+        &ExecuteExt::CloseRatingPeriod {
+            category_id: Uint128::one(),
+            start_after: None,
+            limit: Some(1),
+        }
+        .into(),
+        None,
+    )?;
+    assert!(arena
+        .arena_core
+        .rating_period_end(Uint128::one())?
+        .is_none());
+    let pending_members = [&user1, &user2]
+        .into_iter()
+        .map(|addr| {
+            arena
+                .arena_core
+                .pending_rating_results(addr.to_string(), Uint128::one())
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .filter(|x| !x.is_empty())
+        .count();
+    assert_eq!(pending_members, 1);
+
+    arena.arena_core.execute(
+        &ExecuteExt::CloseRatingPeriod {
+            category_id: Uint128::one(),
+            start_after: None,
+            limit: None,
+        }
+        .into(),
+        None,
+    )?;
+    assert!(arena
+        .arena_core
+        .pending_rating_results(user1.to_string(), Uint128::one())?
+        .is_empty());
+    assert!(arena
+        .arena_core
+        .pending_rating_results(user2.to_string(), Uint128::one())?
+        .is_empty());
+
+    // Nothing is left to apply
+    let result = arena.arena_core.execute(
+        &ExecuteExt::CloseRatingPeriod {
+            category_id: Uint128::one(),
+            start_after: None,
+            limit: None,
+        }
+        .into(),
+        None,
+    );
+    assert!(result.is_err());
+
     // Ensure ELO was updated
-    let user1_rating = arena.arena_core.rating(user1.to_string(), Uint128::one())?;
-    let user2_rating = arena.arena_core.rating(user2.to_string(), Uint128::one())?;
+    let user1_rating = arena
+        .arena_core
+        .rating(user1.to_string(), Uint128::one(), None)?;
+    let user2_rating = arena
+        .arena_core
+        .rating(user2.to_string(), Uint128::one(), None)?;
 
     assert!(user1_rating.is_some());
     assert!(user2_rating.is_some());
 
     assert!(user1_rating.as_ref().unwrap().value > user2_rating.as_ref().unwrap().value);
 
+    // Both members were rated against the other's rating from before the period
+    let initial = SignedDecimal::from_ratio(1500, 1);
+    assert_eq!(
+        user1_rating.as_ref().unwrap().value - initial,
+        initial - user2_rating.as_ref().unwrap().value
+    );
+
+    // The change is kept in the rating history
+    let height = mock.block_info()?.height;
+    let history = arena
+        .arena_core
+        .rating_history(user1.to_string(), Uint128::one(), None, None)?;
+    assert_eq!(
+        history,
+        vec![RatingSnapshot {
+            height,
+            rating: user1_rating.clone().unwrap(),
+            sources: vec![source.clone()],
+        }]
+    );
+    assert_eq!(
+        arena
+            .arena_core
+            .rating(user1.to_string(), Uint128::one(), Some(height))?,
+        user1_rating
+    );
+    assert!(arena
+        .arena_core
+        .rating(user1.to_string(), Uint128::one(), Some(height - 1))?
+        .is_none());
+
     // Check that ELO for category 2 is different
     assert_ne!(
         user1_rating,
         arena
             .arena_core
-            .rating(user1.to_string(), Uint128::new(2))?
+            .rating(user1.to_string(), Uint128::new(2), None)?
     );
 
+    // Only the DAO can revert a competition's rating adjustments
+    let revert_msg = ExecuteExt::RevertRatingAdjustments {
+        module: arena.arena_wager_module.addr_str()?,
+        competition_id: Uint128::one(),
+        limit: Some(1),
+    };
+    let result = arena.arena_core.execute(&revert_msg.clone().into(), None);
+    assert!(result.is_err());
+
+    mock.wait_blocks(1)?;
+    arena.dao_dao.dao_proposal_sudo.set_sender(&admin);
+    arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&arena_interface::core::ExecuteMsg::from(revert_msg))?,
+            funds: vec![],
+        })])?;
+
+    // Once started, anyone can continue the revert until it is complete
+    let continue_msg = ExecuteExt::RevertRatingAdjustments {
+        module: arena.arena_wager_module.addr_str()?,
+        competition_id: Uint128::one(),
+        limit: None,
+    };
+    arena
+        .arena_core
+        .call_as(&user2)
+        .execute(&continue_msg.clone().into(), None)?;
+    let result = arena
+        .arena_core
+        .call_as(&user2)
+        .execute(&continue_msg.into(), None);
+    assert!(result.is_err());
+
+    let user1_reverted = arena
+        .arena_core
+        .rating(user1.to_string(), Uint128::one(), None)?
+        .unwrap();
+    let user2_reverted = arena
+        .arena_core
+        .rating(user2.to_string(), Uint128::one(), None)?
+        .unwrap();
+    assert_eq!(user1_reverted.value, SignedDecimal::from_ratio(1500, 1));
+    assert_eq!(user2_reverted.value, SignedDecimal::from_ratio(1500, 1));
+
+    // The revert is approximate, as the deviation and volatility are not restored
+    assert_eq!(user1_reverted.state, user1_rating.unwrap().state);
+
+    // The reversal is recorded without sources
+    let history =
+        arena
+            .arena_core
+            .rating_history(user1.to_string(), Uint128::one(), None, Some(height))?;
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].rating, user1_reverted);
+    assert!(history[0].sources.is_empty());
+
     Ok(())
 }
 
@@ -289,11 +488,14 @@ fn test_wager_with_additional_fees() -> anyhow::Result<()> {
                 receiver: fee_receiver.to_string(),
                 cw20_msg: None,
                 cw721_msg: None,
+                min_amounts: None,
+                max_amounts: None,
             }]),
         }),
         None,
         Some(vec!["Fee Wager Rule".to_string()]),
         None,
+        None,
     )?;
 
     let escrow_addr = res
@@ -339,10 +541,402 @@ fn test_wager_with_additional_fees() -> anyhow::Result<()> {
     let fee_receiver_balance = mock.query_balance(&fee_receiver, DENOM)?;
     let dao_balance = mock.query_balance(&arena.dao_dao.dao_core.address()?, DENOM)?; // Assuming admin is the DAO in this case
 
-    assert_eq!(user1_balance, Uint128::new(10862)); // Initial 10000 - 1000 stake + 1862 winnings (after 5% tax and 2% additional fee)
-    assert_eq!(user2_balance, Uint128::new(9000)); // Initial 10000 - 1000 stake
-    assert_eq!(fee_receiver_balance, Uint128::new(38)); // 2% of 1900
-    assert_eq!(dao_balance, Uint128::new(100)); // 5% of 2000
+    assert_eq!(user1_balance, Uint128::new(10862)); // Initial 10000 - 1000 stake + 1862 winnings (after 5% tax and 2% additional fee)
+    assert_eq!(user2_balance, Uint128::new(9000)); // Initial 10000 - 1000 stake
+    assert_eq!(fee_receiver_balance, Uint128::new(38)); // 2% of 1900
+    assert_eq!(dao_balance, Uint128::new(100)); // 5% of 2000
+
+    Ok(())
+}
+
+#[test]
+fn test_wager_with_fee_floors_and_caps() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+
+    let user1 = mock.addr_make_with_balance("user1", coins(10000, DENOM))?;
+    let user2 = mock.addr_make_with_balance("user2", coins(10000, DENOM))?;
+    let fee_receiver1 = mock.addr_make("fee_receiver1");
+    let fee_receiver2 = mock.addr_make("fee_receiver2");
+
+    // Cap the combined fee rate at 15%
+    arena.dao_dao.dao_proposal_sudo.set_sender(&admin);
+    arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&arena_interface::core::ExecuteMsg::from(
+                ExecuteExt::UpdateFeeCeiling {
+                    fee_ceiling: Some(Decimal::percent(15)),
+                },
+            ))?,
+            funds: vec![],
+        })])?;
+    assert_eq!(arena.arena_core.fee_ceiling()?, Some(Decimal::percent(15)));
+
+    arena.arena_wager_module.set_sender(&admin);
+    let escrow_info = |fees: Vec<FeeInformation<String>>| -> anyhow::Result<_> {
+        Ok(EscrowInstantiateInfo {
+            code_id: arena.arena_escrow.code_id()?,
+            msg: to_json_binary(&arena_interface::escrow::InstantiateMsg {
+                dues: [&user1, &user2]
+                    .into_iter()
+                    .map(|user| MemberBalanceUnchecked {
+                        addr: user.to_string(),
+                        balance: BalanceUnchecked {
+                            native: Some(vec![Coin::new(1000, DENOM)]),
+                            cw20: None,
+                            cw721: None,
+                        },
+                    })
+                    .collect(),
+            })?,
+            label: "Fee Wager Escrow".to_string(),
+            additional_layered_fees: Some(fees),
+        })
+    };
+    let group_contract = GroupContractInfo::New {
+        info: ModuleInstantiateInfo {
+            code_id: arena.arena_group.code_id()?,
+            msg: to_json_binary(&group::InstantiateMsg {
+                members: teams_to_members(&[user1.clone(), user2.clone()]),
+            })?,
+            admin: None,
+            funds: vec![],
+            label: "Arena Group".to_string(),
+        },
+    };
+
+    // A 12% fee on top of the 5% Arena tax exceeds the ceiling
+    let result = arena.arena_wager_module.create_competition(
+        "Wager with fees".to_string(),
+        Expiration::AtHeight(mock.block_info()?.height + 100),
+        group_contract.clone(),
+        WagerInstantiateExt {},
+        "Fee Wager".to_string(),
+        None,
+        Some(Uint128::one()),
+        Some(escrow_info(vec![FeeInformation {
+            tax: Decimal::percent(12),
+            receiver: fee_receiver1.to_string(),
+            cw20_msg: None,
+            cw721_msg: None,
+            min_amounts: None,
+            max_amounts: None,
+        }])?),
+        None,
+        None,
+        None,
+        None,
+    );
+    assert!(result.is_err());
+
+    // A fee's minimum cannot be more than its cap
+    let result = arena.arena_wager_module.create_competition(
+        "Wager with fees".to_string(),
+        Expiration::AtHeight(mock.block_info()?.height + 100),
+        group_contract.clone(),
+        WagerInstantiateExt {},
+        "Fee Wager".to_string(),
+        None,
+        Some(Uint128::one()),
+        Some(escrow_info(vec![FeeInformation {
+            tax: Decimal::percent(2),
+            receiver: fee_receiver1.to_string(),
+            cw20_msg: None,
+            cw721_msg: None,
+            min_amounts: Some(coins(100, DENOM)),
+            max_amounts: Some(coins(50, DENOM)),
+        }])?),
+        None,
+        None,
+        None,
+        None,
+    );
+    assert!(result.is_err());
+
+    // A 300 minimum on top of the 100 Arena tax takes 20% of the 2000 prize pool
+    let result = arena.arena_wager_module.create_competition(
+        "Wager with fees".to_string(),
+        Expiration::AtHeight(mock.block_info()?.height + 100),
+        group_contract.clone(),
+        WagerInstantiateExt {},
+        "Fee Wager".to_string(),
+        None,
+        Some(Uint128::one()),
+        Some(escrow_info(vec![FeeInformation {
+            tax: Decimal::percent(2),
+            receiver: fee_receiver1.to_string(),
+            cw20_msg: None,
+            cw721_msg: None,
+            min_amounts: Some(coins(300, DENOM)),
+            max_amounts: None,
+        }])?),
+        None,
+        None,
+        None,
+        None,
+    );
+    assert!(result.is_err());
+
+    // The first fee is raised to its minimum, and the second is lowered to its cap, taking 12.5% of the prize pool
+    let res = arena.arena_wager_module.create_competition(
+        "Wager with fees".to_string(),
+        Expiration::AtHeight(mock.block_info()?.height + 100),
+        group_contract,
+        WagerInstantiateExt {},
+        "Fee Wager".to_string(),
+        None,
+        Some(Uint128::one()),
+        Some(escrow_info(vec![
+            FeeInformation {
+                tax: Decimal::percent(2),
+                receiver: fee_receiver1.to_string(),
+                cw20_msg: None,
+                cw721_msg: None,
+                min_amounts: Some(coins(100, DENOM)),
+                max_amounts: None,
+            },
+            FeeInformation {
+                tax: Decimal::percent(4),
+                receiver: fee_receiver2.to_string(),
+                cw20_msg: None,
+                cw721_msg: None,
+                min_amounts: None,
+                max_amounts: Some(coins(50, DENOM)),
+            },
+        ])?),
+        None,
+        None,
+        None,
+        None,
+    )?;
+
+    let escrow_addr = res
+        .events
+        .iter()
+        .find_map(|event| {
+            event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == "escrow_addr")
+                .map(|attr| attr.value.clone())
+        })
+        .unwrap();
+    arena
+        .arena_escrow
+        .set_address(&Addr::unchecked(escrow_addr));
+    arena
+        .arena_escrow
+        .call_as(&user1)
+        .receive_native(&coins(1000, DENOM))?;
+    arena
+        .arena_escrow
+        .call_as(&user2)
+        .receive_native(&coins(1000, DENOM))?;
+
+    arena.arena_wager_module.process_competition(
+        Uint128::one(),
+        Some(Distribution {
+            member_percentages: vec![MemberPercentage {
+                addr: user1.to_string(),
+                percentage: Decimal::one(),
+            }],
+            remainder_addr: user1.to_string(),
+        }),
+    )?;
+    arena.arena_escrow.call_as(&user1).withdraw(None, None)?;
+
+    assert_eq!(
+        mock.query_balance(&arena.dao_dao.dao_core.address()?, DENOM)?,
+        Uint128::new(100)
+    ); // 5% of 2000
+    assert_eq!(
+        mock.query_balance(&fee_receiver1, DENOM)?,
+        Uint128::new(100)
+    ); // 2% of 1900 is raised to 100
+    assert_eq!(mock.query_balance(&fee_receiver2, DENOM)?, Uint128::new(50)); // 4% of 1800 is capped at 50
+    assert_eq!(mock.query_balance(&user1, DENOM)?, Uint128::new(10750)); // Initial 10000 - 1000 stake + 1750 winnings
+
+    Ok(())
+}
+
+#[test]
+fn test_wager_host_stats() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+    setup_voting_module(
+        &mock,
+        &arena,
+        vec![cw4::Member {
+            addr: admin.to_string(),
+            weight: 1u64,
+        }],
+    )?;
+
+    let user1 = mock.addr_make_with_balance("user1", coins(10000, DENOM))?;
+    let user2 = mock.addr_make_with_balance("user2", coins(10000, DENOM))?;
+
+    // Host activity can only be recorded by competition modules
+    let result = arena.arena_core.call_as(&user1).execute(
+        &ExecuteExt::RecordHostActivity {
+            host: user1.to_string(),
+            activity: HostActivity::Create {},
+        }
+        .into(),
+        None,
+    );
+    assert!(result.is_err());
+
+    arena.arena_wager_module.set_sender(&admin);
+    let group_contract = GroupContractInfo::New {
+        info: ModuleInstantiateInfo {
+            code_id: arena.arena_group.code_id()?,
+            msg: to_json_binary(&group::InstantiateMsg {
+                members: teams_to_members(&[user1.clone(), user2.clone()]),
+            })?,
+            admin: None,
+            funds: vec![],
+            label: "Arena Group".to_string(),
+        },
+    };
+
+    // The host takes a 10% fee from the first wager
+    let res = arena.arena_wager_module.create_competition(
+        "A hosted wager".to_string(),
+        Expiration::AtHeight(mock.block_info()?.height + 100),
+        group_contract.clone(),
+        WagerInstantiateExt {},
+        "Hosted Wager".to_string(),
+        None,
+        Some(Uint128::one()),
+        Some(EscrowInstantiateInfo {
+            code_id: arena.arena_escrow.code_id()?,
+            msg: to_json_binary(&arena_interface::escrow::InstantiateMsg {
+                dues: [&user1, &user2]
+                    .into_iter()
+                    .map(|user| MemberBalanceUnchecked {
+                        addr: user.to_string(),
+                        balance: BalanceUnchecked {
+                            native: Some(vec![Coin::new(1000, DENOM)]),
+                            cw20: None,
+                            cw721: None,
+                        },
+                    })
+                    .collect(),
+            })?,
+            label: "Hosted Wager Escrow".to_string(),
+            additional_layered_fees: Some(vec![FeeInformation {
+                tax: Decimal::percent(10),
+                receiver: admin.to_string(),
+                cw20_msg: None,
+                cw721_msg: None,
+                min_amounts: None,
+                max_amounts: None,
+            }]),
+        }),
+        None,
+        None,
+        None,
+        None,
+    )?;
+
+    let escrow_addr = res
+        .events
+        .iter()
+        .find_map(|event| {
+            event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == "escrow_addr")
+                .map(|attr| attr.value.clone())
+        })
+        .unwrap();
+    arena
+        .arena_escrow
+        .set_address(&Addr::unchecked(escrow_addr));
+    arena
+        .arena_escrow
+        .call_as(&user1)
+        .receive_native(&coins(1000, DENOM))?;
+    arena
+        .arena_escrow
+        .call_as(&user2)
+        .receive_native(&coins(1000, DENOM))?;
+
+    arena.arena_wager_module.process_competition(
+        Uint128::one(),
+        Some(Distribution {
+            member_percentages: vec![MemberPercentage {
+                addr: user1.to_string(),
+                percentage: Decimal::one(),
+            }],
+            remainder_addr: user1.to_string(),
+        }),
+    )?;
+    assert_eq!(mock.query_balance(&admin, DENOM)?, Uint128::new(190)); // 10% of 1900
+
+    // The second wager is jailed after it expires
+    arena.arena_wager_module.create_competition(
+        "A jailed wager".to_string(),
+        Expiration::AtHeight(mock.block_info()?.height + 10),
+        group_contract,
+        WagerInstantiateExt {},
+        "Jailed Wager".to_string(),
+        None,
+        Some(Uint128::one()),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )?;
+    mock.wait_blocks(20)?;
+
+    let distribution = Some(Distribution {
+        member_percentages: vec![MemberPercentage {
+            addr: user2.to_string(),
+            percentage: Decimal::one(),
+        }],
+        remainder_addr: user2.to_string(),
+    });
+    arena.arena_wager_module.call_as(&user1).jail_competition(
+        Uint128::new(2),
+        "Jailed Wager".to_string(),
+        "The host did not process the wager".to_string(),
+        distribution.clone(),
+        &[],
+    )?;
+
+    // Further jail proposals do not count against the host again
+    arena.arena_wager_module.call_as(&user2).jail_competition(
+        Uint128::new(2),
+        "Jailed Wager".to_string(),
+        "The host did not process the wager".to_string(),
+        distribution,
+        &[],
+    )?;
+
+    let host_stats = arena.arena_core.host_stats(admin.to_string())?.unwrap();
+    assert_eq!(
+        host_stats,
+        HostStatsResponse {
+            host: admin.clone(),
+            stats: HostStats {
+                competitions_created: 2,
+                competitions_processed: 1,
+                competitions_jailed: 1,
+                earnings: coins(190, DENOM),
+            },
+            reputation: Some(Decimal::percent(50)),
+        }
+    );
+    assert_eq!(
+        arena.arena_core.host_stats_list(None, None)?,
+        vec![host_stats]
+    );
+    assert!(arena.arena_core.host_stats(user1.to_string())?.is_none());
 
     Ok(())
 }
@@ -441,6 +1035,7 @@ fn test_wager_with_preset_distributions() -> anyhow::Result<()> {
         None,
         Some(vec!["Preset Distribution Wager Rule".to_string()]),
         None,
+        None,
     )?;
 
     let escrow_addr = res
@@ -586,6 +1181,7 @@ fn test_wager_with_draw() -> anyhow::Result<()> {
         None,
         None,
         None,
+        None,
     )?;
 
     let escrow_addr = res
@@ -710,6 +1306,7 @@ fn test_wager_with_malicious_host() -> anyhow::Result<()> {
         None,
         None,
         None,
+        None,
     )?;
 
     let escrow_addr = res
@@ -835,6 +1432,7 @@ fn test_wager_with_updated_distribution_after_activation() -> anyhow::Result<()>
         None,
         Some(vec!["Updated Distribution Wager Rule".to_string()]),
         None,
+        None,
     )?;
 
     let escrow_addr = res
@@ -997,6 +1595,7 @@ fn test_jailed_wager_resolved_by_dao() -> anyhow::Result<()> {
         None,
         Some(vec!["Wager Rule".to_string()]),
         None,
+        None,
     )?;
 
     let escrow_addr = res
@@ -1057,6 +1656,12 @@ fn test_jailed_wager_resolved_by_dao() -> anyhow::Result<()> {
         &[],
     )?;
 
+    // Evidence submitted after jailing is attached to later jail proposals
+    arena
+        .arena_wager_module
+        .call_as(&user1)
+        .submit_evidence(Uint128::one(), vec!["Match screenshot".to_string()])?;
+
     // Ensure other person can propose a result
     arena.arena_wager_module.call_as(&user1).jail_competition(
         Uint128::one(),
@@ -1079,6 +1684,27 @@ fn test_jailed_wager_resolved_by_dao() -> anyhow::Result<()> {
         CompetitionStatus::Jailed { activation_height }
     );
 
+    // Each jail proposal keeps a snapshot of the competition for the DAO
+    let jail_proposal = arena.arena_core.jail_proposal(1)?.unwrap();
+    assert_eq!(jail_proposal.originator, admin);
+    let snapshot = jail_proposal.snapshot.unwrap();
+    assert!(snapshot.evidence_ids.is_empty());
+    assert!(snapshot.host_result.is_none());
+
+    let jail_proposal = arena.arena_core.jail_proposal(2)?.unwrap();
+    assert_eq!(jail_proposal.competition_id, Uint128::one());
+    assert_eq!(jail_proposal.module, arena.arena_wager_module.address()?);
+    assert_eq!(jail_proposal.originator, user1);
+    assert_eq!(
+        jail_proposal.distribution.unwrap().remainder_addr,
+        user1.to_string()
+    );
+    let snapshot = jail_proposal.snapshot.unwrap();
+    assert_eq!(snapshot.evidence_ids, vec![Uint128::zero()]);
+    assert_eq!(snapshot.escrow_balances.unwrap().len(), 2);
+    assert!(snapshot.height <= mock.block_info()?.height);
+    assert!(!snapshot.is_truncated);
+
     // Execute the jailed proposal after expiration
     mock.wait_blocks(100)?;
     mock.call_as(&admin).execute(
@@ -1105,6 +1731,252 @@ fn test_jailed_wager_resolved_by_dao() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_jail_bond() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+    setup_voting_module(
+        &mock,
+        &arena,
+        vec![cw4::Member {
+            addr: admin.to_string(),
+            weight: 1u64,
+        }],
+    )?;
+
+    let host = mock.addr_make("host");
+    let user1 = mock.addr_make_with_balance("user1", coins(10000, DENOM))?;
+    let user2 = mock.addr_make_with_balance("user2", coins(10000, DENOM))?;
+    let proposal_module = arena
+        .dao_dao
+        .dao_core
+        .proposal_modules(None, None)?
+        .into_iter()
+        .find(|x| x.prefix == "B")
+        .expect("Could not find the Arena Core's proposal module")
+        .address;
+    let winner = |addr: &Addr| Distribution {
+        member_percentages: vec![MemberPercentage {
+            addr: addr.to_string(),
+            percentage: Decimal::one(),
+        }],
+        remainder_addr: addr.to_string(),
+    };
+
+    // Jailing the category's competitions requires a bond, which is slashed to the host
+    arena.dao_dao.dao_proposal_sudo.set_sender(&admin);
+    arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&arena_interface::core::ExecuteMsg::from(
+                ExecuteExt::UpdateCategories {
+                    to_add: None,
+                    to_edit: Some(vec![EditCompetitionCategory::SetMetadata {
+                        category_id: Uint128::one(),
+                        metadata: CategoryMetadata {
+                            jail_bond: Some(JailBond {
+                                amount: Coin::new(100, DENOM),
+                                slash_recipient: SlashRecipient::Host {},
+                            }),
+                            ..CategoryMetadata::default()
+                        },
+                    }]),
+                },
+            ))?,
+            funds: vec![],
+        })])?;
+
+    arena.arena_wager_module.set_sender(&host);
+    for _ in 0..4 {
+        arena.arena_wager_module.create_competition(
+            "A test wager".to_string(),
+            Expiration::AtHeight(mock.block_info()?.height + 10),
+            GroupContractInfo::New {
+                info: ModuleInstantiateInfo {
+                    code_id: arena.arena_group.code_id()?,
+                    msg: to_json_binary(&group::InstantiateMsg {
+                        members: teams_to_members(&[user1.clone(), user2.clone()]),
+                    })?,
+                    admin: None,
+                    funds: vec![],
+                    label: "Arena Group".to_string(),
+                },
+            },
+            WagerInstantiateExt {},
+            "Test Wager".to_string(),
+            None,
+            Some(Uint128::one()),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )?;
+    }
+
+    // Only the host can submit a result
+    let result = arena
+        .arena_wager_module
+        .call_as(&user1)
+        .submit_result(Uint128::one(), Some(winner(&user1)));
+    assert!(result.is_err());
+
+    for competition_id in [Uint128::one(), Uint128::new(2), Uint128::new(4)] {
+        arena
+            .arena_wager_module
+            .submit_result(competition_id, Some(winner(&user2)))?;
+    }
+    let host_result = arena
+        .arena_wager_module
+        .host_result(Uint128::one())?
+        .unwrap();
+    assert_eq!(
+        host_result.distribution.map(|x| x.into_unchecked()),
+        Some(winner(&user2))
+    );
+
+    mock.wait_blocks(20)?;
+
+    // The bond must be paid to jail the competition
+    let result = arena.arena_wager_module.call_as(&user1).jail_competition(
+        Uint128::one(),
+        "Jailed Wager".to_string(),
+        "The host submitted the wrong winner".to_string(),
+        Some(winner(&user1)),
+        &[],
+    );
+    assert!(result.is_err());
+
+    for competition_id in [Uint128::one(), Uint128::new(2)] {
+        arena.arena_wager_module.call_as(&user1).jail_competition(
+            competition_id,
+            "Jailed Wager".to_string(),
+            "The host submitted the wrong winner".to_string(),
+            Some(winner(&user1)),
+            &coins(100, DENOM),
+        )?;
+    }
+    assert_eq!(mock.query_balance(&user1, DENOM)?, Uint128::new(9800));
+    let bond = arena
+        .arena_wager_module
+        .jail_bond(user1.to_string(), Uint128::one())?
+        .unwrap();
+    assert_eq!(bond.amount, Coin::new(100, DENOM));
+
+    // The host cannot change its result once the competition is jailed
+    let result = arena
+        .arena_wager_module
+        .submit_result(Uint128::one(), Some(winner(&user1)));
+    assert!(result.is_err());
+
+    // The bond is refunded when the DAO's resolution differs from the host's result
+    mock.call_as(&admin).execute(
+        &dao_proposal_single::msg::ExecuteMsg::Vote {
+            proposal_id: 1,
+            vote: dao_voting::voting::Vote::Yes,
+            rationale: None,
+        },
+        &[],
+        &proposal_module,
+    )?;
+    mock.call_as(&admin).execute(
+        &dao_proposal_single::msg::ExecuteMsg::Execute { proposal_id: 1 },
+        &[],
+        &proposal_module,
+    )?;
+    assert_eq!(
+        arena.arena_wager_module.result(Uint128::one())?,
+        Some(winner(&user1))
+    );
+    assert_eq!(mock.query_balance(&user1, DENOM)?, Uint128::new(9900));
+    assert!(arena
+        .arena_wager_module
+        .jail_bond(user1.to_string(), Uint128::one())?
+        .is_none());
+
+    // The bond is slashed when the DAO upholds the host's result
+    arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_wager_module.addr_str()?,
+            msg: to_json_binary(&arena_wager_module::msg::ExecuteMsg::ProcessCompetition {
+                competition_id: Uint128::new(2),
+                distribution: Some(winner(&user2)),
+            })?,
+            funds: vec![],
+        })])?;
+    assert_eq!(mock.query_balance(&user1, DENOM)?, Uint128::new(9900));
+    assert_eq!(mock.query_balance(&host, DENOM)?, Uint128::new(100));
+
+    // The bond is refunded when the jail proposal is closed while the host has no result to uphold
+    arena.arena_wager_module.call_as(&user1).jail_competition(
+        Uint128::new(3),
+        "Jailed Wager".to_string(),
+        "The host never submitted a result".to_string(),
+        Some(winner(&user1)),
+        &coins(100, DENOM),
+    )?;
+    assert_eq!(mock.query_balance(&user1, DENOM)?, Uint128::new(9800));
+    mock.call_as(&admin).execute(
+        &dao_proposal_single::msg::ExecuteMsg::Vote {
+            proposal_id: 3,
+            vote: dao_voting::voting::Vote::No,
+            rationale: None,
+        },
+        &[],
+        &proposal_module,
+    )?;
+    mock.call_as(&admin).execute(
+        &dao_proposal_single::msg::ExecuteMsg::Close { proposal_id: 3 },
+        &[],
+        &proposal_module,
+    )?;
+    assert_eq!(mock.query_balance(&user1, DENOM)?, Uint128::new(9900));
+    assert!(arena
+        .arena_wager_module
+        .jail_bond(user1.to_string(), Uint128::new(3))?
+        .is_none());
+    assert_eq!(
+        arena.arena_core.jail_proposal(3)?.unwrap().jail_bond,
+        Some(Coin::new(100, DENOM))
+    );
+
+    // The bond is slashed when the DAO rejects the jail proposal while the host has a result
+    arena.arena_wager_module.call_as(&user1).jail_competition(
+        Uint128::new(4),
+        "Jailed Wager".to_string(),
+        "The host submitted the wrong winner".to_string(),
+        Some(winner(&user1)),
+        &coins(100, DENOM),
+    )?;
+    assert_eq!(mock.query_balance(&user1, DENOM)?, Uint128::new(9800));
+    mock.call_as(&admin).execute(
+        &dao_proposal_single::msg::ExecuteMsg::Vote {
+            proposal_id: 4,
+            vote: dao_voting::voting::Vote::No,
+            rationale: None,
+        },
+        &[],
+        &proposal_module,
+    )?;
+    mock.call_as(&admin).execute(
+        &dao_proposal_single::msg::ExecuteMsg::Close { proposal_id: 4 },
+        &[],
+        &proposal_module,
+    )?;
+    assert_eq!(mock.query_balance(&user1, DENOM)?, Uint128::new(9800));
+    assert_eq!(mock.query_balance(&host, DENOM)?, Uint128::new(200));
+    assert!(arena
+        .arena_wager_module
+        .jail_bond(user1.to_string(), Uint128::new(4))?
+        .is_none());
+
+    Ok(())
+}
+
 #[test]
 fn test_wager_with_stats() -> anyhow::Result<()> {
     let mock = MockBech32::new(PREFIX);
@@ -1162,6 +2034,7 @@ fn test_wager_with_stats() -> anyhow::Result<()> {
         None,
         Some(vec!["Wager Rule".to_string()]),
         None,
+        None,
     )?;
 
     let escrow_addr = res
@@ -1306,6 +2179,7 @@ fn test_wager_with_aggregate_stats() -> anyhow::Result<()> {
         None,
         Some(vec!["Wager Rule".to_string()]),
         None,
+        None,
     )?;
 
     let escrow_addr = res
@@ -1397,6 +2271,369 @@ fn test_wager_with_aggregate_stats() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_wager_rating_season() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+    setup_voting_module(
+        &mock,
+        &arena,
+        vec![cw4::Member {
+            addr: admin.to_string(),
+            weight: 1u64,
+        }],
+    )?;
+
+    let user1 = mock.addr_make("user1");
+    let user2 = mock.addr_make("user2");
+
+    arena.arena_wager_module.set_sender(&admin);
+
+    // Create a wager without an escrow
+    arena.arena_wager_module.create_competition(
+        "A test wager".to_string(),
+        Expiration::AtHeight(1000000),
+        GroupContractInfo::New {
+            info: ModuleInstantiateInfo {
+                code_id: arena.arena_group.code_id()?,
+                msg: to_json_binary(&group::InstantiateMsg {
+                    members: teams_to_members(&[user1.clone(), user2.clone()]),
+                })?,
+                admin: None,
+                funds: vec![],
+                label: "Arena Group".to_string(),
+            },
+        },
+        WagerInstantiateExt {},
+        "Test Wager".to_string(),
+        None,
+        Some(Uint128::one()),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )?;
+    arena.arena_wager_module.process_competition(
+        Uint128::one(),
+        Some(Distribution {
+            member_percentages: vec![MemberPercentage {
+                addr: user1.to_string(),
+                percentage: Decimal::one(),
+            }],
+            remainder_addr: user1.to_string(),
+        }),
+    )?;
+
+    mock.wait_seconds(604800)?;
+    arena.arena_core.execute(
+        &ExecuteExt::CloseRatingPeriod {
+            category_id: Uint128::one(),
+            start_after: None,
+            limit: None,
+        }
+        .into(),
+        None,
+    )?;
+    let user1_rating = arena
+        .arena_core
+        .rating(user1.to_string(), Uint128::one(), None)?
+        .unwrap();
+    let user2_rating = arena
+        .arena_core
+        .rating(user2.to_string(), Uint128::one(), None)?
+        .unwrap();
+
+    // Only the DAO can start a season
+    let start_season_msg = ExecuteExt::StartSeason {
+        category_id: Uint128::one(),
+        soft_reset: Some(SoftReset {
+            pull: Decimal::percent(50),
+            deviation_increase: Decimal::zero(),
+        }),
+        reward: Some(SeasonReward {
+            distribution: vec![Decimal::percent(70), Decimal::percent(30)],
+        }),
+    };
+    let result = arena
+        .arena_core
+        .call_as(&user1)
+        .execute(&start_season_msg.clone().into(), None);
+    assert!(result.is_err());
+
+    // The reward pool is paid from the DAO's treasury
+    let dao_addr = arena.dao_dao.dao_core.address()?;
+    mock.add_balance(&dao_addr, coins(1001, DENOM))?;
+    arena.dao_dao.dao_proposal_sudo.set_sender(&admin);
+    arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&arena_interface::core::ExecuteMsg::from(start_season_msg))?,
+            funds: coins(1001, DENOM),
+        })])?;
+
+    // The remainder of the split goes to first place
+    assert_eq!(mock.query_balance(&user1, DENOM)?, Uint128::new(701));
+    assert_eq!(mock.query_balance(&user2, DENOM)?, Uint128::new(300));
+
+    let height = mock.block_info()?.height;
+    let season = arena.arena_core.season(Uint128::one(), 1)?.unwrap();
+    assert_eq!(
+        season,
+        Season {
+            id: 1,
+            start_height: 0,
+            end_height: height,
+            reward_pool: coins(1001, DENOM),
+        }
+    );
+    assert_eq!(
+        arena.arena_core.seasons(Uint128::one(), None, None)?,
+        vec![season]
+    );
+
+    // The final leaderboard keeps the ratings from before the soft reset
+    let leaderboard = arena
+        .arena_core
+        .season_leaderboard(Uint128::one(), 1, None, None)?;
+    assert_eq!(
+        leaderboard,
+        vec![
+            SeasonStanding {
+                rank: 1,
+                addr: user1.clone(),
+                rating: user1_rating.clone(),
+                reward: coins(701, DENOM),
+            },
+            SeasonStanding {
+                rank: 2,
+                addr: user2.clone(),
+                rating: user2_rating.clone(),
+                reward: coins(300, DENOM),
+            },
+        ]
+    );
+
+    // Ratings are pulled halfway back to the initial rating
+    let initial = SignedDecimal::from_ratio(1500, 1);
+    for (addr, previous) in [(&user1, &user1_rating), (&user2, &user2_rating)] {
+        let rating = arena
+            .arena_core
+            .rating(addr.to_string(), Uint128::one(), None)?
+            .unwrap();
+        assert_eq!(
+            rating.value,
+            previous.value + (initial - previous.value) * SignedDecimal::percent(50)
+        );
+    }
+
+    // The soft reset of the two ratings completed with the season start
+    let result = arena.arena_core.call_as(&user1).execute(
+        &ExecuteExt::ContinueSoftReset {
+            category_id: Uint128::one(),
+            limit: None,
+        }
+        .into(),
+        None,
+    );
+    assert!(result.is_err());
+
+    // Funds require a season reward
+    let result = arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&arena_interface::core::ExecuteMsg::from(
+                ExecuteExt::StartSeason {
+                    category_id: Uint128::one(),
+                    soft_reset: None,
+                    reward: None,
+                },
+            ))?,
+            funds: coins(1, DENOM),
+        })]);
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_wager_rating_integrity() -> anyhow::Result<()> {
+    let mock = MockBech32::new(PREFIX);
+    let (mut arena, admin) = setup_arena(&mock)?;
+    setup_voting_module(
+        &mock,
+        &arena,
+        vec![cw4::Member {
+            addr: admin.to_string(),
+            weight: 1u64,
+        }],
+    )?;
+
+    let user1 = mock.addr_make_with_balance("user1", coins(10000, DENOM))?;
+    let user2 = mock.addr_make_with_balance("user2", coins(10000, DENOM))?;
+
+    // Repeated pairings keep half the impact of the previous one, and a second pairing flags the pair
+    arena.dao_dao.dao_proposal_sudo.set_sender(&admin);
+    arena
+        .dao_dao
+        .dao_proposal_sudo
+        .proposal_execute(vec![CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: arena.arena_core.addr_str()?,
+            msg: to_json_binary(&arena_interface::core::ExecuteMsg::from(
+                ExecuteExt::UpdateCategories {
+                    to_add: None,
+                    to_edit: Some(vec![EditCompetitionCategory::SetRatingConfig {
+                        category_id: Uint128::one(),
+                        rating_config: RatingConfig {
+                            integrity: Some(IntegrityConfig {
+                                pairing_window: Duration::Time(2592000),
+                                repeat_decay: Decimal::percent(50),
+                                flag_threshold: 2,
+                                min_stake: Some(Coin::new(1000, DENOM)),
+                            }),
+                            ..RatingConfig::default()
+                        },
+                    }]),
+                },
+            ))?,
+            funds: vec![],
+        })])?;
+
+    arena.arena_wager_module.set_sender(&admin);
+    for stake in [None, Some(500u128), Some(500u128)] {
+        let escrow = stake
+            .map(|stake| -> anyhow::Result<_> {
+                Ok(EscrowInstantiateInfo {
+                    code_id: arena.arena_escrow.code_id()?,
+                    msg: to_json_binary(&arena_interface::escrow::InstantiateMsg {
+                        dues: [&user1, &user2]
+                            .into_iter()
+                            .map(|user| MemberBalanceUnchecked {
+                                addr: user.to_string(),
+                                balance: BalanceUnchecked {
+                                    native: Some(vec![Coin::new(stake, DENOM)]),
+                                    cw20: None,
+                                    cw721: None,
+                                },
+                            })
+                            .collect(),
+                    })?,
+                    label: "Wager Escrow".to_string(),
+                    additional_layered_fees: None,
+                })
+            })
+            .transpose()?;
+
+        let res = arena.arena_wager_module.create_competition(
+            "A test wager".to_string(),
+            Expiration::AtHeight(1000000),
+            GroupContractInfo::New {
+                info: ModuleInstantiateInfo {
+                    code_id: arena.arena_group.code_id()?,
+                    msg: to_json_binary(&group::InstantiateMsg {
+                        members: teams_to_members(&[user1.clone(), user2.clone()]),
+                    })?,
+                    admin: None,
+                    funds: vec![],
+                    label: "Arena Group".to_string(),
+                },
+            },
+            WagerInstantiateExt {},
+            "Test Wager".to_string(),
+            None,
+            Some(Uint128::one()),
+            escrow,
+            None,
+            None,
+            None,
+            None,
+        )?;
+
+        if let Some(stake) = stake {
+            let escrow_addr = res
+                .events
+                .iter()
+                .find_map(|event| {
+                    event
+                        .attributes
+                        .iter()
+                        .find(|attr| attr.key == "escrow_addr")
+                        .map(|attr| attr.value.clone())
+                })
+                .unwrap();
+            arena
+                .arena_escrow
+                .set_address(&Addr::unchecked(escrow_addr));
+            arena
+                .arena_escrow
+                .call_as(&user1)
+                .receive_native(&coins(stake, DENOM))?;
+            arena
+                .arena_escrow
+                .call_as(&user2)
+                .receive_native(&coins(stake, DENOM))?;
+        }
+
+        let competition_id = arena.arena_wager_module.competition_count()?;
+        arena.arena_wager_module.process_competition(
+            competition_id,
+            Some(Distribution {
+                member_percentages: vec![MemberPercentage {
+                    addr: user1.to_string(),
+                    percentage: Decimal::one(),
+                }],
+                remainder_addr: user1.to_string(),
+            }),
+        )?;
+    }
+
+    // The unstaked wager is not rated, and the repeated pairing only keeps half its impact
+    let weights: Vec<_> = arena
+        .arena_core
+        .pending_rating_results(user1.to_string(), Uint128::one())?
+        .into_iter()
+        .map(|x| (x.source.unwrap().competition_id, x.weight))
+        .collect();
+    assert_eq!(
+        weights,
+        vec![
+            (Uint128::new(2), Some(Decimal::one())),
+            (Uint128::new(3), Some(Decimal::percent(50))),
+        ]
+    );
+
+    let flagged_pairs = arena.arena_core.flagged_pairs(Uint128::one(), None, None)?;
+    assert_eq!(flagged_pairs.len(), 1);
+    assert_eq!(flagged_pairs[0].pairings, 2);
+    assert!(
+        [&flagged_pairs[0].addr_1, &flagged_pairs[0].addr_2] == [&user1, &user2]
+            || [&flagged_pairs[0].addr_1, &flagged_pairs[0].addr_2] == [&user2, &user1]
+    );
+
+    // The rating change is scaled by the average weight of the period's results
+    mock.wait_seconds(604800)?;
+    arena.arena_core.execute(
+        &ExecuteExt::CloseRatingPeriod {
+            category_id: Uint128::one(),
+            start_after: None,
+            limit: None,
+        }
+        .into(),
+        None,
+    )?;
+    let user1_rating = arena
+        .arena_core
+        .rating(user1.to_string(), Uint128::one(), None)?
+        .unwrap();
+    assert!(user1_rating.value > SignedDecimal::from_ratio(1500, 1));
+
+    Ok(())
+}
+
 #[test]
 #[ignore = "RPC blocks"]
 fn test_migration_v2_v2_1() -> anyhow::Result<()> {
@@ -12,6 +12,7 @@ use arena_interface::{
     core::{InstantiateExt, InstantiateMsg, NewCompetitionCategory, QueryExtFns as _},
     fees::TaxConfiguration,
 };
+use arena_matchmaking_module::state::MatchmakingConfig;
 
 use crate::Arena;
 
@@ -126,9 +127,17 @@ impl<Chain: CwEnv> cw_orch::contract::Deploy<Chain> for Arena<Chain> {
                                     categories: Some(vec![
                                         NewCompetitionCategory {
                                             name: "Category".to_string(),
+                                            rating_system: None,
+                                            rating_config: None,
+                                            parent_id: None,
+                                            metadata: None,
                                         },
                                         NewCompetitionCategory {
                                             name: "Other Category".to_string(),
+                                            rating_system: None,
+                                            rating_config: None,
+                                            parent_id: None,
+                                            metadata: None,
                                         },
                                     ]),
                                     tax: Decimal::from_ratio(5u128, 100u128),
@@ -240,6 +249,25 @@ impl<Chain: CwEnv> cw_orch::contract::Deploy<Chain> for Arena<Chain> {
             None,
         )?;
 
+        // Instantiate the matchmaking module
+        arena.arena_matchmaking_module.instantiate(
+            &arena_matchmaking_module::msg::InstantiateMsg {
+                owner: arena.arena_core.addr_str()?,
+                config: MatchmakingConfig {
+                    escrow_id: arena.arena_escrow.code_id()?,
+                    group_id: arena.arena_group.code_id()?,
+                    host: None,
+                    rating_window: Decimal::from_ratio(100u128, 1u128),
+                    window_increase: Decimal::from_ratio(50u128, 1u128),
+                    window_interval: Duration::Height(10u64),
+                    max_window: None,
+                    wager_duration: Duration::Time(86400u64),
+                },
+            },
+            Some(&arena.dao_dao.dao_core.address()?),
+            None,
+        )?;
+
         Ok(arena)
     }
 
@@ -267,6 +295,7 @@ impl<Chain: CwEnv> cw_orch::contract::Deploy<Chain> for Arena<Chain> {
             Box::new(&mut self.arena_competition_enrollment),
             Box::new(&mut self.arena_token_gateway),
             Box::new(&mut self.arena_payment_registry),
+            Box::new(&mut self.arena_matchmaking_module),
         ]
     }
 }
@@ -1,6 +1,7 @@
 pub mod arena_competition_enrollment;
 pub mod arena_core;
 pub mod arena_league_module;
+pub mod arena_matchmaking_module;
 pub mod arena_payment_registry;
 #[cfg(feature = "abc")]
 pub mod arena_token_gateway;